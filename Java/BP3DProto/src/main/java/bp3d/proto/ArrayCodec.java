@@ -0,0 +1,179 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+import java.nio.ByteBuffer;
+
+public abstract class ArrayCodec implements RawArray {
+    private final ByteBuffer buffer;
+    private final ByteCodec codec;
+    private final int rawSize;
+    private final int itemByteSize;
+
+    protected ArrayCodec(ByteBuffer buffer, ByteCodec codec, int rawSize, int itemBitSize) {
+        this.buffer = buffer;
+        this.codec = codec;
+        this.rawSize = rawSize;
+        this.itemByteSize = itemBitSize / 8;
+    }
+
+    public ByteBuffer buffer() {
+        return buffer;
+    }
+
+    @Override
+    public int size() {
+        return buffer.remaining() / itemByteSize;
+    }
+
+    @Override
+    public long readRaw(int index) {
+        return read(index);
+    }
+
+    @Override
+    public void writeRaw(int index, long value) {
+        write(index, value);
+    }
+
+    protected long read(int index) {
+        int pos = index * itemByteSize;
+        return codec.read(buffer, pos, pos + itemByteSize, rawSize);
+    }
+
+    protected void write(int index, long value) {
+        int pos = index * itemByteSize;
+        codec.write(buffer, pos, pos + itemByteSize, rawSize, value);
+    }
+
+    public static final class Byte extends ArrayCodec {
+        public Byte(ByteBuffer buffer, ByteCodec codec, int rawSize, int itemBitSize) {
+            super(buffer, codec, rawSize, itemBitSize);
+        }
+
+        public byte getRaw(int index) {
+            return Transmute.byteValue(read(index));
+        }
+
+        public Byte setRaw(int index, byte value) {
+            write(index, Transmute.fromValue(value));
+            return this;
+        }
+    }
+
+    public static final class Short extends ArrayCodec {
+        public Short(ByteBuffer buffer, ByteCodec codec, int rawSize, int itemBitSize) {
+            super(buffer, codec, rawSize, itemBitSize);
+        }
+
+        public short getRaw(int index) {
+            return Transmute.shortValue(read(index));
+        }
+
+        public Short setRaw(int index, short value) {
+            write(index, Transmute.fromValue(value));
+            return this;
+        }
+    }
+
+    public static final class Int extends ArrayCodec {
+        public Int(ByteBuffer buffer, ByteCodec codec, int rawSize, int itemBitSize) {
+            super(buffer, codec, rawSize, itemBitSize);
+        }
+
+        public int getRaw(int index) {
+            return Transmute.intValue(read(index));
+        }
+
+        public Int setRaw(int index, int value) {
+            write(index, Transmute.fromValue(value));
+            return this;
+        }
+    }
+
+    public static final class Long extends ArrayCodec {
+        public Long(ByteBuffer buffer, ByteCodec codec, int rawSize, int itemBitSize) {
+            super(buffer, codec, rawSize, itemBitSize);
+        }
+
+        public long getRaw(int index) {
+            return Transmute.longValue(read(index));
+        }
+
+        public Long setRaw(int index, long value) {
+            write(index, Transmute.fromValue(value));
+            return this;
+        }
+    }
+
+    public static final class Float extends ArrayCodec {
+        public Float(ByteBuffer buffer, ByteCodec codec, int rawSize, int itemBitSize) {
+            super(buffer, codec, rawSize, itemBitSize);
+        }
+
+        public float getRaw(int index) {
+            return Transmute.floatValue(read(index));
+        }
+
+        public Float setRaw(int index, float value) {
+            write(index, Transmute.fromValue(value));
+            return this;
+        }
+    }
+
+    public static final class Double extends ArrayCodec {
+        public Double(ByteBuffer buffer, ByteCodec codec, int rawSize, int itemBitSize) {
+            super(buffer, codec, rawSize, itemBitSize);
+        }
+
+        public double getRaw(int index) {
+            return Transmute.doubleValue(read(index));
+        }
+
+        public Double setRaw(int index, double value) {
+            write(index, Transmute.fromValue(value));
+            return this;
+        }
+    }
+
+    public static final class Boolean extends ArrayCodec {
+        public Boolean(ByteBuffer buffer, ByteCodec codec, int rawSize, int itemBitSize) {
+            super(buffer, codec, rawSize, itemBitSize);
+        }
+
+        public boolean getRaw(int index) {
+            return Transmute.booleanValue(read(index));
+        }
+
+        public Boolean setRaw(int index, boolean value) {
+            write(index, Transmute.fromValue(value));
+            return this;
+        }
+    }
+}
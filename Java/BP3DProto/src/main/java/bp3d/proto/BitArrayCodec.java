@@ -0,0 +1,86 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+import java.nio.ByteBuffer;
+
+/**
+ * An array of items packed without padding at bit granularity, the first item starting bitOffset
+ * bits into the buffer.
+ */
+public final class BitArrayCodec implements RawArray {
+    private final ByteBuffer buffer;
+    private final BitCodec codec;
+    private final int bitOffset;
+    private final int itemBitSize;
+    private final int length;
+
+    public BitArrayCodec(ByteBuffer buffer, BitCodec codec, int bitOffset, int itemBitSize, int length) {
+        this.buffer = buffer;
+        this.codec = codec;
+        this.bitOffset = bitOffset;
+        this.itemBitSize = itemBitSize;
+        this.length = length;
+    }
+
+    public ByteBuffer buffer() {
+        return buffer;
+    }
+
+    @Override
+    public int size() {
+        return length;
+    }
+
+    @Override
+    public long readRaw(int index) {
+        return getRaw(index);
+    }
+
+    @Override
+    public void writeRaw(int index, long value) {
+        setRaw(index, value);
+    }
+
+    private int itemBitOffset(int index) {
+        if (index < 0 || index >= length) {
+            throw new IndexOutOfBoundsException("index " + index + " out of bounds for length " + length);
+        }
+        return bitOffset + index * itemBitSize;
+    }
+
+    public long getRaw(int index) {
+        return codec.readBits(buffer, itemBitOffset(index), itemBitSize);
+    }
+
+    public BitArrayCodec setRaw(int index, long value) {
+        codec.writeBits(buffer, itemBitOffset(index), itemBitSize, value);
+        return this;
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+import java.nio.ByteBuffer;
+
+public abstract class BitCodec {
+    public static final BitCodec LE = new BitCodec(ByteCodec.LE) {
+        @Override
+        protected int shift(int bitOffset, int bitSize) {
+            return bitOffset;
+        }
+
+        @Override
+        protected int chunkShift(int shift, int len) {
+            return shift;
+        }
+
+        @Override
+        protected long appendChunk(long value, int done, int len, int bits) {
+            return value | ((long) bits << done);
+        }
+
+        @Override
+        protected int extractChunk(long value, int bitSize, int done, int len) {
+            return (int) (value >>> done);
+        }
+    };
+
+    public static final BitCodec BE = new BitCodec(ByteCodec.BE) {
+        @Override
+        protected int shift(int bitOffset, int bitSize) {
+            return 8 - (bitSize % 8) - bitOffset;
+        }
+
+        @Override
+        protected int chunkShift(int shift, int len) {
+            return 8 - shift - len;
+        }
+
+        @Override
+        protected long appendChunk(long value, int done, int len, int bits) {
+            return (value << len) | bits;
+        }
+
+        @Override
+        protected int extractChunk(long value, int bitSize, int done, int len) {
+            return (int) (value >>> (bitSize - done - len));
+        }
+    };
+
+    private final ByteCodec codec;
+
+    private BitCodec(ByteCodec codec) {
+        this.codec = codec;
+    }
+
+    protected abstract int shift(int bitOffset, int bitSize);
+
+    /**
+     * Returns the shift from the least significant bit of a byte of a chunk of len bits starting
+     * shift bits into the byte.
+     */
+    protected abstract int chunkShift(int shift, int len);
+
+    protected abstract long appendChunk(long value, int done, int len, int bits);
+
+    protected abstract int extractChunk(long value, int bitSize, int done, int len);
+
+    public long read(ByteBuffer buffer, int start, int end, int rawSize, int bitOffset, int bitSize) {
+        long mask = (1L << bitSize) - 1;
+        long value = codec.read(buffer, start, end, rawSize);
+        return (value >>> shift(bitOffset, bitSize)) & mask;
+    }
+
+    public void write(ByteBuffer buffer, int start, int end, int rawSize, int bitOffset, int bitSize, long value) {
+        long mask = (1L << bitSize) - 1;
+        long resetMask = ~(mask << bitOffset);
+        long original = codec.read(buffer, start, end, rawSize);
+        long clean = original & resetMask;
+        codec.write(buffer, start, end, rawSize, clean | ((value & mask) << shift(bitOffset, bitSize)));
+    }
+
+    /**
+     * Reads a value of bitSize bits (at most 64) starting bitOffset bits into the buffer.
+     */
+    public long readBits(ByteBuffer buffer, int bitOffset, int bitSize) {
+        long value = 0;
+        int done = 0;
+        while (done < bitSize) {
+            int pos = bitOffset + done;
+            int shift = pos % 8;
+            int len = Math.min(8 - shift, bitSize - done);
+            int mask = (1 << len) - 1;
+            int bits = ((buffer.get(buffer.position() + pos / 8) & 0xFF) >>> chunkShift(shift, len)) & mask;
+            value = appendChunk(value, done, len, bits);
+            done += len;
+        }
+        return value;
+    }
+
+    /**
+     * Writes a value of bitSize bits (at most 64) starting bitOffset bits into the buffer, leaving
+     * the surrounding bits untouched.
+     */
+    public void writeBits(ByteBuffer buffer, int bitOffset, int bitSize, long value) {
+        int done = 0;
+        while (done < bitSize) {
+            int pos = bitOffset + done;
+            int shift = pos % 8;
+            int len = Math.min(8 - shift, bitSize - done);
+            int mask = (1 << len) - 1;
+            int bits = extractChunk(value, bitSize, done, len) & mask;
+            int index = buffer.position() + pos / 8;
+            int byteShift = chunkShift(shift, len);
+            int original = buffer.get(index) & 0xFF;
+            buffer.put(index, (byte) ((original & ~(mask << byteShift)) | (bits << byteShift)));
+            done += len;
+        }
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+import java.nio.ByteBuffer;
+
+public abstract class ByteCodec {
+    public static final ByteCodec LE = new ByteCodec() {
+        @Override
+        public long decode(byte[] data, int size) {
+            long value = 0;
+            for (int i = 0; i != size; ++i) {
+                value |= ((long) (data[i] & 0xFF)) << (8 * i);
+            }
+            return value;
+        }
+
+        @Override
+        public void encode(byte[] data, int size, long value) {
+            for (int i = 0; i != size; ++i) {
+                data[i] = (byte) (value >>> (8 * i));
+            }
+        }
+    };
+
+    public static final ByteCodec BE = new ByteCodec() {
+        @Override
+        public long decode(byte[] data, int size) {
+            long value = 0;
+            for (int i = 0; i != size; ++i) {
+                value = (value << 8) | (data[i] & 0xFF);
+            }
+            return value;
+        }
+
+        @Override
+        public void encode(byte[] data, int size, long value) {
+            for (int i = 0; i != size; ++i) {
+                data[i] = (byte) (value >>> (8 * (size - i - 1)));
+            }
+        }
+    };
+
+    public abstract long decode(byte[] data, int size);
+
+    public abstract void encode(byte[] data, int size, long value);
+
+    public long read(ByteBuffer buffer, int start, int end, int rawSize) {
+        byte[] data = new byte[8];
+        for (int i = start; i != end; ++i) {
+            data[i - start] = buffer.get(i);
+        }
+        return decode(data, rawSize);
+    }
+
+    public void write(ByteBuffer buffer, int start, int end, int rawSize, long value) {
+        byte[] data = new byte[8];
+        for (int i = start; i != end; ++i) {
+            data[i - start] = buffer.get(i);
+        }
+        encode(data, rawSize, value);
+        for (int i = start; i != end; ++i) {
+            buffer.put(i, data[i - start]);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+import java.io.IOException;
+import java.io.OutputStream;
+import java.nio.ByteBuffer;
+import java.util.ArrayList;
+import java.util.List;
+
+public final class ListCodec<T> implements Codec<List<T>> {
+    private final Length length;
+    private final Codec<T> item;
+
+    private ListCodec(Length length, Codec<T> item) {
+        this.length = length;
+        this.item = item;
+    }
+
+    public static <T> ListCodec<T> of(Length length, Codec<T> item) {
+        return new ListCodec<>(length, item);
+    }
+
+    @Override
+    public Message<List<T>> fromSlice(ByteBuffer slice) throws ProtoException {
+        long len = length.readLong(slice);
+        Reader reader = new Reader(Buffers.from(slice, length.size()));
+        List<T> items = new ArrayList<>();
+        for (long i = 0; i != len; ++i) {
+            items.add(reader.read(item));
+        }
+        return new Message<>(length.size() + reader.offset(), items);
+    }
+
+    @Override
+    public void writeTo(List<T> input, OutputStream out) throws IOException, ProtoException {
+        length.writeLong(input.size(), out);
+        for (T v : input) {
+            item.writeTo(v, out);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+public class ProtoException extends Exception {
+    public ProtoException(String message) {
+        super(message);
+    }
+
+    public static final class InvalidUtf8 extends ProtoException {
+        public InvalidUtf8() {
+            super("invalid UTF-8 string");
+        }
+    }
+
+    public static final class Truncated extends ProtoException {
+        public Truncated() {
+            super("truncated input");
+        }
+    }
+
+    public static final class InvalidUnionDiscriminant extends ProtoException {
+        public final long discriminant;
+
+        public InvalidUnionDiscriminant(long discriminant) {
+            super("invalid union discriminant (" + discriminant + ")");
+            this.discriminant = discriminant;
+        }
+    }
+
+    public static final class InvalidEnumValue extends ProtoException {
+        public final long value;
+
+        public InvalidEnumValue(long value) {
+            super("invalid enum value (" + value + ")");
+            this.value = value;
+        }
+    }
+
+    public static final class LengthMismatch extends ProtoException {
+        public final long expected;
+        public final long actual;
+
+        public LengthMismatch(long expected, long actual) {
+            super("length mismatch (expected " + expected + ", got " + actual + ")");
+            this.expected = expected;
+            this.actual = actual;
+        }
+    }
+}
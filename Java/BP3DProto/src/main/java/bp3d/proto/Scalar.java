@@ -0,0 +1,102 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+import java.io.IOException;
+import java.io.OutputStream;
+import java.nio.ByteBuffer;
+import java.util.function.LongFunction;
+import java.util.function.ToLongFunction;
+
+public final class Scalar<T> implements Codec<T>, Length {
+    public static final Scalar<Byte> I8_LE = new Scalar<>(1, ByteCodec.LE, Transmute::byteValue, Transmute::fromValue);
+    public static final Scalar<Short> I16_LE = new Scalar<>(2, ByteCodec.LE, Transmute::shortValue, Transmute::fromValue);
+    public static final Scalar<Integer> I32_LE = new Scalar<>(4, ByteCodec.LE, Transmute::intValue, Transmute::fromValue);
+    public static final Scalar<Long> I64_LE = new Scalar<>(8, ByteCodec.LE, Transmute::longValue, Transmute::fromValue);
+    public static final Scalar<Short> U8_LE = new Scalar<>(1, ByteCodec.LE, v -> (short) v, Transmute::fromValue);
+    public static final Scalar<Integer> U16_LE = new Scalar<>(2, ByteCodec.LE, v -> (int) v, Transmute::fromValue);
+    public static final Scalar<Long> U32_LE = new Scalar<>(4, ByteCodec.LE, Transmute::longValue, Transmute::fromValue);
+    public static final Scalar<Long> U64_LE = new Scalar<>(8, ByteCodec.LE, Transmute::longValue, Transmute::fromValue);
+    public static final Scalar<Float> F32_LE = new Scalar<>(4, ByteCodec.LE, Transmute::floatValue, Transmute::fromValue);
+    public static final Scalar<Double> F64_LE = new Scalar<>(8, ByteCodec.LE, Transmute::doubleValue, Transmute::fromValue);
+    public static final Scalar<Boolean> BOOL_LE = new Scalar<>(1, ByteCodec.LE, Transmute::booleanValue, Transmute::fromValue);
+    public static final Scalar<Byte> I8_BE = new Scalar<>(1, ByteCodec.BE, Transmute::byteValue, Transmute::fromValue);
+    public static final Scalar<Short> I16_BE = new Scalar<>(2, ByteCodec.BE, Transmute::shortValue, Transmute::fromValue);
+    public static final Scalar<Integer> I32_BE = new Scalar<>(4, ByteCodec.BE, Transmute::intValue, Transmute::fromValue);
+    public static final Scalar<Long> I64_BE = new Scalar<>(8, ByteCodec.BE, Transmute::longValue, Transmute::fromValue);
+    public static final Scalar<Short> U8_BE = new Scalar<>(1, ByteCodec.BE, v -> (short) v, Transmute::fromValue);
+    public static final Scalar<Integer> U16_BE = new Scalar<>(2, ByteCodec.BE, v -> (int) v, Transmute::fromValue);
+    public static final Scalar<Long> U32_BE = new Scalar<>(4, ByteCodec.BE, Transmute::longValue, Transmute::fromValue);
+    public static final Scalar<Long> U64_BE = new Scalar<>(8, ByteCodec.BE, Transmute::longValue, Transmute::fromValue);
+    public static final Scalar<Float> F32_BE = new Scalar<>(4, ByteCodec.BE, Transmute::floatValue, Transmute::fromValue);
+    public static final Scalar<Double> F64_BE = new Scalar<>(8, ByteCodec.BE, Transmute::doubleValue, Transmute::fromValue);
+    public static final Scalar<Boolean> BOOL_BE = new Scalar<>(1, ByteCodec.BE, Transmute::booleanValue, Transmute::fromValue);
+
+    private final int size;
+    private final ByteCodec codec;
+    private final LongFunction<T> fromLong;
+    private final ToLongFunction<T> toLong;
+
+    private Scalar(int size, ByteCodec codec, LongFunction<T> fromLong, ToLongFunction<T> toLong) {
+        this.size = size;
+        this.codec = codec;
+        this.fromLong = fromLong;
+        this.toLong = toLong;
+    }
+
+    @Override
+    public int size() {
+        return size;
+    }
+
+    @Override
+    public long readLong(ByteBuffer slice) throws ProtoException {
+        if (slice.remaining() < size) {
+            throw new ProtoException.Truncated();
+        }
+        return codec.read(slice, 0, size, size);
+    }
+
+    @Override
+    public void writeLong(long value, OutputStream out) throws IOException {
+        byte[] data = new byte[8];
+        codec.encode(data, size, value);
+        out.write(data, 0, size);
+    }
+
+    @Override
+    public Message<T> fromSlice(ByteBuffer slice) throws ProtoException {
+        return new Message<>(size, fromLong.apply(readLong(slice)));
+    }
+
+    @Override
+    public void writeTo(T input, OutputStream out) throws IOException {
+        writeLong(toLong.applyAsLong(input), out);
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+import java.io.IOException;
+import java.io.OutputStream;
+import java.nio.ByteBuffer;
+import java.util.function.Function;
+
+public final class StructCodec<T> implements Codec<T> {
+    private final int size;
+    private final Function<ByteBuffer, T> wrap;
+    private final Function<T, ByteBuffer> unwrap;
+
+    public StructCodec(int size, Function<ByteBuffer, T> wrap, Function<T, ByteBuffer> unwrap) {
+        this.size = size;
+        this.wrap = wrap;
+        this.unwrap = unwrap;
+    }
+
+    public int size() {
+        return size;
+    }
+
+    @Override
+    public Message<T> fromSlice(ByteBuffer slice) throws ProtoException {
+        if (slice.remaining() < size) {
+            throw new ProtoException.Truncated();
+        }
+        return new Message<>(size, wrap.apply(Buffers.range(slice, 0, size)));
+    }
+
+    @Override
+    public void writeTo(T input, OutputStream out) throws IOException {
+        ByteBuffer data = Buffers.range(unwrap.apply(input), 0, size);
+        byte[] bytes = new byte[size];
+        data.get(bytes);
+        out.write(bytes);
+    }
+}
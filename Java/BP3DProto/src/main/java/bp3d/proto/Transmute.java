@@ -0,0 +1,81 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+/**
+ * Bit-level conversions between raw unsigned values and their typed views.
+ */
+public final class Transmute {
+    private Transmute() {
+    }
+
+    public static byte byteValue(long raw) {
+        return (byte) raw;
+    }
+
+    public static short shortValue(long raw) {
+        return (short) raw;
+    }
+
+    public static int intValue(long raw) {
+        return (int) raw;
+    }
+
+    public static long longValue(long raw) {
+        return raw;
+    }
+
+    public static float floatValue(long raw) {
+        return Float.intBitsToFloat((int) raw);
+    }
+
+    public static double doubleValue(long raw) {
+        return Double.longBitsToDouble(raw);
+    }
+
+    public static boolean booleanValue(long raw) {
+        return raw != 0;
+    }
+
+    public static long fromValue(long value) {
+        return value;
+    }
+
+    public static long fromValue(float value) {
+        return Float.floatToRawIntBits(value) & 0xFFFFFFFFL;
+    }
+
+    public static long fromValue(double value) {
+        return Double.doubleToRawLongBits(value);
+    }
+
+    public static long fromValue(boolean value) {
+        return value ? 1 : 0;
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+import java.util.function.LongFunction;
+import java.util.function.ToLongFunction;
+
+/**
+ * An array codec whose items are converted from their raw value when read and back when written.
+ */
+public final class ViewArray<T> {
+    private final RawArray array;
+    private final LongFunction<T> fromRaw;
+    private final ToLongFunction<T> toRaw;
+
+    public ViewArray(RawArray array, LongFunction<T> fromRaw, ToLongFunction<T> toRaw) {
+        this.array = array;
+        this.fromRaw = fromRaw;
+        this.toRaw = toRaw;
+    }
+
+    public RawArray raw() {
+        return array;
+    }
+
+    public int size() {
+        return array.size();
+    }
+
+    public T get(int index) {
+        return fromRaw.apply(array.readRaw(index));
+    }
+
+    public ViewArray<T> set(int index, T value) {
+        array.writeRaw(index, toRaw.applyAsLong(value));
+        return this;
+    }
+}
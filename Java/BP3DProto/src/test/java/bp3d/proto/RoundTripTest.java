@@ -0,0 +1,157 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+package bp3d.proto;
+
+import java.io.ByteArrayOutputStream;
+import java.nio.ByteBuffer;
+import java.util.Arrays;
+import java.util.List;
+
+/**
+ * Round trip tests of the runtime codecs; run as a plain program so that no test framework is
+ * needed, exits with a non-zero status on the first failure.
+ */
+public final class RoundTripTest {
+    private RoundTripTest() {
+    }
+
+    private static void check(boolean condition, String what) {
+        if (!condition) {
+            throw new AssertionError(what);
+        }
+    }
+
+    private static <T> T roundTrip(Codec<T> codec, T value, int expectedSize) throws Exception {
+        ByteArrayOutputStream out = new ByteArrayOutputStream();
+        codec.writeTo(value, out);
+        byte[] bytes = out.toByteArray();
+        check(bytes.length == expectedSize, "encoded size " + bytes.length + " != " + expectedSize);
+        Message<T> msg = codec.fromSlice(ByteBuffer.wrap(bytes));
+        check(msg.size() == expectedSize, "decoded size " + msg.size() + " != " + expectedSize);
+        return msg.data();
+    }
+
+    private static void byteCodec() {
+        ByteBuffer buffer = ByteBuffer.allocate(4);
+        ByteCodec.LE.write(buffer, 0, 3, 3, 0x123456);
+        check(buffer.get(0) == 0x56 && buffer.get(2) == 0x12, "ByteCodec.LE byte order");
+        check(ByteCodec.LE.read(buffer, 0, 3, 3) == 0x123456, "ByteCodec.LE read");
+        ByteCodec.BE.write(buffer, 1, 3, 2, 0xABCD);
+        check((buffer.get(1) & 0xFF) == 0xAB && (buffer.get(2) & 0xFF) == 0xCD, "ByteCodec.BE byte order");
+        check(ByteCodec.BE.read(buffer, 1, 3, 2) == 0xABCD, "ByteCodec.BE read");
+        check(buffer.get(0) == 0x56, "ByteCodec.BE untouched neighbour");
+    }
+
+    private static void bitCodec() {
+        ByteBuffer buffer = ByteBuffer.allocate(3);
+        BitCodec.LE.writeBits(buffer, 4, 12, 0xABC);
+        check((buffer.get(0) & 0xFF) == 0xC0 && (buffer.get(1) & 0xFF) == 0xAB, "BitCodec.LE bit order");
+        check(BitCodec.LE.readBits(buffer, 4, 12) == 0xABC, "BitCodec.LE readBits");
+        BitCodec.LE.writeBits(buffer, 0, 4, 0xF);
+        check(BitCodec.LE.readBits(buffer, 4, 12) == 0xABC, "BitCodec.LE untouched neighbour");
+        buffer = ByteBuffer.allocate(3);
+        BitCodec.BE.writeBits(buffer, 4, 12, 0xABC);
+        check((buffer.get(0) & 0xFF) == 0x0A && (buffer.get(1) & 0xFF) == 0xBC, "BitCodec.BE bit order");
+        check(BitCodec.BE.readBits(buffer, 4, 12) == 0xABC, "BitCodec.BE readBits");
+        buffer = ByteBuffer.allocate(2);
+        BitCodec.LE.write(buffer, 0, 2, 2, 3, 5, 0x15);
+        check(BitCodec.LE.read(buffer, 0, 2, 2, 3, 5) == 0x15, "BitCodec.LE read");
+        check(BitCodec.LE.read(buffer, 0, 2, 2, 0, 3) == 0, "BitCodec.LE untouched low bits");
+    }
+
+    private static void arrayCodec() {
+        ArrayCodec.Short array = new ArrayCodec.Short(ByteBuffer.allocate(6), ByteCodec.LE, 2, 16);
+        check(array.size() == 3, "ArrayCodec size");
+        array.setRaw(0, (short) -2).setRaw(2, (short) 300);
+        check(array.getRaw(0) == -2 && array.getRaw(1) == 0 && array.getRaw(2) == 300, "ArrayCodec get/set");
+        check((array.buffer().get(4) & 0xFF) == 0x2C && array.buffer().get(5) == 0x01, "ArrayCodec layout");
+    }
+
+    private static void bitArrayCodec() {
+        BitArrayCodec array = new BitArrayCodec(ByteBuffer.allocate(4), BitCodec.LE, 3, 5, 5);
+        check(array.size() == 5, "BitArrayCodec size");
+        for (int i = 0; i != 5; ++i) {
+            array.setRaw(i, i * 7);
+        }
+        for (int i = 0; i != 5; ++i) {
+            check(array.getRaw(i) == i * 7, "BitArrayCodec item " + i);
+        }
+        check((array.buffer().get(0) & 0x07) == 0, "BitArrayCodec leading bits");
+        array.setRaw(1, 0xFF);
+        check(array.getRaw(1) == 0x1F && array.getRaw(2) == 14, "BitArrayCodec masking");
+        try {
+            array.getRaw(5);
+            check(false, "BitArrayCodec out of bounds");
+        } catch (IndexOutOfBoundsException ignored) {
+            // expected
+        }
+    }
+
+    private static void viewArray() {
+        BitArrayCodec raw = new BitArrayCodec(ByteBuffer.allocate(2), BitCodec.LE, 0, 4, 4);
+        ViewArray<Byte> array = new ViewArray<>(raw, v -> (byte) (v > 7 ? v - 16 : v), v -> (long) v);
+        array.set(0, (byte) -3).set(3, (byte) 7);
+        check(array.size() == 4, "ViewArray size");
+        check(array.get(0) == -3 && array.get(3) == 7, "ViewArray get/set");
+        check(raw.getRaw(0) == 13, "ViewArray raw value");
+    }
+
+    private static void codecs() throws Exception {
+        check(roundTrip(Scalar.I16_BE, (short) -1234, 2) == -1234, "Scalar.I16_BE");
+        check(roundTrip(Scalar.U32_LE, 0xFFFFFFFEL, 4) == 0xFFFFFFFEL, "Scalar.U32_LE");
+        check(roundTrip(Scalar.F64_LE, 1.5, 8) == 1.5, "Scalar.F64_LE");
+        check(roundTrip(NullTerminatedString.CODEC, "h\u00e9llo", 7).equals("h\u00e9llo"), "NullTerminatedString");
+        check(roundTrip(VarcharString.of(Scalar.U8_LE), "abc", 4).equals("abc"), "VarcharString");
+        List<Short> items = Arrays.asList((short) 1, (short) -2, (short) 3);
+        check(roundTrip(ListCodec.of(Scalar.U8_LE, Scalar.I16_LE), items, 7).equals(items), "ListCodec");
+        check(roundTrip(ListCodec.of(FixedLen.of(3), Scalar.I16_LE), items, 6).equals(items), "FixedLen");
+        try {
+            ListCodec.of(FixedLen.of(2), Scalar.I16_LE).writeTo(items, new ByteArrayOutputStream());
+            check(false, "FixedLen length mismatch");
+        } catch (ProtoException ignored) {
+            // expected
+        }
+        try {
+            NullTerminatedString.CODEC.fromSlice(ByteBuffer.wrap(new byte[] {'a', 'b'}));
+            check(false, "NullTerminatedString truncated");
+        } catch (ProtoException.Truncated ignored) {
+            // expected
+        }
+    }
+
+    public static void main(String[] args) throws Exception {
+        byteCodec();
+        bitCodec();
+        arrayCodec();
+        bitArrayCodec();
+        viewArray();
+        codecs();
+        System.out.println("OK");
+    }
+}
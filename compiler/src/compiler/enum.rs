@@ -28,13 +28,16 @@
 
 use std::collections::HashMap;
 use crate::compiler::Error;
+use crate::compiler::structure::FixedFieldType;
 
 #[derive(Clone, Debug)]
 pub struct Enum {
     pub name: String,
     pub largest: usize,
+    pub ty: FixedFieldType,
     pub variants: Vec<(String, usize)>,
-    pub variants_map: HashMap<String, usize>
+    pub variants_map: HashMap<String, usize>,
+    pub doc: Option<String>
 }
 
 impl Enum {
@@ -43,6 +46,7 @@ impl Enum {
         variants.sort_by(|(_, v), (_, v1)| v.cmp(v1));
         let mut variants_map = HashMap::new();
         let largest = variants.last().map(|(_, v)| *v).ok_or(Error::ZeroEnum)?;
+        let ty = FixedFieldType::from_max_value(largest)?;
         for (k, v) in &variants {
             variants_map.insert(k.clone(), *v);
         }
@@ -50,7 +54,14 @@ impl Enum {
             name: value.name,
             variants,
             variants_map,
-            largest
+            largest,
+            ty,
+            doc: value.doc
         })
     }
+
+    /// Returns the number of bits needed to store all variants.
+    pub fn bit_size(&self) -> usize {
+        (usize::BITS - self.largest.leading_zeros()) as usize
+    }
 }
@@ -38,12 +38,21 @@ simple_error! {
         UnsupportedViewType(SimpleType) => "unsupported view for type: {:?}",
         MissingBitSize => "missing bits specifier on a structure field",
         UndefinedReference(String) => "undefined reference to '{}'",
+        UnsupportedListItem => "list items must be items, strings or lists",
+        InvalidListLen => "lists must have exactly one of max_len or fixed_len",
         UnalignedArrayCodec => "unaligned array in structure",
         SolverError => "failed to resolve imported type",
         ZeroEnum => "enums must have at least 1 variant",
+        EnumOverflow(String) => "enum '{}' does not fit in the field",
+        ZeroFlags => "flags must have at least 1 flag",
+        FlagsOverflow(String) => "flags '{}' do not fit in the field",
         InvalidUnionDiscriminant => "invalid union discriminant path",
         FloatInUnionDiscriminant => "floats are not allowed as union discriminants",
+        FlagsInUnionDiscriminant => "flags are not allowed as union discriminants",
         InvalidUnionCase(String) => "invalid union case {}",
-        UnionTypeMismatch => "mismatch with union discriminant types" 
+        UnionTypeMismatch => "mismatch with union discriminant types",
+        ReservedUnionCase(String) => "union case name '{}' is reserved",
+        UnknownUnionNotLast(String) => "union field '{}' with an unknown case must be the last field of its message",
+        EmbeddedUnknownUnion(String) => "message '{}' has a union with an unknown case and can only be decoded as the outermost message" 
     }
 }
@@ -0,0 +1,64 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use std::collections::HashMap;
+use crate::compiler::Error;
+use crate::compiler::structure::FixedFieldType;
+use crate::model::structure::StructFieldType;
+
+#[derive(Clone, Debug)]
+pub struct Flags {
+    pub name: String,
+    pub largest: usize,
+    pub ty: FixedFieldType,
+    pub flags: Vec<(String, usize)>,
+    pub flags_map: HashMap<String, usize>,
+    pub doc: Option<String>
+}
+
+impl Flags {
+    pub fn from_model(value: crate::model::protocol::Flags) -> Result<Flags, Error> {
+        let mut flags: Vec<(String, usize)> = value.flags.into_iter().collect();
+        flags.sort_by(|(k, v), (k1, v1)| v.cmp(v1).then_with(|| k.cmp(k1)));
+        let largest = flags.last().map(|(_, v)| *v).ok_or(Error::ZeroFlags)?;
+        let ty = FixedFieldType::from_model(StructFieldType::Unsigned { bits: largest + 1 })?;
+        let flags_map = flags.iter().cloned().collect();
+        Ok(Flags {
+            name: value.name,
+            largest,
+            ty,
+            flags,
+            flags_map,
+            doc: value.doc
+        })
+    }
+
+    /// Returns the number of bits needed to store all flags.
+    pub fn bit_size(&self) -> usize {
+        self.largest + 1
+    }
+}
@@ -30,9 +30,10 @@ use std::cell::Cell;
 use std::rc::Rc;
 use crate::compiler::error::Error;
 use crate::compiler::Protocol;
+use crate::compiler::r#enum::Enum;
 use crate::compiler::structure::{FixedFieldType, Structure};
 use crate::compiler::union::Union;
-use crate::model::message::MessageFieldType;
+use crate::model::message::{ItemType as ModelItemType, MessageFieldType};
 use crate::model::protocol::Endianness;
 
 #[derive(Clone, Debug)]
@@ -56,23 +57,130 @@ impl Referenced {
     }
 }
 
+/// A plain value usable as a message item or list item: either a scalar or an enum.
+#[derive(Clone, Debug)]
+pub enum ValueType {
+    Scalar(FixedFieldType),
+    Enum(Rc<Enum>)
+}
+
+impl ValueType {
+    pub fn lookup(proto: &Protocol, name: &str) -> Option<Self> {
+        FixedFieldType::from_name(name)
+            .map(ValueType::Scalar)
+            .or_else(|| proto.enums_by_name.get(name).map(|v| ValueType::Enum(v.clone())))
+    }
+
+    /// Returns the scalar type used to encode this value.
+    pub fn raw_type(&self) -> FixedFieldType {
+        match self {
+            ValueType::Scalar(v) => *v,
+            ValueType::Enum(v) => v.ty
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ArrayField {
     pub ty: FixedFieldType,
+    pub max_len: usize,
+    /// Whether exactly `max_len` items are encoded without a length prefix.
+    pub fixed: bool,
     pub item_type: Rc<Structure>
 }
 
 #[derive(Clone, Debug)]
 pub struct VarcharStringField {
     pub ty: FixedFieldType,
+    pub max_len: usize
 }
 
 #[derive(Clone, Debug)]
 pub struct ListField {
     pub ty: FixedFieldType,
+    pub max_len: usize,
+    /// Whether exactly `max_len` items are encoded without a length prefix.
+    pub fixed: bool,
     pub item_type: Rc<Message>,
 }
 
+/// The type of the items of a list which is not a plain list of structures or messages.
+#[derive(Clone, Debug)]
+pub enum ItemType {
+    Struct(Rc<Structure>),
+    Message(Rc<Message>),
+    Value(ValueType),
+    NullTerminatedString,
+    VarcharString(VarcharStringField),
+    List(Box<ItemListField>)
+}
+
+impl ItemType {
+    fn lookup(proto: &Protocol, name: String) -> Result<Self, Error> {
+        match Referenced::lookup(proto, &name) {
+            Some(Referenced::Struct(v)) => Ok(ItemType::Struct(v)),
+            Some(Referenced::Message(v)) => {
+                v.check_embeddable()?;
+                v.embedded.set(true);
+                Ok(ItemType::Message(v))
+            },
+            None => ValueType::lookup(proto, &name).map(ItemType::Value).ok_or(Error::UndefinedReference(name))
+        }
+    }
+
+    fn from_model(proto: &Protocol, value: ModelItemType) -> Result<Self, Error> {
+        let ty = match value {
+            ModelItemType::Name(name) => return Self::lookup(proto, name),
+            ModelItemType::Inline(ty) => *ty
+        };
+        match ty {
+            MessageFieldType::Item { item_type } => Self::lookup(proto, item_type),
+            MessageFieldType::String { max_len: None } => Ok(ItemType::NullTerminatedString),
+            MessageFieldType::String { max_len: Some(max_len) } => Ok(ItemType::VarcharString(VarcharStringField {
+                ty: FixedFieldType::from_max_value(max_len)?,
+                max_len
+            })),
+            MessageFieldType::List { max_len, fixed_len, item_type } => {
+                let (ty, max_len, fixed) = list_len(max_len, fixed_len)?;
+                Ok(ItemType::List(Box::new(ItemListField {
+                    ty,
+                    max_len,
+                    fixed,
+                    item_type: Self::from_model(proto, item_type)?
+                })))
+            },
+            _ => Err(Error::UnsupportedListItem)
+        }
+    }
+
+    pub fn is_self_delimited(&self) -> bool {
+        match self {
+            ItemType::Message(v) => v.is_self_delimited(),
+            ItemType::List(v) => v.item_type.is_self_delimited(),
+            _ => true
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ItemListField {
+    pub ty: FixedFieldType,
+    pub max_len: usize,
+    /// Whether exactly `max_len` items are encoded without a length prefix.
+    pub fixed: bool,
+    pub item_type: ItemType
+}
+
+/// Returns the type of the length prefix, the maximum or exact number of items and whether the
+/// number of items is fixed.
+fn list_len(max_len: Option<usize>, fixed_len: Option<usize>) -> Result<(FixedFieldType, usize, bool), Error> {
+    match (max_len, fixed_len) {
+        (Some(max_len), None) => Ok((FixedFieldType::from_max_value(max_len)?, max_len, false)),
+        (None, Some(fixed_len)) => Ok((FixedFieldType::from_max_value(fixed_len)?, fixed_len, true)),
+        _ => Err(Error::InvalidListLen)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct FixedField {
     pub ty: FixedFieldType
@@ -88,12 +196,14 @@ pub struct UnionField {
 #[derive(Clone, Debug)]
 pub enum FieldType {
     Fixed(FixedField),
+    Enum(Rc<Enum>),
     Ref(Referenced),
     NullTerminatedString,
     VarcharString(VarcharStringField),
     Array(ArrayField),
     Union(UnionField),
     List(ListField),
+    ItemList(ItemListField),
     Payload
 }
 
@@ -107,6 +217,19 @@ impl FieldType {
             _ => false
         }
     }
+
+    /// Returns true if the size of this field can be computed from its encoded bytes alone, that
+    /// is if it does not contain a payload extending to the end of the input.
+    pub fn is_self_delimited(&self) -> bool {
+        match self {
+            FieldType::Ref(Referenced::Message(v)) => v.is_self_delimited(),
+            FieldType::List(v) => v.item_type.is_self_delimited(),
+            FieldType::ItemList(v) => v.item_type.is_self_delimited(),
+            FieldType::Union(v) => v.r.is_self_delimited(),
+            FieldType::Payload => false,
+            _ => true
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -121,14 +244,34 @@ pub struct Field {
     pub ty: FieldType,
     pub optional: bool,
     pub size: SizeInfo,
-    pub endianness: Endianness
+    pub endianness: Endianness,
+    pub doc: Option<String>
 }
 
 impl Field {
     fn from_model(proto: &Protocol, unsorted: &[Field], value: crate::model::message::MessageField) -> Result<Self, Error> {
         match value.info {
             MessageFieldType::Item { item_type } => {
-                let r = Referenced::lookup(proto, &item_type).ok_or_else(|| Error::UndefinedReference(item_type))?;
+                let r = match Referenced::lookup(proto, &item_type) {
+                    Some(r) => r,
+                    None => {
+                        let value_type = ValueType::lookup(proto, &item_type).ok_or(Error::UndefinedReference(item_type))?;
+                        return Ok(Field {
+                            name: value.name,
+                            doc: value.doc,
+                            ty: match value_type {
+                                ValueType::Scalar(ty) => FieldType::Fixed(FixedField { ty }),
+                                ValueType::Enum(r) => FieldType::Enum(r)
+                            },
+                            optional: value.optional.unwrap_or_default(),
+                            size: SizeInfo {
+                                is_dyn_sized: false,
+                                is_element_dyn_sized: false
+                            },
+                            endianness: proto.endianness
+                        });
+                    }
+                };
                 match r {
                     Referenced::Struct(r) => {
                         if r.fields.len() == 1 && r.fields[0].as_fixed().is_some()
@@ -137,6 +280,7 @@ impl Field {
                             let fixed = unsafe { r.fields[0].as_fixed().unwrap_unchecked() };
                             Ok(Field {
                                 name: value.name,
+                                doc: value.doc,
                                 ty: FieldType::Fixed(FixedField {
                                     ty: fixed.ty
                                 }),
@@ -150,6 +294,7 @@ impl Field {
                         } else {
                             Ok(Field {
                                 name: value.name,
+                                doc: value.doc,
                                 ty: FieldType::Ref(Referenced::Struct(r)),
                                 optional: value.optional.unwrap_or_default(),
                                 size: SizeInfo {
@@ -161,8 +306,10 @@ impl Field {
                         }
                     },
                     Referenced::Message(r) => {
+                        r.check_embeddable()?;
                         Ok(Field {
                             name: value.name,
+                            doc: value.doc,
                             optional: value.optional.unwrap_or_default(),
                             size: r.size,
                             ty: FieldType::Ref(Referenced::Message(r)),
@@ -171,21 +318,47 @@ impl Field {
                     }
                 }
             },
-            MessageFieldType::List { max_len, item_type } => {
-                let r = Referenced::lookup(proto, &item_type).ok_or_else(|| Error::UndefinedReference(item_type))?;
-                let ty = FixedFieldType::from_max_value(max_len)?;
+            MessageFieldType::List { max_len, fixed_len, item_type } => {
+                let (ty, max_len, fixed) = list_len(max_len, fixed_len)?;
+                let r = match ItemType::from_model(proto, item_type)? {
+                    ItemType::Struct(v) => Referenced::Struct(v),
+                    ItemType::Message(v) => Referenced::Message(v),
+                    item_type => {
+                        // Fixed lists of plain values always have the same size.
+                        let is_dyn_sized = !(fixed && matches!(item_type, ItemType::Value(_)));
+                        return Ok(Field {
+                            name: value.name,
+                            doc: value.doc,
+                            ty: FieldType::ItemList(ItemListField {
+                                ty,
+                                max_len,
+                                fixed,
+                                item_type
+                            }),
+                            optional: value.optional.unwrap_or_default(),
+                            size: SizeInfo {
+                                is_element_dyn_sized: false,
+                                is_dyn_sized
+                            },
+                            endianness: proto.endianness
+                        });
+                    }
+                };
                 match r {
                     Referenced::Struct(item_type) => {
                         Ok(Field {
                             name: value.name,
+                            doc: value.doc,
                             ty: FieldType::Array(ArrayField {
                                 item_type,
+                                max_len,
+                                fixed,
                                 ty
                             }),
                             optional: value.optional.unwrap_or_default(),
                             size: SizeInfo {
                                 is_element_dyn_sized: false,
-                                is_dyn_sized: true
+                                is_dyn_sized: !fixed
                             },
                             endianness: proto.endianness
                         })
@@ -194,8 +367,11 @@ impl Field {
                         item_type.embedded.set(true);
                         Ok(Field {
                             name: value.name,
+                            doc: value.doc,
                             ty: FieldType::List(ListField {
                                 ty,
+                                max_len,
+                                fixed,
                                 item_type
                             }),
                             optional: value.optional.unwrap_or_default(),
@@ -213,6 +389,7 @@ impl Field {
                     None => {
                         Ok(Field {
                             name: value.name,
+                            doc: value.doc,
                             ty: FieldType::NullTerminatedString,
                             optional: value.optional.unwrap_or_default(),
                             size: SizeInfo {
@@ -226,8 +403,10 @@ impl Field {
                         let ty = FixedFieldType::from_max_value(max_len)?;
                         Ok(Field {
                             name: value.name,
+                            doc: value.doc,
                             ty: FieldType::VarcharString(VarcharStringField {
-                                ty
+                                ty,
+                                max_len
                             }),
                             optional: value.optional.unwrap_or_default(),
                             size: SizeInfo {
@@ -263,6 +442,7 @@ impl Field {
                 }
                 Ok(Field {
                     name: value.name,
+                    doc: value.doc,
                     ty: FieldType::Union(UnionField {
                         r: r.clone(),
                         on_name,
@@ -275,6 +455,7 @@ impl Field {
             },
             MessageFieldType::Payload => Ok(Field {
                 name: value.name,
+                doc: value.doc,
                 ty: FieldType::Payload,
                 optional: value.optional.unwrap_or_default(),
                 size: SizeInfo {
@@ -292,6 +473,7 @@ pub struct Message {
     pub name: String,
     pub fields: Vec<Field>,
     pub size: SizeInfo,
+    pub doc: Option<String>,
     embedded: Cell<bool>
 }
 
@@ -300,7 +482,39 @@ impl Message {
         self.embedded.get()
     }
 
+    pub fn is_self_delimited(&self) -> bool {
+        self.fields.iter().all(|v| v.ty.is_self_delimited())
+    }
+
+    /// Returns true if the given field is the discriminant of a union of this message.
+    pub fn is_discriminant(&self, field: &Field) -> bool {
+        self.fields.iter().any(|v| match &v.ty {
+            FieldType::Union(v) => v.on_name == field.name,
+            _ => false
+        })
+    }
+
+    /// Returns true if this message has a union with an unknown case.
+    pub fn has_unknown_union(&self) -> bool {
+        self.fields.iter().any(|v| match &v.ty {
+            FieldType::Union(v) => v.r.unknown,
+            _ => false
+        })
+    }
+
+    /// Checks that this message can be used in a field, list or union of another message.
+    ///
+    /// Unknown union cases extend to the end of the input, which is only delimited for the
+    /// outermost message.
+    pub fn check_embeddable(&self) -> Result<(), Error> {
+        match self.has_unknown_union() {
+            true => Err(Error::EmbeddedUnknownUnion(self.name.clone())),
+            false => Ok(())
+        }
+    }
+
     pub fn from_model(proto: &Protocol, value: crate::model::message::Message) -> Result<Message, Error> {
+        let field_count = value.fields.len();
         let mut fields = Vec::with_capacity(value.fields.len());
         let mut dyn_sized_elem_count = 0;
         let mut is_dyn_sized = false;
@@ -318,6 +532,11 @@ impl Message {
             if dyn_sized_elem_count > 1 {
                 return Err(Error::MultiPayload)
             }
+            if let FieldType::Union(v) = &field.ty {
+                if v.r.unknown && fields.len() + 1 < field_count {
+                    return Err(Error::UnknownUnionNotLast(field.name))
+                }
+            }
             fields.push(field);
         }
         Ok(Message {
@@ -327,6 +546,7 @@ impl Message {
                 is_dyn_sized,
                 is_element_dyn_sized: dyn_sized_elem_count > 0
             },
+            doc: value.doc,
             embedded: Cell::new(false)
         })
     }
@@ -32,6 +32,7 @@ mod error;
 mod protocol;
 pub mod util;
 pub mod r#enum;
+pub mod flags;
 pub mod union;
 
 pub use protocol::Protocol;
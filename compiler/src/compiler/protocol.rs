@@ -31,6 +31,7 @@ use std::rc::Rc;
 use crate::compiler::error::Error;
 use crate::compiler::message::Message;
 use crate::compiler::r#enum::Enum;
+use crate::compiler::flags::Flags;
 use crate::compiler::structure::Structure;
 use crate::compiler::union::Union;
 use crate::compiler::util::{ImportResolver, TypePathMap};
@@ -44,11 +45,14 @@ pub struct Protocol {
     pub structs_by_name: HashMap<String, Rc<Structure>>,
     pub messages_by_name: HashMap<String, Rc<Message>>,
     pub enums_by_name: HashMap<String, Rc<Enum>>,
+    pub flags_by_name: HashMap<String, Rc<Flags>>,
     pub unions_by_name: HashMap<String, Rc<Union>>,
     pub structs: Vec<Rc<Structure>>,
     pub messages: Vec<Rc<Message>>,
     pub enums: Vec<Rc<Enum>>,
-    pub unions: Vec<Rc<Union>>
+    pub flags: Vec<Rc<Flags>>,
+    pub unions: Vec<Rc<Union>>,
+    pub doc: Option<String>
 }
 
 impl Protocol {
@@ -60,11 +64,14 @@ impl Protocol {
             structs_by_name: HashMap::new(),
             messages_by_name: HashMap::new(),
             enums_by_name: HashMap::new(),
+            flags_by_name: HashMap::new(),
             unions_by_name: HashMap::new(),
             structs: Vec::new(),
             messages: Vec::new(),
             enums: Vec::new(),
-            unions: Vec::new()
+            flags: Vec::new(),
+            unions: Vec::new(),
+            doc: value.doc
         };
         if let Some(imports) = value.imports {
             for v in imports {
@@ -77,6 +84,12 @@ impl Protocol {
                     None => {
                         match r.enums_by_name.get(&v.type_name) {
                             None => {
+                                if let Some(vv) = r.flags_by_name.get(&v.type_name) {
+                                    let type_path = solver.get_full_type_path(&v.protocol, &v.type_name).ok_or(Error::SolverError)?;
+                                    proto.flags_by_name.insert(v.type_name, vv.clone());
+                                    proto.type_path_by_name.add(vv.name.clone(), type_path);
+                                    continue;
+                                }
                                 match r.unions_by_name.get(&v.type_name) {
                                     Some(vv) => {
                                         let type_path = solver.get_full_type_path(&v.protocol, &v.type_name).ok_or(Error::SolverError)?;
@@ -113,6 +126,13 @@ impl Protocol {
                 proto.enums.push(v);
             }
         }
+        if let Some(flags) = value.flags {
+            for v in flags {
+                let v = Rc::new(Flags::from_model(v)?);
+                proto.flags_by_name.insert(v.name.clone(), v.clone());
+                proto.flags.push(v);
+            }
+        }
         if let Some(structs) = value.structs {
             for v in structs {
                 let v = Rc::new(Structure::from_model(&proto, v)?);
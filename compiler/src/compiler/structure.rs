@@ -30,6 +30,7 @@ use std::rc::Rc;
 use crate::compiler::error::Error;
 use crate::compiler::Protocol;
 use crate::compiler::r#enum::Enum;
+use crate::compiler::flags::Flags;
 use crate::model::protocol::Endianness;
 use crate::model::structure::{SimpleType, StructFieldType, StructFieldView};
 
@@ -74,6 +75,24 @@ impl FixedFieldType {
         }
     }
 
+    /// Returns the scalar type with the given name, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "int8" => Some(Self::Int8),
+            "int16" => Some(Self::Int16),
+            "int32" => Some(Self::Int32),
+            "int64" => Some(Self::Int64),
+            "uint8" => Some(Self::UInt8),
+            "uint16" => Some(Self::UInt16),
+            "uint32" => Some(Self::UInt32),
+            "uint64" => Some(Self::UInt64),
+            "float32" => Some(Self::Float32),
+            "float64" => Some(Self::Float64),
+            "bool" => Some(Self::Bool),
+            _ => None
+        }
+    }
+
     pub fn from_max_value(max_value: usize) -> Result<Self, Error> {
         let bit_size = if max_value > u32::MAX as usize {
             64
@@ -156,6 +175,9 @@ pub enum FieldView {
     /// Apply an enum view.
     Enum(Rc<Enum>),
 
+    /// Apply a flags view.
+    Flags(Rc<Flags>),
+
     /// Apply a raw C-like cast (used for unsigned > signed and unsigned > float of same bit size).
     Transmute,
 
@@ -183,8 +205,21 @@ impl FieldView {
                     return Err(Error::UnsupportedViewType(ty));
                 }
                 let r = proto.enums_by_name.get(&name).ok_or_else(|| Error::UndefinedReference(name))?;
+                if r.bit_size() > bit_size {
+                    return Err(Error::EnumOverflow(r.name.clone()));
+                }
                 Ok(FieldView::Enum(r.clone()))
             }
+            Some(StructFieldView::Flags { name }) => {
+                if ty != SimpleType::Unsigned {
+                    return Err(Error::UnsupportedViewType(ty));
+                }
+                let r = proto.flags_by_name.get(&name).ok_or(Error::UndefinedReference(name))?;
+                if r.bit_size() > bit_size {
+                    return Err(Error::FlagsOverflow(r.name.clone()));
+                }
+                Ok(FieldView::Flags(r.clone()))
+            }
             Some(StructFieldView::FloatRange { min, max }) => {
                 if ty != SimpleType::Float {
                     return Err(Error::UnsupportedViewType(ty));
@@ -229,7 +264,8 @@ pub struct FixedField {
     pub ty: FixedFieldType,
     pub loc: Location,
     pub view: FieldView,
-    pub endianness: Endianness
+    pub endianness: Endianness,
+    pub doc: Option<String>
 }
 
 #[derive(Clone, Debug)]
@@ -238,27 +274,66 @@ pub struct FixedArrayField {
     pub ty: FixedFieldType,
     pub array_len: usize,
     pub loc: Location,
-    pub endianness: Endianness
+    pub view: FieldView,
+    pub endianness: Endianness,
+    pub doc: Option<String>
 }
 
 impl FixedArrayField {
     pub fn item_bit_size(&self) -> usize {
         self.loc.bit_size / self.array_len
     }
+
+    /// Whether items are packed at bit granularity, which is the case when they are not whole
+    /// bytes or when the array does not start on a byte boundary.
+    pub fn is_bit_packed(&self) -> bool {
+        self.loc.bit_offset != 0 || !self.item_bit_size().is_multiple_of(8)
+    }
+
+    /// Returns the type items are read and written as; bit-packed items and items with a view are
+    /// always unsigned.
+    pub fn raw_item_type(&self) -> FixedFieldType {
+        match self.is_bit_packed() || !self.view.is_transmute() {
+            true => FixedFieldType::from_model(StructFieldType::Unsigned { bits: self.item_bit_size() }).unwrap(),
+            false => self.ty
+        }
+    }
+
+    /// Whether items must be converted from their raw value, either because of an explicit view or
+    /// because the raw type differs from the item type.
+    pub fn has_view(&self) -> bool {
+        !self.view.is_transmute() || self.raw_item_type() != self.ty
+    }
+
+    /// Returns the number of bytes the array touches, including partially used bytes at both ends.
+    pub fn byte_span(&self) -> usize {
+        (self.loc.bit_offset + self.loc.bit_size).div_ceil(8)
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct StructField {
     pub name: String,
     pub r: Rc<Structure>,
-    pub loc: Location
+    pub loc: Location,
+    pub doc: Option<String>
+}
+
+#[derive(Clone, Debug)]
+pub struct StructArrayField {
+    pub name: String,
+    pub r: Rc<Structure>,
+    pub array_len: usize,
+    pub loc: Location,
+    pub doc: Option<String>
 }
 
 #[derive(Clone, Debug)]
 pub enum Field {
     Fixed(FixedField),
     Array(FixedArrayField),
-    Struct(StructField)
+    Struct(StructField),
+    StructArray(StructArrayField)
 }
 
 impl Field {
@@ -273,7 +348,8 @@ impl Field {
         match self {
             Field::Fixed(v) => &v.loc,
             Field::Array(v) => &v.loc,
-            Field::Struct(v) => &v.loc
+            Field::Struct(v) => &v.loc,
+            Field::StructArray(v) => &v.loc
         }
     }
 
@@ -281,7 +357,17 @@ impl Field {
         match self {
             Field::Fixed(v) => &v.name,
             Field::Array(v) => &v.name,
-            Field::Struct(v) => &v.name
+            Field::Struct(v) => &v.name,
+            Field::StructArray(v) => &v.name
+        }
+    }
+
+    pub fn doc(&self) -> Option<&str> {
+        match self {
+            Field::Fixed(v) => v.doc.as_deref(),
+            Field::Array(v) => v.doc.as_deref(),
+            Field::Struct(v) => v.doc.as_deref(),
+            Field::StructArray(v) => v.doc.as_deref()
         }
     }
 
@@ -289,8 +375,24 @@ impl Field {
         match value.info {
             StructFieldType::Struct { item_type } => {
                 let r = proto.structs_by_name.get(&item_type).ok_or_else(|| Error::UndefinedReference(item_type))?;
+                let array_len = value.array_len.unwrap_or(1);
+                if array_len > 1 {
+                    let bit_size = r.bit_size * array_len;
+                    // Item views are sliced out of the parent buffer, so each item must start on a byte.
+                    if !r.bit_size.is_multiple_of(8) || !last_bit_offset.is_multiple_of(8) {
+                        return Err(Error::UnalignedArrayCodec);
+                    }
+                    return Ok((Self::StructArray(StructArrayField {
+                        name: value.name,
+                        doc: value.doc,
+                        r: r.clone(),
+                        array_len,
+                        loc: Location::from_model(bit_size, last_bit_offset)
+                    }), last_bit_offset + bit_size));
+                }
                 Ok((Self::Struct(StructField {
                     name: value.name,
+                    doc: value.doc,
                     r: r.clone(),
                     loc: Location::from_model(r.bit_size, last_bit_offset)
                 }), last_bit_offset + r.bit_size))
@@ -303,19 +405,23 @@ impl Field {
                 let ty = FixedFieldType::from_model(value.info)?;
                 let loc = Location::from_model(bit_size, last_bit_offset);
                 if array_len > 1 {
-                    if bit_size % 8 != 0 {
-                        return Err(Error::UnalignedArrayCodec);
-                    }
-                    Ok((Self::Array(FixedArrayField {
+                    let field = FixedArrayField {
                         name: value.name,
+                        doc: value.doc,
                         endianness: proto.endianness,
                         array_len,
                         ty,
-                        loc
-                    }), last_bit_offset + bit_size))
+                        loc,
+                        view
+                    };
+                    if field.is_bit_packed() && field.view.is_transmute() && matches!(ty, FixedFieldType::Float32 | FixedFieldType::Float64) {
+                        return Err(Error::UnalignedArrayCodec);
+                    }
+                    Ok((Self::Array(field), last_bit_offset + bit_size))
                 } else {
                     Ok((Self::Fixed(FixedField {
                         name: value.name,
+                        doc: value.doc,
                         endianness: proto.endianness,
                         ty,
                         loc,
@@ -332,7 +438,8 @@ pub struct Structure {
     pub name: String,
     pub fields: Vec<Field>,
     pub byte_size: usize,
-    pub bit_size: usize
+    pub bit_size: usize,
+    pub doc: Option<String>
 }
 
 impl Structure {
@@ -353,7 +460,8 @@ impl Structure {
                 (last_bit_offset / 8) + 1
             } else {
                 last_bit_offset / 8
-            }
+            },
+            doc: value.doc
         })
     }
 }
@@ -29,19 +29,21 @@
 use std::rc::Rc;
 use crate::compiler::message::{Referenced, SizeInfo};
 use crate::compiler::{Error, Protocol};
-use crate::compiler::structure::{Field, FieldView, FixedField, Structure};
+use crate::compiler::structure::{Field, FieldView, FixedField, FixedFieldType, Structure};
 
 #[derive(Clone, Debug)]
 pub struct UnionField {
     pub name: String,
     pub case: usize,
-    pub item_type: Option<Referenced>
+    pub item_type: Option<Referenced>,
+    pub doc: Option<String>
 }
 
 impl UnionField {
     pub fn from_model(proto: &Protocol, discriminant: &FixedField, value: crate::model::union::UnionField) -> Result<Self, Error> {
         let case: usize = match &discriminant.view {
             FieldView::Float { .. } => return Err(Error::FloatInUnionDiscriminant),
+            FieldView::Flags(_) => return Err(Error::FlagsInUnionDiscriminant),
             FieldView::Enum(v) => v.variants_map.get(&value.case).map(|v| *v).ok_or_else(|| Error::InvalidUnionCase(value.case))?,
             FieldView::Transmute | FieldView::SignedCast { .. } => {
                 let value: isize = value.case.parse().map_err(|_| Error::InvalidUnionCase(value.case))?;
@@ -51,10 +53,14 @@ impl UnionField {
         };
         let item_type = value.item_type.map(|v| Referenced::lookup(proto, &v).ok_or_else(|| Error::UndefinedReference(v)))
             .transpose()?;
+        if let Some(Referenced::Message(v)) = &item_type {
+            v.check_embeddable()?;
+        }
         Ok(UnionField {
             name: value.name,
             case,
-            item_type
+            item_type,
+            doc: value.doc
         })
     }
 }
@@ -80,7 +86,7 @@ impl<'a> Iterator for DiscriminantFieldIterator<'a> {
         let field = &self.cur.fields[*index];
         let is_leaf = match field {
             Field::Fixed(_) => true,
-            Field::Array(_) => std::unreachable!(),
+            Field::Array(_) | Field::StructArray(_) => std::unreachable!(),
             Field::Struct(v) => {
                 self.cur = &v.r;
                 false
@@ -102,6 +108,11 @@ impl DiscriminantField {
         self.leaf.fields[self.leaf_index].as_fixed().unwrap()
     }
 
+    /// Returns the unsigned integer type the discriminant is stored as.
+    pub fn get_raw_type(&self) -> FixedFieldType {
+        self.get_leaf().loc.get_unsigned_integer_type()
+    }
+
     pub fn from_model(proto: &Protocol, discriminant: String) -> Result<Self, Error> {
         let mut parts = discriminant.split(".");
         let name = parts.next().ok_or(Error::InvalidUnionDiscriminant)?;
@@ -116,7 +127,7 @@ impl DiscriminantField {
             match field {
                 Field::Fixed(_) => break,
                 Field::Struct(v) => leaf = &v.r,
-                Field::Array(_) => return Err(Error::InvalidUnionDiscriminant)
+                Field::Array(_) | Field::StructArray(_) => return Err(Error::InvalidUnionDiscriminant)
             }
         }
         Ok(DiscriminantField {
@@ -133,14 +144,34 @@ pub struct Union  {
     pub name: String,
     pub discriminant: DiscriminantField,
     pub cases: Vec<UnionField>,
-    pub size: SizeInfo
+    pub size: SizeInfo,
+    pub unknown: bool,
+    pub doc: Option<String>
 }
 
 impl Union {
+    /// Returns true if any case of this union holds data.
+    pub fn has_data(&self) -> bool {
+        self.unknown || self.cases.iter().any(|v| v.item_type.is_some())
+    }
+
+    pub fn is_self_delimited(&self) -> bool {
+        !self.unknown && self.cases.iter().all(|v| match &v.item_type {
+            Some(Referenced::Message(v)) => v.is_self_delimited(),
+            _ => true
+        })
+    }
+
     pub fn from_model(proto: &Protocol, value: crate::model::union::Union) -> Result<Self, Error> {
         let discriminant = DiscriminantField::from_model(proto, value.discriminant)?;
         let cases = value.cases.into_iter().map(|v| UnionField::from_model(proto, discriminant.get_leaf(), v))
             .collect::<Result<Vec<UnionField>, Error>>()?;
+        let unknown = value.unknown.unwrap_or_default();
+        if unknown {
+            if let Some(case) = cases.iter().find(|v| v.name == "Unknown") {
+                return Err(Error::ReservedUnionCase(case.name.clone()));
+            }
+        }
         let is_element_dyn_sized = cases.iter().any(|v| v.item_type.as_ref().map(|v| match v {
             Referenced::Struct(_) => false,
             Referenced::Message(v) => v.size.is_element_dyn_sized
@@ -149,14 +180,17 @@ impl Union {
             Referenced::Struct(_) => false,
             Referenced::Message(v) => v.size.is_dyn_sized
         }).unwrap_or_default());
+        // Unknown cases extend to the end of the input, just like a payload.
         Ok(Union {
             name: value.name,
             discriminant,
             cases,
             size: SizeInfo {
-                is_element_dyn_sized,
-                is_dyn_sized
-            }
+                is_element_dyn_sized: is_element_dyn_sized || unknown,
+                is_dyn_sized: is_dyn_sized || unknown
+            },
+            unknown,
+            doc: value.doc
         })
     }
 }
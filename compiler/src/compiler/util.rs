@@ -64,6 +64,10 @@ impl TypePathMap {
             Some(v) => Cow::Borrowed(&*v)
         }
     }
+
+    pub fn type_paths(&self) -> impl Iterator<Item = &str> {
+        self.type_path_by_name.values().map(|v| &**v)
+    }
 }
 
 impl ImportResolver for () {
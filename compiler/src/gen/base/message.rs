@@ -27,7 +27,7 @@
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use itertools::Itertools;
-use crate::compiler::message::{Field, FieldType, Message, Referenced};
+use crate::compiler::message::{Field, FieldType, ItemListField, ItemType, Message, Referenced, ValueType};
 use crate::compiler::structure::FixedFieldType;
 use crate::compiler::util::TypePathMap;
 use crate::gen::template::Template;
@@ -50,12 +50,85 @@ pub trait Utilities: crate::gen::base::structure::Utilities {
     fn get_payload_type_inline() -> &'static str;
     fn gen_struct_ref_type(type_name: &str) -> String;
     fn gen_message_ref_type(type_name: &str) -> String;
+    fn gen_ref_type_inline(type_name: &str) -> String;
     fn gen_union_ref_type(type_name: &str) -> String;
+    fn gen_enum_codec(raw_codec: &str, type_name: &str) -> String;
+    fn gen_enum_codec_inline(raw_codec: &str, type_name: &str) -> String;
+    fn gen_list_item_type(ty: &str) -> String;
 }
 
-fn gen_field_decl<U: Utilities>(field: &Field, template: &Template, type_path_by_name: &TypePathMap) -> String {
+/// Renders the codec of the item count of a list, which is either a length prefix bounded by
+/// `max_len` or, for fixed lists, exactly `max_len` items without any prefix.
+pub fn gen_len_codec<U: Utilities>(endianness: Endianness, ty: FixedFieldType, max_len: usize, fixed: bool, template: &Template) -> String {
+    template.scope()
+        .var("codec", U::get_value_type(endianness, ty))
+        .var_d("max_len", max_len)
+        .render("", &[if fixed { "fixed_len" } else { "max_len" }]).unwrap().trim_end().into()
+}
+
+/// Generates the type of a plain value once decoded.
+pub fn gen_value_type<U: Utilities>(ty: &ValueType, type_path_by_name: &TypePathMap) -> String {
+    match ty {
+        ValueType::Scalar(ty) => U::get_field_type(*ty).into(),
+        ValueType::Enum(v) => type_path_by_name.get(&v.name).into()
+    }
+}
+
+/// Generates the codec of a plain value.
+pub fn gen_value_codec<U: Utilities>(endianness: Endianness, ty: &ValueType, type_path_by_name: &TypePathMap) -> String {
+    match ty {
+        ValueType::Scalar(ty) => U::get_value_type(endianness, *ty).into(),
+        ValueType::Enum(v) => U::gen_enum_codec(U::get_value_type(endianness, v.ty), type_path_by_name.get(&v.name))
+    }
+}
+
+/// Generates the type of a single list item once decoded.
+pub fn gen_item_type<U: Utilities>(endianness: Endianness, ty: &ItemType, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let item_type = match ty {
+        ItemType::Struct(v) => U::gen_struct_ref_type(type_path_by_name.get(&v.name)),
+        ItemType::Message(v) => U::gen_message_ref_type(type_path_by_name.get(&v.name)),
+        ItemType::Value(v) => gen_value_type::<U>(v, type_path_by_name),
+        ItemType::NullTerminatedString => U::get_string_type(StringType::NullTerminated).into(),
+        ItemType::VarcharString(_) => U::get_string_type(StringType::Varchar).into(),
+        ItemType::List(v) => gen_item_list_type::<U>(endianness, v, template, type_path_by_name)
+    };
+    U::gen_list_item_type(&item_type)
+}
+
+/// Generates the codec of a single list item.
+///
+/// Returns None for structures and messages as lists of those are rendered with the `array` and
+/// `list` fragments instead.
+pub fn gen_item_codec<U: Utilities>(endianness: Endianness, ty: &ItemType, template: &Template, type_path_by_name: &TypePathMap) -> Option<String> {
+    match ty {
+        ItemType::Struct(_) | ItemType::Message(_) => None,
+        ItemType::Value(v) => Some(gen_value_codec::<U>(endianness, v, type_path_by_name)),
+        ItemType::NullTerminatedString => Some(U::get_string_type_inline(StringType::NullTerminated).into()),
+        ItemType::VarcharString(v) => Some(template.scope()
+            .var("codec", U::get_value_type(endianness, v.ty))
+            .var_d("max_len", v.max_len)
+            .render("", &["varchar"]).unwrap().trim_end().into()),
+        ItemType::List(v) => Some(gen_item_list_type::<U>(endianness, v, template, type_path_by_name))
+    }
+}
+
+/// Renders the type or codec, depending on the template, of a list of items.
+pub fn gen_item_list_type<U: Utilities>(endianness: Endianness, v: &ItemListField, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let mut scope = template.scope();
+    scope.var("len", gen_len_codec::<U>(endianness, v.ty, v.max_len, v.fixed, template));
+    match (&v.item_type, gen_item_codec::<U>(endianness, &v.item_type, template, type_path_by_name)) {
+        (ItemType::Struct(r), _) => scope.var("type_name", type_path_by_name.get(&r.name)).render("", &["array"]),
+        (ItemType::Message(r), _) => scope.var("type_name", type_path_by_name.get(&r.name)).render("", &["list"]),
+        (item_type, item_codec) => scope.var("item_codec", item_codec.unwrap_or_default())
+            .var("type_name", gen_item_type::<U>(endianness, item_type, template, type_path_by_name))
+            .render("", &["items"])
+    }.unwrap().trim_end().into()
+}
+
+pub fn gen_field_type<U: Utilities>(field: &Field, template: &Template, type_path_by_name: &TypePathMap) -> String {
     let msg_type = match &field.ty {
         FieldType::Fixed(ty) => U::get_field_type(ty.ty).into(),
+        FieldType::Enum(v) => type_path_by_name.get(&v.name).into(),
         FieldType::Ref(v) => match v {
             Referenced::Struct(v) => U::gen_struct_ref_type(type_path_by_name.get(&v.name)),
             Referenced::Message(v) => U::gen_message_ref_type(type_path_by_name.get(&v.name)),
@@ -63,21 +136,25 @@ fn gen_field_decl<U: Utilities>(field: &Field, template: &Template, type_path_by
         FieldType::NullTerminatedString => U::get_string_type(StringType::NullTerminated).into(),
         FieldType::VarcharString(_) => U::get_string_type(StringType::Varchar).into(),
         FieldType::Array(v) => template.scope()
-            .var("codec", U::get_value_type(field.endianness, v.ty))
+            .var("len", gen_len_codec::<U>(field.endianness, v.ty, v.max_len, v.fixed, template))
             .var("type_name", type_path_by_name.get(&v.item_type.name))
-            .render("", &["array"]).unwrap(),
+            .render("", &["array"]).unwrap().trim_end().into(),
         FieldType::Union(v) => U::gen_union_ref_type(type_path_by_name.get(&v.r.name)),
         FieldType::List(v) => template.scope()
-            .var("codec", U::get_value_type(field.endianness, v.ty))
+            .var("len", gen_len_codec::<U>(field.endianness, v.ty, v.max_len, v.fixed, template))
             .var("type_name", type_path_by_name.get(&v.item_type.name))
-            .render("", &["list"]).unwrap(),
+            .render("", &["list"]).unwrap().trim_end().into(),
+        FieldType::ItemList(v) => gen_item_list_type::<U>(field.endianness, v, template, type_path_by_name),
         FieldType::Payload => U::get_payload_type().into()
     };
-    let msg_type = match field.optional {
+    match field.optional {
         true => U::gen_option_type(&msg_type),
         false => msg_type
-    };
-    template.scope().var("name", &field.name).var("type", msg_type)
+    }
+}
+
+fn gen_field_decl<U: Utilities>(field: &Field, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    template.scope().var("name", &field.name).var("type", gen_field_type::<U>(field, template, type_path_by_name))
         .render("decl", &["field"]).unwrap()
 }
 
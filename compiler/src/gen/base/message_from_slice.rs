@@ -30,7 +30,7 @@ use std::borrow::Cow;
 use itertools::Itertools;
 use crate::compiler::message::{Field, FieldType, Message, Referenced};
 use crate::compiler::util::TypePathMap;
-use crate::gen::base::message::{StringType, Utilities};
+use crate::gen::base::message::{gen_item_list_type, gen_len_codec, StringType, Utilities};
 use crate::gen::template::Template;
 
 fn gen_optional<'a, U: Utilities>(optional: bool, type_name: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
@@ -45,17 +45,19 @@ pub fn generate_field_type_inline<'a, U: Utilities>(msg: &Message, field: &'a Fi
     let mut union = None;
     let msg_type = match &field.ty {
         FieldType::Fixed(ty) => gen_optional::<U>(field.optional, U::get_value_type_inline(field.endianness, ty.ty)),
+        FieldType::Enum(v) => gen_optional::<U>(field.optional, U::gen_enum_codec_inline(U::get_value_type(field.endianness, v.ty), type_path_by_name.get(&v.name))),
         FieldType::Ref(v) => match v {
-            Referenced::Struct(v) => gen_optional::<U>(field.optional, type_path_by_name.get(&v.name)),
-            Referenced::Message(v) => gen_optional::<U>(field.optional, type_path_by_name.get(&v.name))
+            Referenced::Struct(v) => gen_optional::<U>(field.optional, U::gen_ref_type_inline(type_path_by_name.get(&v.name))),
+            Referenced::Message(v) => gen_optional::<U>(field.optional, U::gen_ref_type_inline(type_path_by_name.get(&v.name)))
         }
         FieldType::NullTerminatedString => gen_optional::<U>(field.optional, U::get_string_type_inline(StringType::NullTerminated)),
         FieldType::VarcharString(v) => gen_optional::<U>(field.optional, template.scope()
-            .var("codec", U::get_value_type(field.endianness, v.ty)).render("", &["varchar"]).unwrap()),
-        FieldType::Array(v) => gen_optional::<U>(field.optional, template.scope()
             .var("codec", U::get_value_type(field.endianness, v.ty))
+            .var_d("max_len", v.max_len).render("", &["varchar"]).unwrap().trim_end().to_string()),
+        FieldType::Array(v) => gen_optional::<U>(field.optional, template.scope()
+            .var("len", gen_len_codec::<U>(field.endianness, v.ty, v.max_len, v.fixed, template))
             .var("type_name", type_path_by_name.get(&v.item_type.name))
-            .render("", &["array"]).unwrap()),
+            .render("", &["array"]).unwrap().trim_end().to_string()),
         FieldType::Union(v) => {
             union = Some(&*v.on_name);
             gen_optional::<U>(field.optional, type_path_by_name.get(&v.r.name))
@@ -63,15 +65,16 @@ pub fn generate_field_type_inline<'a, U: Utilities>(msg: &Message, field: &'a Fi
         FieldType::List(v) => {
             match msg.is_embedded() {
                 false => gen_optional::<U>(field.optional, template.scope()
-                    .var("codec", U::get_value_type(field.endianness, v.ty))
+                    .var("len", gen_len_codec::<U>(field.endianness, v.ty, v.max_len, v.fixed, template))
                     .var("type_name", type_path_by_name.get(&v.item_type.name))
-                    .render("", &["unsized"]).unwrap()),
+                    .render("", &["unsized"]).unwrap().trim_end().to_string()),
                 true => gen_optional::<U>(field.optional, template.scope()
-                    .var("codec", U::get_value_type(field.endianness, v.ty))
+                    .var("len", gen_len_codec::<U>(field.endianness, v.ty, v.max_len, v.fixed, template))
                     .var("type_name", type_path_by_name.get(&v.item_type.name))
-                    .render("", &["list"]).unwrap()),
+                    .render("", &["list"]).unwrap().trim_end().to_string()),
             }
         },
+        FieldType::ItemList(v) => gen_optional::<U>(field.optional, gen_item_list_type::<U>(field.endianness, v, template, type_path_by_name)),
         FieldType::Payload => gen_optional::<U>(field.optional, U::get_payload_type_inline())
     };
     (msg_type, union)
@@ -103,8 +106,78 @@ pub fn generate_from_slice_impl<U: Utilities>(msg: &Message, template: &Template
         .render("", &["impl"]).unwrap()
 }
 
+fn gen_field_from_slice_with_limits_impl<U: Utilities>(msg: &Message, field: &Field, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let mut scope = template.scope();
+    scope.var("name", &field.name);
+    let (msg_type, union) = generate_field_type_inline::<U>(msg, field, template, type_path_by_name);
+    scope.var("type", msg_type);
+    match union {
+        Some(on_name) => scope.var("on_name", on_name).render("limits", &["field_union"]).unwrap(),
+        None => scope.render("limits", &["field"]).unwrap()
+    }
+}
+
+pub fn generate_from_slice_with_limits_impl<U: Utilities>(msg: &Message, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let fields = msg.fields.iter().map(|field|
+        gen_field_from_slice_with_limits_impl::<U>(msg, field, template, type_path_by_name)).join("");
+    let field_names = msg.fields.iter().map(|field| template.scope()
+        .var("name", &field.name).render("impl", &["field_name"]).unwrap()).join("");
+    template.scope().var("fields", fields).var("field_names", field_names)
+        .render("", &["limits"]).unwrap()
+}
+
+fn gen_field_measure_impl<U: Utilities>(msg: &Message, index: usize, field: &Field, offsets: &str, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let mut scope = template.scope();
+    scope.var("name", &field.name).var_d("index", index).var("offsets", offsets);
+    let (msg_type, union) = generate_field_type_inline::<U>(msg, field, template, type_path_by_name);
+    scope.var("type", msg_type);
+    // The first field is always measured, following ones unless a previous call saved a later one.
+    let mut code = scope.render("measure", &[if index == 0 { "first" } else { "next" }]).unwrap();
+    if msg.is_discriminant(field) {
+        code += &scope.render("measure", &["field_offset"]).unwrap();
+    }
+    if let Some(on_name) = union {
+        let on_field = msg.fields.iter().find(|v| v.name == on_name).unwrap();
+        let (on_type, _) = generate_field_type_inline::<U>(msg, on_field, template, type_path_by_name);
+        scope.var("on_name", on_name).var("on_type", on_type);
+        code += &scope.render("measure", &["field_union"]).unwrap();
+    } else {
+        code += &scope.render("measure", &["field"]).unwrap();
+    }
+    code
+}
+
+pub fn generate_measure_impl<U: Utilities>(msg: &Message, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    // Offsets of discriminants are saved along the field and byte offset, as a resumed
+    // measurement does not go through the discriminant fields again.
+    let discriminants = msg.fields.iter().filter(|field| msg.is_discriminant(field));
+    let offset_vars = discriminants.clone().map(|field| template.scope().var("name", &field.name)
+        .render("measure", &["offset_var"]).unwrap().trim_end().to_string()).join("");
+    let offsets = discriminants.map(|field| template.scope().var("name", &field.name)
+        .render("measure", &["offset"]).unwrap().trim_end().to_string()).join("");
+    let fields = msg.fields.iter().enumerate().map(|(index, field)|
+        gen_field_measure_impl::<U>(msg, index, field, &offsets, template, type_path_by_name)).join("");
+    template.scope().var("fields", fields).var("offset_vars", offset_vars)
+        .render("", &["measure"]).unwrap()
+}
+
 pub fn generate<U: Utilities>(template: &[u8], msg: &Message, type_path_by_name: &TypePathMap) -> String {
     let mut template = Template::compile(template).unwrap();
     template.var("msg_name", &msg.name).var("generics", U::gen_generics(msg));
     generate_from_slice_impl::<U>(msg, &template, type_path_by_name)
 }
+
+/// Generates the FromSlice implementation including an override of from_slice_with_limits.
+pub fn generate_with_limits<U: Utilities>(template: &[u8], msg: &Message, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(template).unwrap();
+    template.var("msg_name", &msg.name).var("generics", U::gen_generics(msg));
+    let limits = generate_from_slice_with_limits_impl::<U>(msg, &template, type_path_by_name);
+    template.var("from_slice_with_limits", limits);
+    generate_from_slice_impl::<U>(msg, &template, type_path_by_name)
+}
+
+pub fn generate_measure<U: Utilities>(template: &[u8], msg: &Message, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(template).unwrap();
+    template.var("msg_name", &msg.name).var("generics", U::gen_generics(msg));
+    generate_measure_impl::<U>(msg, &template, type_path_by_name)
+}
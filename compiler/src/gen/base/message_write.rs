@@ -33,7 +33,7 @@ use crate::gen::base::message::Utilities;
 use crate::gen::base::message_from_slice::generate_field_type_inline;
 use crate::gen::template::Template;
 
-fn gen_field_write_impl<U: Utilities>(msg: &Message, field: &Field, template: &Template, type_path_by_name: &TypePathMap) -> String {
+fn gen_field_write_impl<U: Utilities>(msg: &Message, field: &Field, template: &Template, fragment: &str, type_path_by_name: &TypePathMap) -> String {
     let mut scope = template.scope();
     scope.var("name", &field.name);
     let (msg_type, union) = generate_field_type_inline::<U>(msg, field, template, type_path_by_name);
@@ -42,15 +42,20 @@ fn gen_field_write_impl<U: Utilities>(msg: &Message, field: &Field, template: &T
     }
     scope.var("type", msg_type);
     if union.is_some() {
-        scope.render("impl", &["field_union"]).unwrap()
+        scope.render(fragment, &["field_union"]).unwrap()
     } else {
-        scope.render("impl", &["field"]).unwrap()
+        scope.render(fragment, &["field"]).unwrap()
     }
 }
 
 pub fn generate<U: Utilities>(template: &[u8], msg: &Message, type_path_by_name: &TypePathMap) -> String {
+    generate_fragment::<U>(template, "impl", msg, type_path_by_name)
+}
+
+/// Same as [generate] but renders the given fragment instead of `impl`.
+pub fn generate_fragment<U: Utilities>(template: &[u8], fragment: &str, msg: &Message, type_path_by_name: &TypePathMap) -> String {
     let mut template = Template::compile(template).unwrap();
     template.var("msg_name", &msg.name).var("generics", U::gen_generics(msg));
-    let fields = msg.fields.iter().map(|field| gen_field_write_impl::<U>(msg, field, &template, type_path_by_name)).join("");
-    template.var("fields", fields).render("", &["impl"]).unwrap()
+    let fields = msg.fields.iter().map(|field| gen_field_write_impl::<U>(msg, field, &template, fragment, type_path_by_name)).join("");
+    template.var("fields", fields).render("", &[fragment]).unwrap()
 }
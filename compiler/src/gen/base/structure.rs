@@ -27,7 +27,8 @@
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use itertools::Itertools;
-use crate::compiler::structure::{Field, FieldView, FixedField, FixedFieldType, Structure};
+use crate::compiler::flags::Flags;
+use crate::compiler::structure::{Field, FieldView, FixedArrayField, FixedField, FixedFieldType, Structure};
 use crate::compiler::util::TypePathMap;
 use crate::gen::template::{Scope, Template};
 use crate::model::protocol::Endianness;
@@ -39,6 +40,8 @@ pub trait Utilities {
     fn get_bit_codec_inline(endianness: Endianness) -> &'static str;
     fn get_byte_codec_inline(endianness: Endianness) -> &'static str;
     fn get_byte_codec(endianness: Endianness) -> &'static str;
+    fn get_bit_codec(endianness: Endianness) -> &'static str;
+    fn get_generic_type(field_type: FixedFieldType) -> &'static str;
 }
 
 fn gen_field_getter<U: Utilities>(field: &Field, template: &Template, type_path_by_name: &TypePathMap) -> String {
@@ -51,7 +54,8 @@ fn gen_field_getter<U: Utilities>(field: &Field, template: &Template, type_path_
             let raw_field_type = v.loc.get_unsigned_integer_type();
             let raw_field_type = U::get_field_type(raw_field_type);
             let function_name = U::get_function_name(v);
-            scope.var("raw_type", raw_field_type).var("function_name", function_name);
+            scope.var("raw_type", raw_field_type).var("function_name", function_name)
+                .var_d("raw_size", v.loc.get_unsigned_integer_type().get_byte_size());
             if v.loc.bit_size % 8 != 0 {
                 scope.var("codec", U::get_bit_codec_inline(v.endianness))
                     .var_d("bit_offset", v.loc.bit_offset)
@@ -65,11 +69,12 @@ fn gen_field_getter<U: Utilities>(field: &Field, template: &Template, type_path_
             code += &gen_field_view_getter::<U>(v, &scope, type_path_by_name);
             code
         }
-        Field::Array(v) => scope.var("raw_type", U::get_field_type(v.ty))
-            .var("codec", U::get_byte_codec(v.endianness)).var_d("bit_size", v.item_bit_size())
-            .render("getters", &["array"]).unwrap(),
+        Field::Array(v) => gen_array_field::<U>(v, scope, "getters", type_path_by_name),
         Field::Struct(v) => scope.var("type_name", type_path_by_name.get(&v.r.name))
-            .render("getters", &["struct"]).unwrap()
+            .render("getters", &["struct"]).unwrap(),
+        Field::StructArray(v) => scope.var("type_name", type_path_by_name.get(&v.r.name))
+            .var_d("item_size", v.r.byte_size).var_d("array_len", v.array_len)
+            .render("getters", &["struct_array"]).unwrap()
     }
 }
 
@@ -83,7 +88,8 @@ fn gen_field_setter<U: Utilities>(field: &Field, template: &Template, type_path_
             let raw_field_type = v.loc.get_unsigned_integer_type();
             let raw_field_type = U::get_field_type(raw_field_type);
             let function_name = U::get_function_name_mut(v);
-            scope.var("raw_type", raw_field_type).var("function_name", function_name);
+            scope.var("raw_type", raw_field_type).var("function_name", function_name)
+                .var_d("raw_size", v.loc.get_unsigned_integer_type().get_byte_size());
             if v.loc.bit_size % 8 != 0 {
                 scope.var("codec", U::get_bit_codec_inline(v.endianness))
                     .var_d("bit_offset", v.loc.bit_offset)
@@ -97,14 +103,87 @@ fn gen_field_setter<U: Utilities>(field: &Field, template: &Template, type_path_
             code += &gen_field_view_setter::<U>(v, &scope, type_path_by_name);
             code
         }
-        Field::Array(v) => scope.var("raw_type", U::get_field_type(v.ty))
-            .var("codec", U::get_byte_codec(v.endianness)).var_d("bit_size", v.item_bit_size())
-            .render("setters", &["array"]).unwrap(),
+        Field::Array(v) => gen_array_field::<U>(v, scope, "setters", type_path_by_name),
         Field::Struct(v) => scope.var("type_name", type_path_by_name.get(&v.r.name))
-            .render("setters", &["struct"]).unwrap()
+            .render("setters", &["struct"]).unwrap(),
+        Field::StructArray(v) => scope.var("type_name", type_path_by_name.get(&v.r.name))
+            .var_d("item_size", v.r.byte_size).var_d("array_len", v.array_len)
+            .render("setters", &["struct_array"]).unwrap()
     }
 }
 
+/// Renders the accessor of an array field in the given section (getters or setters); arrays with a
+/// view get a raw accessor followed by an accessor converting items through the item_view fragment.
+fn gen_array_field<'a, U: Utilities>(field: &'a FixedArrayField, mut scope: Scope<'_, '_, 'a>, section: &str, type_path_by_name: &'a TypePathMap) -> String {
+    let raw_type = field.raw_item_type();
+    scope.var("raw_type", U::get_field_type(raw_type)).var_d("bit_size", field.item_bit_size());
+    let (kind, codec_kind) = match field.is_bit_packed() {
+        true => {
+            scope.var_d("end", field.loc.byte_offset + field.byte_span())
+                .var("codec", U::get_bit_codec(field.endianness))
+                .var_d("bit_offset", field.loc.bit_offset).var_d("array_len", field.array_len);
+            ("bit_array", "bit")
+        },
+        false => {
+            scope.var_d("raw_size", raw_type.get_byte_size()).var("codec", U::get_byte_codec(field.endianness));
+            ("array", "byte")
+        }
+    };
+    if !field.has_view() {
+        return scope.render(section, &[kind]).unwrap();
+    }
+    scope.var("name", format!("raw_{}", field.name));
+    let mut code = scope.render(section, &[kind]).unwrap();
+    scope.var("name", &field.name);
+    let view_kind = match &field.view {
+        FieldView::Float { a, b, a_inv, b_inv } => {
+            scope.var("view_type", U::get_field_type(field.ty))
+                .var("a", format!("{:?}", a)).var("b", format!("{:?}", b))
+                .var("a_inv", format!("{:?}", a_inv)).var("b_inv", format!("{:?}", b_inv));
+            "float"
+        },
+        FieldView::Enum(e) => {
+            scope.var("view_type", type_path_by_name.get(&e.name));
+            "enum"
+        },
+        FieldView::Flags(f) => {
+            gen_flags_view::<U>(&mut scope, raw_type, f, type_path_by_name);
+            "flags"
+        },
+        FieldView::SignedCast(max_positive) => {
+            scope.var("view_type", U::get_field_type(field.ty)).var_d("max_positive", max_positive);
+            "signed"
+        },
+        FieldView::Transmute | FieldView::None => {
+            scope.var("view_type", U::get_field_type(field.ty));
+            match field.ty {
+                FixedFieldType::Bool => "bool",
+                _ => "cast"
+            }
+        }
+    };
+    scope.var("generic_type", U::get_generic_type(field.ty));
+    let path = format!("item_view.{}", view_kind);
+    for (fragment, var) in [("get", "from_raw"), ("set", "to_raw"), ("output", "output_type")] {
+        let value = scope.render(&path, &[fragment]).unwrap();
+        scope.var(var, value.trim().to_string());
+    }
+    let array_type = scope.render(&format!("{}.array_view", section), &[codec_kind]).unwrap();
+    scope.var("array_type", array_type.trim().to_string());
+    code += &scope.render(section, &["array_view"]).unwrap();
+    code
+}
+
+fn gen_flags_view<U: Utilities>(scope: &mut Scope, raw_type: FixedFieldType, f: &Flags, type_path_by_name: &TypePathMap) {
+    // The raw field may be wider than the flags type, in which case values must be cast.
+    let (flags_cast, raw_cast) = match f.ty == raw_type {
+        true => (String::new(), String::new()),
+        false => (format!(" as {}", U::get_field_type(f.ty)), format!(" as {}", U::get_field_type(raw_type)))
+    };
+    scope.var("view_type", type_path_by_name.get(&f.name).to_string())
+        .var("flags_cast", flags_cast).var("raw_cast", raw_cast);
+}
+
 fn gen_field_view_getter<U: Utilities>(field: &FixedField, scope: &Scope, type_path_by_name: &TypePathMap) -> String {
     let mut scope = scope.clone();
     match &field.view {
@@ -112,7 +191,11 @@ fn gen_field_view_getter<U: Utilities>(field: &FixedField, scope: &Scope, type_p
             .var("a", format!("{:?}", a)).var("b", format!("{:?}", b))
             .render("getters", &["view_float"]).unwrap(),
         FieldView::Enum(e) => scope.var("view_type", type_path_by_name.get(&e.name))
-            .var_d("enum_largest", e.largest).render("getters", &["view_enum"]).unwrap(),
+            .render("getters", &["view_enum"]).unwrap(),
+        FieldView::Flags(f) => {
+            gen_flags_view::<U>(&mut scope, field.loc.get_unsigned_integer_type(), f, type_path_by_name);
+            scope.render("getters", &["view_flags"]).unwrap()
+        },
         FieldView::Transmute => {
             let field_type = U::get_field_type(field.ty);
             scope.var("view_type", field_type);
@@ -136,7 +219,11 @@ fn gen_field_view_setter<U: Utilities>(field: &FixedField, scope: &Scope, type_p
             .var("a_inv", format!("{:?}", a_inv)).var("b_inv", format!("{:?}", b_inv))
             .render("setters", &["view_float"]).unwrap(),
         FieldView::Enum(e) => scope.var("view_type", type_path_by_name.get(&e.name))
-            .var_d("enum_largest", e.largest).render("setters", &["view_enum"]).unwrap(),
+            .render("setters", &["view_enum"]).unwrap(),
+        FieldView::Flags(f) => {
+            gen_flags_view::<U>(&mut scope, field.loc.get_unsigned_integer_type(), f, type_path_by_name);
+            scope.render("setters", &["view_flags"]).unwrap()
+        },
         FieldView::Transmute | FieldView::SignedCast { .. } => {
             let field_type = U::get_field_type(field.ty);
             scope.var("view_type", field_type);
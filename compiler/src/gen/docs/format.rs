@@ -0,0 +1,175 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::borrow::Cow;
+use itertools::Itertools;
+use crate::gen::template::Template;
+
+const MARKDOWN_TEMPLATE: &[u8] = include_bytes!("./markdown.template");
+const HTML_TEMPLATE: &[u8] = include_bytes!("./html.template");
+
+/// The output format of the documentation generator.
+///
+/// All text passed to the formatting functions is expected to be already escaped using
+/// [escape](DocFormat::escape), except for [preformatted](DocFormat::preformatted) which is
+/// inserted verbatim.
+pub trait DocFormat {
+    const EXTENSION: &'static str;
+
+    fn new() -> Self;
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str>;
+    fn document(&self, title: &str, body: String) -> String;
+    fn table(&self, head: &[&str], rows: &[Vec<String>]) -> String;
+    fn template(&self) -> &Template<'static, 'static>;
+
+    fn heading(&self, level: usize, anchor: &str, text: &str) -> String {
+        self.template().scope().var("anchor", anchor.to_string()).var("text", text.to_string())
+            .render("", &[&format!("heading{}", level)]).unwrap()
+    }
+
+    fn paragraph(&self, text: &str) -> String {
+        self.template().scope().var("text", text.to_string()).render("", &["paragraph"]).unwrap()
+    }
+
+    fn link(&self, text: &str, href: &str) -> String {
+        self.template().scope().var("text", text.to_string()).var("href", href.to_string())
+            .render("", &["link"]).unwrap().trim_end().into()
+    }
+
+    fn code(&self, text: &str) -> String {
+        self.template().scope().var("text", text.to_string()).render("", &["code"]).unwrap().trim_end().into()
+    }
+
+    fn preformatted(&self, text: &str) -> String {
+        self.template().scope().var("text", text.to_string()).render("", &["preformatted"]).unwrap()
+    }
+}
+
+fn gen_row(template: &Template, fragment: &str, cell: &str, values: impl Iterator<Item = impl AsRef<str>>) -> String {
+    let cells = values.map(|v| template.scope().var("value", v.as_ref().to_string())
+        .render("table", &[cell]).unwrap().trim_end().to_string()).join("");
+    template.scope().var("cells", cells).render("table", &[fragment]).unwrap()
+}
+
+pub struct Markdown {
+    template: Template<'static, 'static>
+}
+
+impl DocFormat for Markdown {
+    const EXTENSION: &'static str = "md";
+
+    fn new() -> Self {
+        Self {
+            template: Template::compile(MARKDOWN_TEMPLATE).unwrap()
+        }
+    }
+
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !text.contains(['\\', '`', '*', '_', '[', ']', '<', '>', '|', '\n']) {
+            return text.into();
+        }
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' => {
+                    out.push('\\');
+                    out.push(c);
+                },
+                // Markdown tables cannot span multiple lines.
+                '\n' => out.push(' '),
+                _ => out.push(c)
+            }
+        }
+        out.into()
+    }
+
+    fn document(&self, _: &str, body: String) -> String {
+        body
+    }
+
+    fn table(&self, head: &[&str], rows: &[Vec<String>]) -> String {
+        let rows = rows.iter().map(|row| gen_row(&self.template, "row", "cell", row.iter())).join("");
+        self.template.scope()
+            .var("head", gen_row(&self.template, "row", "cell", head.iter()).trim_end().to_string())
+            .var("separator", gen_row(&self.template, "row", "separator", head.iter()).trim_end().to_string())
+            .var("rows", rows.trim_end().to_string())
+            .render("", &["table"]).unwrap()
+    }
+
+    fn template(&self) -> &Template<'static, 'static> {
+        &self.template
+    }
+}
+
+pub struct Html {
+    template: Template<'static, 'static>
+}
+
+impl DocFormat for Html {
+    const EXTENSION: &'static str = "html";
+
+    fn new() -> Self {
+        Self {
+            template: Template::compile(HTML_TEMPLATE).unwrap()
+        }
+    }
+
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !text.contains(['&', '<', '>', '"']) {
+            return text.into();
+        }
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '"' => out.push_str("&quot;"),
+                _ => out.push(c)
+            }
+        }
+        out.into()
+    }
+
+    fn document(&self, title: &str, body: String) -> String {
+        self.template.scope().var("title", title.to_string()).var("body", body.trim_end().to_string())
+            .render("", &["document"]).unwrap()
+    }
+
+    fn table(&self, head: &[&str], rows: &[Vec<String>]) -> String {
+        let rows = rows.iter().map(|row| gen_row(&self.template, "row", "cell", row.iter())).join("");
+        self.template.scope()
+            .var("head", gen_row(&self.template, "head", "head_cell", head.iter()).trim_end().to_string())
+            .var("rows", rows.trim_end().to_string())
+            .render("", &["table"]).unwrap()
+    }
+
+    fn template(&self) -> &Template<'static, 'static> {
+        &self.template
+    }
+}
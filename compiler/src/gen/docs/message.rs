@@ -0,0 +1,151 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::compiler::message::{Field, FieldType, ItemType, Message, Referenced, ValueType};
+use crate::compiler::structure::FixedFieldType;
+use crate::compiler::util::TypePathMap;
+use crate::gen::docs::format::DocFormat;
+use crate::gen::docs::util::{gen_bytes, gen_doc, gen_type_link, get_type_name};
+
+/// Returns the type name of a list item.
+fn gen_item_name<F: DocFormat>(format: &F, ty: &ItemType, type_path_by_name: &TypePathMap) -> String {
+    match ty {
+        ItemType::Struct(v) => gen_type_link(format, type_path_by_name, &v.name),
+        ItemType::Message(v) => gen_type_link(format, type_path_by_name, &v.name),
+        ItemType::Value(ValueType::Scalar(v)) => get_type_name(*v).into(),
+        ItemType::Value(ValueType::Enum(v)) => gen_type_link(format, type_path_by_name, &v.name),
+        ItemType::NullTerminatedString | ItemType::VarcharString(_) => "string".into(),
+        ItemType::List(v) => format!("list of {}", gen_item_name(format, &v.item_type, type_path_by_name))
+    }
+}
+
+/// Returns the encoding and size columns of a list whose items have the given size, if fixed.
+fn gen_list_info(ty: FixedFieldType, max_len: usize, fixed: bool, item_size: Option<usize>) -> [String; 2] {
+    match (fixed, item_size) {
+        (false, Some(size)) => [
+            format!("{} item count followed by the items", get_type_name(ty)),
+            format!("{} + count × {}", ty.get_byte_size(), size)
+        ],
+        (false, None) => [
+            format!("{} item count followed by the items", get_type_name(ty)),
+            format!("{} + size of each item", ty.get_byte_size())
+        ],
+        (true, Some(size)) => [format!("exactly {} items, without item count", max_len), gen_bytes(max_len * size)],
+        (true, None) => [format!("exactly {} items, without item count", max_len), "size of each item".into()]
+    }
+}
+
+/// Returns the type, encoding and size columns of a message field.
+fn gen_field_info<F: DocFormat>(format: &F, field: &Field, type_path_by_name: &TypePathMap) -> [String; 3] {
+    let link = |name: &str| gen_type_link(format, type_path_by_name, name);
+    match &field.ty {
+        FieldType::Fixed(v) => [
+            get_type_name(v.ty).into(),
+            "value".into(),
+            gen_bytes(v.ty.get_byte_size())
+        ],
+        FieldType::Enum(v) => [
+            link(&v.name),
+            format!("{} value", get_type_name(v.ty)),
+            gen_bytes(v.ty.get_byte_size())
+        ],
+        FieldType::Ref(Referenced::Struct(v)) => [link(&v.name), "structure".into(), gen_bytes(v.byte_size)],
+        FieldType::Ref(Referenced::Message(v)) => [
+            link(&v.name),
+            "nested message".into(),
+            match v.size.is_dyn_sized {
+                true => "variable".into(),
+                false => "fixed".into()
+            }
+        ],
+        FieldType::NullTerminatedString => [
+            "string".into(),
+            "UTF-8 bytes followed by a null byte".into(),
+            "length + 1".into()
+        ],
+        FieldType::VarcharString(v) => [
+            "string".into(),
+            format!("{} byte length followed by UTF-8 bytes", get_type_name(v.ty)),
+            format!("{} + length", v.ty.get_byte_size())
+        ],
+        FieldType::Array(v) => {
+            let [encoding, size] = gen_list_info(v.ty, v.max_len, v.fixed, Some(v.item_type.byte_size));
+            [format!("list of {}", link(&v.item_type.name)), encoding, size]
+        },
+        FieldType::List(v) => {
+            let [encoding, size] = gen_list_info(v.ty, v.max_len, v.fixed, None);
+            [format!("list of {}", link(&v.item_type.name)), encoding, size]
+        },
+        FieldType::ItemList(v) => {
+            let item_size = match &v.item_type {
+                ItemType::Value(item) => Some(item.raw_type().get_byte_size()),
+                _ => None
+            };
+            let [encoding, size] = gen_list_info(v.ty, v.max_len, v.fixed, item_size);
+            [format!("list of {}", gen_item_name(format, &v.item_type, type_path_by_name)), encoding, size]
+        },
+        FieldType::Union(v) => [
+            link(&v.r.name),
+            format!("case selected by {}", format.code(&format.escape(&v.on_name))),
+            "size of the selected case".into()
+        ],
+        FieldType::Payload => [
+            "bytes".into(),
+            "raw bytes up to the end of the message".into(),
+            "remaining bytes".into()
+        ]
+    }
+}
+
+pub fn gen_message_doc<F: DocFormat>(format: &F, msg: &Message, type_path_by_name: &TypePathMap) -> Vec<String> {
+    let mut blocks = vec![format.heading(3, &msg.name, &format.escape(&msg.name))];
+    if let Some(doc) = &msg.doc {
+        blocks.push(format.paragraph(&gen_doc(format, Some(doc))));
+    }
+    let size = match msg.size.is_dyn_sized {
+        true => "Variable size; fields are encoded in order without padding.",
+        false => "Fixed size; fields are encoded in order without padding."
+    };
+    blocks.push(format.paragraph(size));
+    let rows: Vec<Vec<String>> = msg.fields.iter().map(|field| {
+        let [ty, mut encoding, mut size] = gen_field_info(format, field, type_path_by_name);
+        if field.optional {
+            encoding = format!("presence byte (0 when absent), then {}", encoding);
+            size = format!("1 + ({}) when present, 1 otherwise", size);
+        }
+        vec![
+            format.code(&format.escape(&field.name)),
+            ty,
+            encoding,
+            size,
+            gen_doc(format, field.doc.as_deref())
+        ]
+    }).collect();
+    blocks.push(format.table(&["Field", "Type", "Encoding", "Size", "Description"], &rows));
+    blocks
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod format;
+mod util;
+mod structure;
+mod r#enum;
+mod flags;
+mod union;
+mod message;
+
+use bp3d_util::simple_error;
+use crate::compiler::Protocol;
+use crate::gen::{File, FileType, Generator};
+use crate::gen::docs::format::{DocFormat, Html, Markdown};
+use crate::gen::docs::message::gen_message_doc;
+use crate::gen::docs::r#enum::gen_enum_doc;
+use crate::gen::docs::flags::gen_flags_doc;
+use crate::gen::docs::structure::gen_structure_doc;
+use crate::gen::docs::union::gen_union_doc;
+use crate::gen::docs::util::gen_doc;
+use crate::model::protocol::Endianness;
+
+simple_error! {
+    pub Error {
+        Unknown => "unknown"
+    }
+}
+
+fn gen_section(blocks: &mut Vec<String>, format: &impl DocFormat, title: &str, items: Vec<Vec<String>>) {
+    if !items.is_empty() {
+        blocks.push(format.heading(2, title, title));
+        blocks.extend(items.into_iter().flatten());
+    }
+}
+
+fn gen_protocol_doc<F: DocFormat>(proto: &Protocol) -> File {
+    let format = F::new();
+    let mut blocks = vec![format.heading(1, &proto.name, &format.escape(&proto.name))];
+    if let Some(doc) = &proto.doc {
+        blocks.push(format.paragraph(&gen_doc(&format, Some(doc))));
+    }
+    blocks.push(format.paragraph(match proto.endianness {
+        Endianness::Little => "Byte order: little endian.",
+        Endianness::Big => "Byte order: big endian."
+    }));
+    gen_section(&mut blocks, &format, "Enums", proto.enums.iter().map(|v| gen_enum_doc(&format, v)).collect());
+    gen_section(&mut blocks, &format, "Flags", proto.flags.iter().map(|v| gen_flags_doc(&format, v)).collect());
+    gen_section(&mut blocks, &format, "Structures", proto.structs.iter()
+        .map(|v| gen_structure_doc(&format, v, &proto.type_path_by_name)).collect());
+    gen_section(&mut blocks, &format, "Unions", proto.unions.iter()
+        .map(|v| gen_union_doc(&format, v, &proto.type_path_by_name)).collect());
+    gen_section(&mut blocks, &format, "Messages", proto.messages.iter()
+        .map(|v| gen_message_doc(&format, v, &proto.type_path_by_name)).collect());
+    // Blocks are separated by an empty line which is required by Markdown.
+    let body = blocks.join("\n");
+    File::new(FileType::Message, format!("{}.{}", proto.name, F::EXTENSION), format.document(&proto.name, body))
+}
+
+/// A generator rendering the documentation of each protocol as a `<name>.md` Markdown document.
+///
+/// Imported types link to the document of their protocol; see [GeneratorHtml] for the expected
+/// import paths.
+pub struct GeneratorMarkdown;
+
+impl Generator for GeneratorMarkdown {
+    type Error = Error;
+
+    fn generate(proto: Protocol) -> Result<Vec<File>, Self::Error> {
+        Ok(vec![gen_protocol_doc::<Markdown>(&proto)])
+    }
+}
+
+/// A generator rendering the documentation of each protocol as a standalone `<name>.html` page.
+///
+/// Imported types link to `../<protocol>/<protocol>.html`, where the protocol is the last component
+/// of the import path; imports should therefore be registered using the protocol name as import
+/// path with the `::` separator.
+pub struct GeneratorHtml;
+
+impl Generator for GeneratorHtml {
+    type Error = Error;
+
+    fn generate(proto: Protocol) -> Result<Vec<File>, Self::Error> {
+        Ok(vec![gen_protocol_doc::<Html>(&proto)])
+    }
+}
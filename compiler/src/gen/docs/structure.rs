@@ -0,0 +1,94 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::structure::{Field, FieldView, FixedFieldType, Structure};
+use crate::compiler::util::TypePathMap;
+use crate::gen::docs::format::DocFormat;
+use crate::gen::docs::util::{gen_bytes, gen_doc, gen_type_link, get_key, get_type_name};
+
+fn gen_view_type<F: DocFormat>(format: &F, view: &FieldView, ty: FixedFieldType, type_path_by_name: &TypePathMap) -> String {
+    let ty = get_type_name(ty);
+    match view {
+        FieldView::Float { a, b, .. } => format!("{} (raw × {} + {})", ty, a, b),
+        FieldView::Enum(e) => gen_type_link(format, type_path_by_name, &e.name),
+        FieldView::Flags(f) => gen_type_link(format, type_path_by_name, &f.name),
+        FieldView::SignedCast(_) => format!("{} (two's complement)", ty),
+        FieldView::Transmute | FieldView::None => ty.into()
+    }
+}
+
+fn gen_field_type<F: DocFormat>(format: &F, field: &Field, type_path_by_name: &TypePathMap) -> String {
+    match field {
+        Field::Fixed(v) => gen_view_type(format, &v.view, v.ty, type_path_by_name),
+        Field::Array(v) => format!("{}[{}]", gen_view_type(format, &v.view, v.ty, type_path_by_name), v.array_len),
+        Field::Struct(v) => gen_type_link(format, type_path_by_name, &v.r.name),
+        Field::StructArray(v) => format!("{}[{}]", gen_type_link(format, type_path_by_name, &v.r.name), v.array_len)
+    }
+}
+
+/// Renders one line per byte of the structure, each bit being marked with the key of the field
+/// which occupies it.
+fn gen_bit_layout(s: &Structure) -> String {
+    let mut bits = vec!['.'; s.byte_size * 8];
+    for (index, field) in s.fields.iter().enumerate() {
+        let loc = field.loc();
+        let start = loc.byte_offset * 8 + loc.bit_offset;
+        for bit in &mut bits[start..start + loc.bit_size] {
+            *bit = get_key(index);
+        }
+    }
+    let width = s.byte_size.saturating_sub(1).to_string().len();
+    let header = format!("{:width$}   0 1 2 3 4 5 6 7", "", width = width + 5);
+    let lines = bits.chunks(8).enumerate()
+        .map(|(byte, bits)| format!("byte {:>width$} | {} |", byte, bits.iter().join(" "), width = width));
+    std::iter::once(header).chain(lines).join("\n")
+}
+
+pub fn gen_structure_doc<F: DocFormat>(format: &F, s: &Structure, type_path_by_name: &TypePathMap) -> Vec<String> {
+    let mut blocks = vec![format.heading(3, &s.name, &format.escape(&s.name))];
+    if let Some(doc) = &s.doc {
+        blocks.push(format.paragraph(&gen_doc(format, Some(doc))));
+    }
+    blocks.push(format.paragraph(&format!("Fixed size: {} ({} bits).", gen_bytes(s.byte_size), s.bit_size)));
+    let rows: Vec<Vec<String>> = s.fields.iter().enumerate().map(|(index, field)| {
+        let loc = field.loc();
+        vec![
+            format.code(&get_key(index).to_string()),
+            format.code(&format.escape(field.name())),
+            gen_field_type(format, field, type_path_by_name),
+            loc.byte_offset.to_string(),
+            loc.bit_offset.to_string(),
+            loc.bit_size.to_string(),
+            gen_doc(format, field.doc())
+        ]
+    }).collect();
+    blocks.push(format.table(&["Key", "Field", "Type", "Byte offset", "Bit offset", "Bit size", "Description"], &rows));
+    blocks.push(format.preformatted(&gen_bit_layout(s)));
+    blocks
+}
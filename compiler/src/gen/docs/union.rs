@@ -0,0 +1,69 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::structure::FieldView;
+use crate::compiler::union::Union;
+use crate::compiler::util::TypePathMap;
+use crate::gen::docs::format::DocFormat;
+use crate::gen::docs::util::{gen_doc, gen_type_link};
+
+fn gen_case_value<F: DocFormat>(format: &F, u: &Union, case: usize) -> String {
+    match &u.discriminant.get_leaf().view {
+        FieldView::Enum(e) => match e.variants.iter().find(|(_, v)| *v == case) {
+            Some((name, _)) => format!("{} ({})", format.code(&format.escape(name)), case),
+            None => case.to_string()
+        },
+        FieldView::Transmute | FieldView::SignedCast(_) => (case as isize).to_string(),
+        _ => case.to_string()
+    }
+}
+
+pub fn gen_union_doc<F: DocFormat>(format: &F, u: &Union, type_path_by_name: &TypePathMap) -> Vec<String> {
+    let mut blocks = vec![format.heading(3, &u.name, &format.escape(&u.name))];
+    if let Some(doc) = &u.doc {
+        blocks.push(format.paragraph(&gen_doc(format, Some(doc))));
+    }
+    let path = std::iter::once(u.discriminant.root.name.as_str())
+        .chain(u.discriminant.iter().map(|(f, _)| f.name())).join(".");
+    blocks.push(format.paragraph(&format!("Discriminant: {} in {}. The union has no encoding of its own: \
+        the selected case is encoded in place and an empty case takes no space.",
+        format.code(&format.escape(&path)), gen_type_link(format, type_path_by_name, &u.discriminant.root.name))));
+    if u.unknown {
+        blocks.push(format.paragraph("Any other discriminant value decodes as an unknown case holding the remaining \
+            bytes of the input, which are encoded back unchanged."));
+    }
+    let rows: Vec<Vec<String>> = u.cases.iter().map(|case| vec![
+        format.code(&format.escape(&case.name)),
+        gen_case_value(format, u, case.case),
+        case.item_type.as_ref().map(|v| gen_type_link(format, type_path_by_name, v.name())).unwrap_or_else(|| "-".into()),
+        gen_doc(format, case.doc.as_deref())
+    ]).collect();
+    blocks.push(format.table(&["Case", "Value", "Type", "Description"], &rows));
+    blocks
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::compiler::structure::FixedFieldType;
+use crate::compiler::util::TypePathMap;
+use crate::gen::docs::format::DocFormat;
+
+const KEYS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+pub fn get_type_name(ty: FixedFieldType) -> &'static str {
+    match ty {
+        FixedFieldType::Int8 => "int8",
+        FixedFieldType::Int16 => "int16",
+        FixedFieldType::Int32 => "int32",
+        FixedFieldType::Int64 => "int64",
+        FixedFieldType::UInt8 => "uint8",
+        FixedFieldType::UInt16 => "uint16",
+        FixedFieldType::UInt32 => "uint32",
+        FixedFieldType::UInt64 => "uint64",
+        FixedFieldType::Float32 => "float32",
+        FixedFieldType::Float64 => "float64",
+        FixedFieldType::Bool => "bool"
+    }
+}
+
+/// Returns the key identifying the field at the given index in bit-layout diagrams.
+pub fn get_key(index: usize) -> char {
+    KEYS.get(index).map(|v| *v as char).unwrap_or('#')
+}
+
+/// Generates a link to the documentation of a type.
+///
+/// Types imported from other protocols link to the document of the protocol they come from; the
+/// protocol is deduced from the last component of the import path, so imports should be
+/// registered using the protocol name as import path with the `::` separator.
+pub fn gen_type_link<F: DocFormat>(format: &F, type_path_by_name: &TypePathMap, name: &str) -> String {
+    let path = type_path_by_name.get(name);
+    let href = match path.rsplit("::").nth(1) {
+        Some(protocol) => format!("../{0}/{0}.{1}#{2}", protocol, F::EXTENSION, name),
+        None => format!("#{}", name)
+    };
+    format.link(&format.escape(name), &href)
+}
+
+pub fn gen_doc<F: DocFormat>(format: &F, doc: Option<&str>) -> String {
+    doc.map(|v| format.escape(v).into_owned()).unwrap_or_default()
+}
+
+pub fn gen_bytes(byte_size: usize) -> String {
+    match byte_size {
+        1 => String::from("1 byte"),
+        _ => format!("{} bytes", byte_size)
+    }
+}
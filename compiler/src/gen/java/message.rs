@@ -0,0 +1,47 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::compiler::message::Message;
+use crate::compiler::util::TypePathMap;
+use crate::gen::java::util::JavaUtils;
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./message.template");
+const TEMPLATE_FROM_SLICE: &[u8] = include_bytes!("./message.from_slice.template");
+const TEMPLATE_WRITE: &[u8] = include_bytes!("./message.write.template");
+
+pub fn gen_message_decl(msg: &Message, type_path_by_name: &TypePathMap) -> String {
+    // The codec is a static member of the message class, so both reading and writing are always
+    // generated alongside the declaration.
+    let mut code = crate::gen::base::message::generate::<JavaUtils>(TEMPLATE, msg, type_path_by_name);
+    code += &crate::gen::base::message_from_slice::generate::<JavaUtils>(TEMPLATE_FROM_SLICE, msg, type_path_by_name);
+    code += &crate::gen::base::message_write::generate::<JavaUtils>(TEMPLATE_WRITE, msg, type_path_by_name);
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    code += &template.var("msg_name", &msg.name).render("", &["end"]).unwrap();
+    code
+}
@@ -0,0 +1,83 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod util;
+mod structure;
+mod r#enum;
+mod flags;
+mod union;
+mod message;
+
+use bp3d_util::simple_error;
+use crate::compiler::Protocol;
+use crate::gen::{File, FileType, Generator};
+use crate::gen::java::message::gen_message_decl;
+use crate::gen::java::r#enum::gen_enum_decl;
+use crate::gen::java::flags::gen_flags_decl;
+use crate::gen::java::structure::gen_structure_decl;
+use crate::gen::java::union::gen_union_decl;
+
+simple_error! {
+    pub Error {
+        Unknown => "unknown"
+    }
+}
+
+fn gen_file(proto: &Protocol, ty: FileType, name: &str, code: String) -> File {
+    File::new(ty, format!("{}.java", name), format!("package {};\n\n{}", proto.name, code))
+}
+
+/// A generator producing one Java source file per type, in a package named after the protocol.
+///
+/// Generated code depends on the bp3d.proto Java runtime. Message classes always contain both the
+/// reading and writing halves of their codec.
+pub struct GeneratorJava;
+
+impl Generator for GeneratorJava {
+    type Error = Error;
+
+    fn generate(proto: Protocol) -> Result<Vec<File>, Self::Error> {
+        let mut files = Vec::new();
+        for v in &proto.enums {
+            files.push(gen_file(&proto, FileType::Enum, &v.name, gen_enum_decl(v)));
+        }
+        for v in &proto.flags {
+            files.push(gen_file(&proto, FileType::Enum, &v.name, gen_flags_decl(v)));
+        }
+        for v in &proto.structs {
+            files.push(gen_file(&proto, FileType::Structure, &v.name, gen_structure_decl(v, &proto.type_path_by_name)));
+        }
+        for v in &proto.unions {
+            files.push(gen_file(&proto, FileType::Union, &v.name, gen_union_decl(v, &proto.type_path_by_name)));
+        }
+        for v in &proto.messages {
+            files.push(gen_file(&proto, FileType::Message, &v.name, gen_message_decl(v, &proto.type_path_by_name)));
+        }
+        Ok(files)
+    }
+}
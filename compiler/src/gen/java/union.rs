@@ -0,0 +1,86 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::union::Union;
+use crate::compiler::util::TypePathMap;
+use crate::gen::base::structure::Utilities;
+use crate::gen::java::util::{to_pascal_case, JavaUtils};
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./union.template");
+
+fn get_discriminant_path(u: &Union) -> String {
+    u.discriminant.iter().map(|(f, is_leaf)| if is_leaf {
+        format!("getRaw{}()", to_pascal_case(f.name()))
+    } else {
+        format!("get{}()", to_pascal_case(f.name()))
+    }).join(".")
+}
+
+fn get_discriminant_path_mut(u: &Union) -> String {
+    u.discriminant.iter().map(|(f, is_leaf)| if is_leaf {
+        format!("setRaw{}", to_pascal_case(f.name()))
+    } else {
+        format!("get{}()", to_pascal_case(f.name()))
+    }).join(".")
+}
+
+fn render_cases(u: &Union, template: &Template, path: &str, type_path_by_name: &TypePathMap, with_none: bool) -> String {
+    u.cases.iter().filter_map(|case| {
+        let mut scope = template.scope();
+        // Java has no unsigned 64 bits literals, so cases are emitted using their signed bit pattern.
+        scope.var("name", &case.name).var_d("case", case.case as i64);
+        match &case.item_type {
+            None if with_none => Some(scope.render(path, &["none"]).unwrap()),
+            None => None,
+            Some(item_type) => Some(scope.var("type_name", type_path_by_name.get(item_type.name()))
+                .render(path, &["ref"]).unwrap())
+        }
+    }).join("")
+}
+
+pub fn gen_union_decl(u: &Union, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    let leaf = u.discriminant.get_leaf();
+    template.var("union_name", &u.name)
+        .var("discriminant_path_mut", get_discriminant_path_mut(u))
+        .var("discriminant_path", get_discriminant_path(u))
+        .var("discriminant_raw_type", JavaUtils::get_field_type(leaf.loc.get_unsigned_integer_type()))
+        .var("discriminant_type", type_path_by_name.get(&u.discriminant.root.name));
+    let render = |path: &str, with_none: bool| {
+        let cases = render_cases(u, &template, path, type_path_by_name, with_none);
+        template.scope().var("cases", cases).render("", &[path]).unwrap()
+    };
+    let mut code = render("decl", true);
+    code += &render("getters", true);
+    code += &template.render("", &["setter"]).unwrap();
+    code += &render("from_slice", true);
+    code += &render("write_to", false);
+    code
+}
@@ -0,0 +1,202 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::compiler::message::Message;
+use crate::compiler::structure::{FixedField, FixedFieldType};
+use crate::gen::base::message::StringType;
+use crate::model::protocol::Endianness;
+
+macro_rules! gen_value_type {
+    ($prefix: literal, $ty: expr, $suffix: literal) => {
+        match $ty {
+            FixedFieldType::Int8 => concat!($prefix, "I8", $suffix),
+            FixedFieldType::Int16 => concat!($prefix, "I16", $suffix),
+            FixedFieldType::Int32 => concat!($prefix, "I32", $suffix),
+            FixedFieldType::Int64 => concat!($prefix, "I64", $suffix),
+            FixedFieldType::UInt8 => concat!($prefix, "U8", $suffix),
+            FixedFieldType::UInt16 => concat!($prefix, "U16", $suffix),
+            FixedFieldType::UInt32 => concat!($prefix, "U32", $suffix),
+            FixedFieldType::UInt64 => concat!($prefix, "U64", $suffix),
+            FixedFieldType::Float32 => concat!($prefix, "F32", $suffix),
+            FixedFieldType::Float64 => concat!($prefix, "F64", $suffix),
+            FixedFieldType::Bool => concat!($prefix, "BOOL", $suffix)
+        }
+    };
+}
+
+/// Returns the boxed type of a Java primitive, or the type itself if it is not a primitive.
+pub fn get_boxed_type(ty: &str) -> &str {
+    match ty {
+        "byte" => "Byte",
+        "short" => "Short",
+        "int" => "Integer",
+        "long" => "Long",
+        "float" => "Float",
+        "double" => "Double",
+        "boolean" => "Boolean",
+        _ => ty
+    }
+}
+
+pub fn to_pascal_case(name: &str) -> String {
+    name.split('_').map(|v| {
+        let mut chars = v.chars();
+        match chars.next() {
+            Some(c) => c.to_uppercase().chain(chars).collect(),
+            None => String::new()
+        }
+    }).collect()
+}
+
+pub struct JavaUtils;
+
+impl crate::gen::base::structure::Utilities for JavaUtils {
+    fn get_field_type(field_type: FixedFieldType) -> &'static str {
+        // Java has no unsigned types, so unsigned values are widened to the next signed type able
+        // to hold them (except for 64 bits values which keep their bit pattern).
+        match field_type {
+            FixedFieldType::Int8 => "byte",
+            FixedFieldType::Int16 => "short",
+            FixedFieldType::Int32 => "int",
+            FixedFieldType::Int64 => "long",
+            FixedFieldType::UInt8 => "short",
+            FixedFieldType::UInt16 => "int",
+            FixedFieldType::UInt32 => "long",
+            FixedFieldType::UInt64 => "long",
+            FixedFieldType::Float32 => "float",
+            FixedFieldType::Float64 => "double",
+            FixedFieldType::Bool => "boolean"
+        }
+    }
+
+    fn get_function_name(_: &FixedField) -> &'static str {
+        "read"
+    }
+
+    fn get_function_name_mut(_: &FixedField) -> &'static str {
+        "write"
+    }
+
+    fn get_bit_codec_inline(endianness: Endianness) -> &'static str {
+        match endianness {
+            Endianness::Little => "bp3d.proto.BitCodec.LE",
+            Endianness::Big => "bp3d.proto.BitCodec.BE"
+        }
+    }
+
+    fn get_byte_codec_inline(endianness: Endianness) -> &'static str {
+        match endianness {
+            Endianness::Little => "bp3d.proto.ByteCodec.LE",
+            Endianness::Big => "bp3d.proto.ByteCodec.BE"
+        }
+    }
+
+    fn get_byte_codec(endianness: Endianness) -> &'static str {
+        Self::get_byte_codec_inline(endianness)
+    }
+
+    fn get_bit_codec(endianness: Endianness) -> &'static str {
+        Self::get_bit_codec_inline(endianness)
+    }
+
+    fn get_generic_type(field_type: FixedFieldType) -> &'static str {
+        get_boxed_type(Self::get_field_type(field_type))
+    }
+}
+
+impl crate::gen::base::message::Utilities for JavaUtils {
+    fn gen_generics(_: &Message) -> String {
+        String::new()
+    }
+
+    fn get_value_type(endianness: Endianness, ty: FixedFieldType) -> &'static str {
+        match endianness {
+            Endianness::Little => gen_value_type!("bp3d.proto.Scalar.", ty, "_LE"),
+            Endianness::Big => gen_value_type!("bp3d.proto.Scalar.", ty, "_BE")
+        }
+    }
+
+    fn get_value_type_inline(endianness: Endianness, ty: FixedFieldType) -> &'static str {
+        Self::get_value_type(endianness, ty)
+    }
+
+    fn gen_option_type(ty: &str) -> String {
+        get_boxed_type(ty).into()
+    }
+
+    fn gen_option_type_inline(ty: &str) -> String {
+        format!("bp3d.proto.Optional.of({})", ty)
+    }
+
+    fn get_string_type(_: StringType) -> &'static str {
+        "String"
+    }
+
+    fn get_string_type_inline(ty: StringType) -> &'static str {
+        match ty {
+            StringType::Varchar => "bp3d.proto.VarcharString",
+            StringType::NullTerminated => "bp3d.proto.NullTerminatedString.CODEC"
+        }
+    }
+
+    fn get_payload_type() -> &'static str {
+        "java.nio.ByteBuffer"
+    }
+
+    fn get_payload_type_inline() -> &'static str {
+        "bp3d.proto.Payload.CODEC"
+    }
+
+    fn gen_struct_ref_type(type_name: &str) -> String {
+        type_name.into()
+    }
+
+    fn gen_message_ref_type(type_name: &str) -> String {
+        type_name.into()
+    }
+
+    fn gen_ref_type_inline(type_name: &str) -> String {
+        format!("{}.CODEC", type_name)
+    }
+
+    fn gen_union_ref_type(type_name: &str) -> String {
+        type_name.into()
+    }
+
+    fn gen_enum_codec(raw_codec: &str, type_name: &str) -> String {
+        format!("bp3d.proto.EnumCodec.of({}, {}::fromRaw, v -> v.raw)", raw_codec, type_name)
+    }
+
+    fn gen_enum_codec_inline(raw_codec: &str, type_name: &str) -> String {
+        Self::gen_enum_codec(raw_codec, type_name)
+    }
+
+    fn gen_list_item_type(ty: &str) -> String {
+        get_boxed_type(ty).into()
+    }
+}
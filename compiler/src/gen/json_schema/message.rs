@@ -0,0 +1,114 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde_json::{json, Map, Value};
+use crate::compiler::message::{Field, FieldType, ItemListField, ItemType, Message, Referenced, ValueType};
+use crate::gen::json_schema::structure::gen_structure;
+use crate::gen::json_schema::union::gen_union;
+use crate::gen::json_schema::util::{gen_enum, gen_fixed, gen_unsigned, with_doc, Defs};
+
+pub fn gen_referenced(defs: &mut Defs, r: &Referenced) -> Value {
+    match r {
+        Referenced::Struct(v) => gen_structure(defs, v),
+        Referenced::Message(v) => defs.get_or_insert(&v.name, |defs| gen_message_object(defs, v))
+    }
+}
+
+fn gen_value(defs: &mut Defs, ty: &ValueType) -> Value {
+    match ty {
+        ValueType::Scalar(v) => gen_fixed(*v),
+        ValueType::Enum(v) => gen_enum(defs, v)
+    }
+}
+
+fn gen_item(defs: &mut Defs, ty: &ItemType) -> Value {
+    match ty {
+        ItemType::Struct(v) => gen_structure(defs, v),
+        ItemType::Message(v) => gen_referenced(defs, &Referenced::Message(v.clone())),
+        ItemType::Value(v) => gen_value(defs, v),
+        ItemType::NullTerminatedString => json!({ "type": "string" }),
+        ItemType::VarcharString(v) => json!({ "type": "string", "maxLength": v.max_len }),
+        ItemType::List(v) => gen_item_list(defs, v)
+    }
+}
+
+/// Generates the schema of a list; fixed lists must hold exactly `max_len` items.
+fn gen_array(items: Value, max_len: usize, fixed: bool) -> Value {
+    let mut schema = json!({
+        "type": "array",
+        "items": items,
+        "maxItems": max_len
+    });
+    if fixed {
+        schema["minItems"] = max_len.into();
+    }
+    schema
+}
+
+fn gen_item_list(defs: &mut Defs, v: &ItemListField) -> Value {
+    let items = gen_item(defs, &v.item_type);
+    gen_array(items, v.max_len, v.fixed)
+}
+
+fn gen_field(defs: &mut Defs, field: &Field) -> Value {
+    let schema = match &field.ty {
+        FieldType::Fixed(v) => gen_fixed(v.ty),
+        FieldType::Enum(v) => gen_enum(defs, v),
+        FieldType::Ref(v) => gen_referenced(defs, v),
+        FieldType::NullTerminatedString => json!({ "type": "string" }),
+        FieldType::VarcharString(v) => json!({ "type": "string", "maxLength": v.max_len }),
+        FieldType::Array(v) => gen_array(gen_structure(defs, &v.item_type), v.max_len, v.fixed),
+        FieldType::List(v) => {
+            let items = gen_referenced(defs, &Referenced::Message(v.item_type.clone()));
+            gen_array(items, v.max_len, v.fixed)
+        },
+        FieldType::ItemList(v) => gen_item_list(defs, v),
+        FieldType::Union(v) => gen_union(defs, &v.r),
+        FieldType::Payload => json!({ "type": "array", "items": gen_unsigned(8) })
+    };
+    match field.optional {
+        true => json!({ "anyOf": [schema, { "type": "null" }] }),
+        false => schema
+    }
+}
+
+/// Generates the schema of the JSON object representing a message.
+pub fn gen_message_object(defs: &mut Defs, msg: &Message) -> Value {
+    let mut properties = Map::new();
+    for field in &msg.fields {
+        let schema = gen_field(defs, field);
+        properties.insert(field.name.clone(), with_doc(schema, field.doc.as_deref()));
+    }
+    let required: Vec<&str> = msg.fields.iter().filter(|v| !v.optional).map(|v| &*v.name).collect();
+    with_doc(json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false
+    }), msg.doc.as_deref())
+}
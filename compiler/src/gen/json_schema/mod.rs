@@ -0,0 +1,75 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod util;
+mod structure;
+mod union;
+mod message;
+
+use bp3d_util::simple_error;
+use serde_json::Value;
+use crate::compiler::Protocol;
+use crate::gen::{File, FileType, Generator};
+use crate::gen::json_schema::message::gen_message_object;
+use crate::gen::json_schema::util::Defs;
+
+const SCHEMA: &str = "https://json-schema.org/draft/2020-12/schema";
+
+simple_error! {
+    pub Error {
+        Json(serde_json::Error) => "json error: {}"
+    }
+}
+
+/// A generator exporting a `<message>.schema.json` JSON Schema document for each message of a
+/// protocol, describing the JSON representation of the message.
+///
+/// Each document is standalone: all referenced types, including imported ones, are stored under
+/// `$defs`.
+pub struct GeneratorJsonSchema;
+
+impl Generator for GeneratorJsonSchema {
+    type Error = Error;
+
+    fn generate(proto: Protocol) -> Result<Vec<File>, Self::Error> {
+        proto.messages.iter().map(|msg| {
+            let mut defs = Defs::new();
+            let mut schema = gen_message_object(&mut defs, msg);
+            let map = schema.as_object_mut().unwrap();
+            map.shift_insert(0, "$schema".into(), SCHEMA.into());
+            map.shift_insert(1, "title".into(), msg.name.clone().into());
+            let defs = defs.into_inner();
+            if !defs.is_empty() {
+                map.insert("$defs".into(), Value::Object(defs));
+            }
+            let mut code = serde_json::to_string_pretty(&schema).map_err(Error::Json)?;
+            code += "\n";
+            Ok(File::new(FileType::Message, format!("{}.schema.json", msg.name), code))
+        }).collect()
+    }
+}
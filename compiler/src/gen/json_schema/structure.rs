@@ -0,0 +1,92 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde_json::{json, Map, Value};
+use crate::compiler::structure::{Field, FieldView, FixedFieldType, Structure};
+use crate::gen::json_schema::util::{gen_enum, gen_fixed, gen_flags, gen_signed, gen_unsigned, with_doc, Defs};
+
+fn gen_item(ty: FixedFieldType, bit_size: usize) -> Value {
+    match ty {
+        FixedFieldType::Int8 | FixedFieldType::Int16 | FixedFieldType::Int32 | FixedFieldType::Int64 => gen_signed(bit_size),
+        FixedFieldType::UInt8 | FixedFieldType::UInt16 | FixedFieldType::UInt32 | FixedFieldType::UInt64 => gen_unsigned(bit_size),
+        _ => gen_fixed(ty)
+    }
+}
+
+fn gen_view(defs: &mut Defs, view: &FieldView, ty: FixedFieldType, bit_size: usize) -> Value {
+    match view {
+        FieldView::Float { a, b, .. } => {
+            let raw_max = ((1u128 << bit_size) - 1) as f64;
+            let (min, max) = (*b, a * raw_max + b);
+            json!({ "type": "number", "minimum": min.min(max), "maximum": min.max(max) })
+        },
+        // Raw values which are not a variant of the enum are serialized as null.
+        FieldView::Enum(e) => json!({ "anyOf": [gen_enum(defs, e), { "type": "null" }] }),
+        FieldView::Flags(f) => gen_flags(defs, f),
+        FieldView::SignedCast(_) => gen_signed(bit_size),
+        FieldView::Transmute | FieldView::None => gen_item(ty, bit_size)
+    }
+}
+
+pub fn gen_structure(defs: &mut Defs, s: &Structure) -> Value {
+    defs.get_or_insert(&s.name, |defs| {
+        let mut properties = Map::new();
+        for field in &s.fields {
+            let schema = match field {
+                Field::Fixed(v) => gen_view(defs, &v.view, v.ty, v.loc.bit_size),
+                Field::Array(v) if v.has_view() => json!({
+                    "type": "array",
+                    "items": gen_view(defs, &v.view, v.ty, v.item_bit_size()),
+                    "minItems": v.array_len,
+                    "maxItems": v.array_len
+                }),
+                Field::Array(v) => json!({
+                    "type": "array",
+                    "items": gen_item(v.raw_item_type(), v.item_bit_size()),
+                    "minItems": v.array_len,
+                    "maxItems": v.array_len
+                }),
+                Field::Struct(v) => gen_structure(defs, &v.r),
+                Field::StructArray(v) => json!({
+                    "type": "array",
+                    "items": gen_structure(defs, &v.r),
+                    "minItems": v.array_len,
+                    "maxItems": v.array_len
+                })
+            };
+            properties.insert(field.name().into(), with_doc(schema, field.doc()));
+        }
+        let required: Vec<&str> = s.fields.iter().map(|v| v.name()).collect();
+        with_doc(json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false
+        }), s.doc.as_deref())
+    })
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde_json::{json, Value};
+use crate::compiler::union::Union;
+use crate::gen::json_schema::message::gen_referenced;
+use crate::gen::json_schema::util::{gen_unsigned, with_doc, Defs};
+
+/// Generates the schema of a union; cases without data are represented by their name and cases
+/// with data by an object holding the data under the name of the case, unknown cases included.
+pub fn gen_union(defs: &mut Defs, u: &Union) -> Value {
+    defs.get_or_insert(&u.name, |defs| {
+        let mut cases: Vec<Value> = u.cases.iter().map(|case| {
+            let schema = match &case.item_type {
+                None => json!({ "const": case.name }),
+                Some(item_type) => json!({
+                    "type": "object",
+                    "properties": { &case.name: gen_referenced(defs, item_type) },
+                    "required": [case.name],
+                    "additionalProperties": false
+                })
+            };
+            with_doc(schema, case.doc.as_deref())
+        }).collect();
+        if u.unknown {
+            // Unknown cases are serialized as their raw discriminant followed by their bytes.
+            let discriminant = gen_unsigned(u.discriminant.get_leaf().loc.bit_size);
+            cases.push(json!({
+                "type": "object",
+                "properties": {
+                    "Unknown": {
+                        "type": "array",
+                        "prefixItems": [discriminant, { "type": "array", "items": gen_unsigned(8) }],
+                        "items": false,
+                        "minItems": 2
+                    }
+                },
+                "required": ["Unknown"],
+                "additionalProperties": false,
+                "description": "A case which is not part of this union, kept undecoded."
+            }));
+        }
+        with_doc(json!({ "oneOf": cases }), u.doc.as_deref())
+    })
+}
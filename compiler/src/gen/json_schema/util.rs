@@ -0,0 +1,108 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde_json::{json, Map, Value};
+use crate::compiler::r#enum::Enum;
+use crate::compiler::flags::Flags;
+use crate::compiler::structure::FixedFieldType;
+
+/// The definitions shared by all the schemas of a message, stored under `$defs`.
+pub struct Defs {
+    defs: Map<String, Value>
+}
+
+impl Defs {
+    pub fn new() -> Self {
+        Self {
+            defs: Map::new()
+        }
+    }
+
+    /// Returns a reference to the named definition, generating it first if needed.
+    pub fn get_or_insert(&mut self, name: &str, f: impl FnOnce(&mut Defs) -> Value) -> Value {
+        if !self.defs.contains_key(name) {
+            let def = f(self);
+            self.defs.insert(name.into(), def);
+        }
+        json!({ "$ref": format!("#/$defs/{}", name) })
+    }
+
+    pub fn into_inner(self) -> Map<String, Value> {
+        self.defs
+    }
+}
+
+pub fn with_doc(mut schema: Value, doc: Option<&str>) -> Value {
+    if let (Some(doc), Some(map)) = (doc, schema.as_object_mut()) {
+        map.insert("description".into(), doc.into());
+    }
+    schema
+}
+
+pub fn gen_unsigned(bit_size: usize) -> Value {
+    let max = match bit_size {
+        64 => u64::MAX,
+        _ => (1u64 << bit_size) - 1
+    };
+    json!({ "type": "integer", "minimum": 0, "maximum": max })
+}
+
+pub fn gen_signed(bit_size: usize) -> Value {
+    let max = match bit_size {
+        64 => i64::MAX,
+        _ => (1i64 << (bit_size - 1)) - 1
+    };
+    json!({ "type": "integer", "minimum": -max - 1, "maximum": max })
+}
+
+pub fn gen_fixed(ty: FixedFieldType) -> Value {
+    match ty {
+        FixedFieldType::Int8 => gen_signed(8),
+        FixedFieldType::Int16 => gen_signed(16),
+        FixedFieldType::Int32 => gen_signed(32),
+        FixedFieldType::Int64 => gen_signed(64),
+        FixedFieldType::UInt8 => gen_unsigned(8),
+        FixedFieldType::UInt16 => gen_unsigned(16),
+        FixedFieldType::UInt32 => gen_unsigned(32),
+        FixedFieldType::UInt64 => gen_unsigned(64),
+        FixedFieldType::Float32 | FixedFieldType::Float64 => json!({ "type": "number" }),
+        FixedFieldType::Bool => json!({ "type": "boolean" })
+    }
+}
+
+pub fn gen_enum(defs: &mut Defs, e: &Enum) -> Value {
+    defs.get_or_insert(&e.name, |_| {
+        let names: Vec<&str> = e.variants.iter().map(|(k, _)| &**k).collect();
+        with_doc(json!({ "type": "string", "enum": names }), e.doc.as_deref())
+    })
+}
+
+/// Flags are serialized as their raw bits.
+pub fn gen_flags(defs: &mut Defs, f: &Flags) -> Value {
+    defs.get_or_insert(&f.name, |_| with_doc(gen_unsigned(f.bit_size()), f.doc.as_deref()))
+}
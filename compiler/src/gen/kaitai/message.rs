@@ -0,0 +1,154 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::message::{FieldType, ItemListField, ItemType, Message, ValueType};
+use crate::compiler::structure::{FieldView, FixedFieldType};
+use crate::compiler::union::Union;
+use crate::compiler::util::TypePathMap;
+use crate::gen::kaitai::util::{gen_field, gen_type_path, get_byte_type, to_snake_case};
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./type.template");
+
+fn gen_discriminant_expr(u: &Union, on_name: &str) -> String {
+    let path = u.discriminant.iter().map(|(f, _)| f.name()).join(".");
+    match &u.discriminant.get_leaf().view {
+        FieldView::Enum(_) => format!("{}.{}.to_i", on_name, path),
+        _ => format!("{}.{}", on_name, path)
+    }
+}
+
+/// Returns the attributes describing a single list item.
+///
+/// Items which cannot be described by attributes alone, such as length-prefixed strings or
+/// nested lists, are declared as helper types named after `name` and appended to `helpers`.
+fn gen_item_attrs(template: &Template, ty: &ItemType, name: &str, type_path_by_name: &TypePathMap, helpers: &mut String) -> Vec<(&'static str, String)> {
+    match ty {
+        ItemType::Struct(v) => vec![("type", gen_type_path(type_path_by_name, &v.name))],
+        ItemType::Message(v) => vec![("type", gen_type_path(type_path_by_name, &v.name))],
+        ItemType::Value(v) => {
+            let mut attrs = vec![("type", get_byte_type(v.raw_type()).to_string())];
+            if let ValueType::Enum(e) = v {
+                attrs.push(("enum", gen_type_path(type_path_by_name, &e.name)));
+            }
+            attrs
+        },
+        ItemType::NullTerminatedString => vec![("type", "strz".into()), ("encoding", "UTF-8".into())],
+        ItemType::VarcharString(v) => {
+            let code = template.scope().var("name", name).render("", &["type", "seq"]).unwrap()
+                + &gen_field(template, "len", &[("type", get_byte_type(v.ty))])
+                + &gen_field(template, "value", &[("type", "str"), ("size", "len"), ("encoding", "UTF-8")]);
+            *helpers += &code;
+            vec![("type", name.into())]
+        },
+        ItemType::List(v) => {
+            let item_attrs = gen_item_attrs(template, &v.item_type, &format!("{}_item", name), type_path_by_name, helpers);
+            let mut attrs: Vec<(&str, &str)> = item_attrs.iter().map(|(k, v)| (*k, &**v)).collect();
+            let count = match v.fixed {
+                true => v.max_len.to_string(),
+                false => "count".into()
+            };
+            attrs.push(("repeat", "expr"));
+            attrs.push(("repeat-expr", &count));
+            let mut code = template.scope().var("name", name).render("", &["type", "seq"]).unwrap();
+            if !v.fixed {
+                code += &gen_field(template, "count", &[("type", get_byte_type(v.ty))]);
+            }
+            code += &gen_field(template, "items", &attrs);
+            *helpers += &code;
+            vec![("type", name.into())]
+        }
+    }
+}
+
+pub fn gen_message_decl(msg: &Message, type_path_by_name: &TypePathMap) -> String {
+    let template = Template::compile(TEMPLATE).unwrap();
+    let mut code = template.scope().var("name", to_snake_case(&msg.name)).render("", &["type"]).unwrap();
+    code += &template.render("", &["seq"]).unwrap();
+    let mut helpers = String::new();
+    for field in &msg.fields {
+        let mut condition = String::new();
+        if field.optional {
+            let present = format!("{}_present", field.name);
+            code += &gen_field(&template, &present, &[("type", "u1")]);
+            condition = format!("{} != 0", present);
+        }
+        let field_if: &[(&str, &str)] = match field.optional {
+            true => &[("if", &condition)],
+            false => &[]
+        };
+        let field_code = |id: &str, attrs: &[(&str, &str)]| {
+            let attrs: Vec<(&str, &str)> = attrs.iter().chain(field_if.iter()).copied().collect();
+            gen_field(&template, id, &attrs)
+        };
+        // Returns the code of the item count field, if any, and the item count expression.
+        let gen_count = |ty: &FixedFieldType, max_len: usize, fixed: bool| match fixed {
+            true => (String::new(), max_len.to_string()),
+            false => {
+                let count = format!("{}_count", field.name);
+                (field_code(&count, &[("type", get_byte_type(*ty))]), count)
+            }
+        };
+        let gen_list = |ty: &FixedFieldType, max_len: usize, fixed: bool, item_type: &str| {
+            let (code, count) = gen_count(ty, max_len, fixed);
+            code + &field_code(&field.name, &[("type", &gen_type_path(type_path_by_name, item_type)),
+                ("repeat", "expr"), ("repeat-expr", &count)])
+        };
+        let mut gen_items = |v: &ItemListField| {
+            let (code, count) = gen_count(&v.ty, v.max_len, v.fixed);
+            let name = format!("{}_{}_item", to_snake_case(&msg.name), field.name);
+            let mut attrs = gen_item_attrs(&template, &v.item_type, &name, type_path_by_name, &mut helpers);
+            attrs.push(("repeat", "expr".into()));
+            attrs.push(("repeat-expr", count));
+            let attrs: Vec<(&str, &str)> = attrs.iter().map(|(k, v)| (*k, &**v)).collect();
+            code + &field_code(&field.name, &attrs)
+        };
+        code += &match &field.ty {
+            FieldType::Fixed(v) => field_code(&field.name, &[("type", get_byte_type(v.ty))]),
+            FieldType::Enum(v) => field_code(&field.name, &[("type", get_byte_type(v.ty)),
+                ("enum", &gen_type_path(type_path_by_name, &v.name))]),
+            FieldType::Ref(v) => field_code(&field.name, &[("type", &gen_type_path(type_path_by_name, v.name()))]),
+            FieldType::NullTerminatedString => field_code(&field.name, &[("type", "strz"), ("encoding", "UTF-8")]),
+            FieldType::VarcharString(v) => {
+                let len = format!("{}_len", field.name);
+                field_code(&len, &[("type", get_byte_type(v.ty))])
+                    + &field_code(&field.name, &[("type", "str"), ("size", &len), ("encoding", "UTF-8")])
+            },
+            FieldType::Array(v) => gen_list(&v.ty, v.max_len, v.fixed, &v.item_type.name),
+            FieldType::List(v) => gen_list(&v.ty, v.max_len, v.fixed, &v.item_type.name),
+            FieldType::ItemList(v) => gen_items(v),
+            FieldType::Union(v) => {
+                let ty = format!("{}({})", gen_type_path(type_path_by_name, &v.r.name), gen_discriminant_expr(&v.r, &v.on_name));
+                field_code(&field.name, &[("type", &ty)])
+            },
+            FieldType::Payload => field_code(&field.name, &[("size-eos", "true")])
+        };
+    }
+    code + &helpers
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod util;
+mod structure;
+mod r#enum;
+mod union;
+mod message;
+
+use bp3d_util::simple_error;
+use itertools::Itertools;
+use crate::compiler::Protocol;
+use crate::gen::{File, FileType, Generator};
+use crate::gen::kaitai::message::gen_message_decl;
+use crate::gen::kaitai::r#enum::gen_enum_decl;
+use crate::gen::kaitai::structure::gen_structure_decl;
+use crate::gen::kaitai::union::gen_union_decl;
+use crate::gen::template::Template;
+use crate::model::protocol::Endianness;
+
+const TEMPLATE: &[u8] = include_bytes!("./protocol.template");
+
+simple_error! {
+    pub Error {
+        Unknown => "unknown"
+    }
+}
+
+/// A generator exporting each protocol as a Kaitai Struct `<name>.ksy` specification.
+///
+/// Imported protocols must be registered using their protocol name as import path with the `::`
+/// separator, so that type references match Kaitai's `<spec>::<type>` syntax.
+pub struct GeneratorKaitai;
+
+impl Generator for GeneratorKaitai {
+    type Error = Error;
+
+    fn generate(proto: Protocol) -> Result<Vec<File>, Self::Error> {
+        let mut template = Template::compile(TEMPLATE).unwrap();
+        template.var("name", &proto.name).var("endian", match proto.endianness {
+            Endianness::Little => "le",
+            Endianness::Big => "be"
+        });
+        let mut code = template.render("", &["header"]).unwrap();
+        let imports = proto.type_path_by_name.type_paths()
+            .filter_map(|v| v.rsplit_once("::").map(|(k, _)| k))
+            .unique().sorted()
+            .map(|v| template.scope().var("import", v).render("imports", &["import"]).unwrap())
+            .join("");
+        if !imports.is_empty() {
+            code += &template.scope().var("imports", imports.trim_end()).render("", &["imports"]).unwrap();
+        }
+        code += &template.render("", &["types"]).unwrap();
+        code += &proto.structs.iter().map(|v| gen_structure_decl(v, &proto.type_path_by_name)).join("");
+        code += &proto.unions.iter().map(|v| gen_union_decl(v, &proto.type_path_by_name)).join("");
+        code += &proto.messages.iter().map(|v| gen_message_decl(v, &proto.type_path_by_name)).join("");
+        if !proto.enums.is_empty() {
+            code += &template.render("", &["enums"]).unwrap();
+            code += &proto.enums.iter().map(|v| gen_enum_decl(v)).join("");
+        }
+        Ok(vec![File::new(FileType::Message, format!("{}.ksy", proto.name), code)])
+    }
+}
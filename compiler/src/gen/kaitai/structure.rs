@@ -0,0 +1,102 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::compiler::structure::{Field, FieldView, FixedField, FixedFieldType, Location, Structure};
+use crate::compiler::util::TypePathMap;
+use crate::gen::kaitai::util::{gen_field, gen_float, gen_type_path, get_byte_type, to_snake_case};
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./type.template");
+
+fn is_byte_aligned(loc: &Location, bit_size: usize) -> bool {
+    loc.bit_offset == 0 && matches!(bit_size, 8 | 16 | 32 | 64)
+}
+
+fn gen_fixed_field(template: &Template, v: &FixedField, type_path_by_name: &TypePathMap, instances: &mut String) -> String {
+    let ty = match is_byte_aligned(&v.loc, v.loc.bit_size) {
+        true => match &v.view {
+            FieldView::Transmute if v.ty != FixedFieldType::Bool => get_byte_type(v.ty).into(),
+            _ => get_byte_type(v.loc.get_unsigned_integer_type()).into()
+        },
+        false => format!("b{}", v.loc.bit_size)
+    };
+    let value_id = format!("{}_value", v.name);
+    match &v.view {
+        FieldView::Enum(e) => return gen_field(template, &v.name, &[("type", &ty), ("enum", &gen_type_path(type_path_by_name, &e.name))]),
+        FieldView::Float { a, b, .. } => {
+            *instances += &template.scope().var("id", value_id)
+                .var("value", format!("{} * {} + {}", v.name, gen_float(*a), gen_float(*b)))
+                .render("", &["instance"]).unwrap();
+        },
+        FieldView::SignedCast(max_positive) => {
+            *instances += &template.scope().var("id", value_id)
+                .var("value", format!("{0} > {1} ? {0} - {2} : {0}", v.name, max_positive, (max_positive + 1) * 2))
+                .render("", &["instance"]).unwrap();
+        },
+        FieldView::Flags(f) => {
+            for (k, bit) in &f.flags {
+                *instances += &template.scope().var("id", format!("{}_{}", v.name, to_snake_case(k)))
+                    .var("value", format!("({} & {:#x}) != 0", v.name, 1u64 << bit))
+                    .render("", &["instance"]).unwrap();
+            }
+        },
+        FieldView::Transmute | FieldView::None => ()
+    }
+    gen_field(template, &v.name, &[("type", &ty)])
+}
+
+pub fn gen_structure_decl(s: &Structure, type_path_by_name: &TypePathMap) -> String {
+    let template = Template::compile(TEMPLATE).unwrap();
+    let mut instances = String::new();
+    let mut code = template.scope().var("name", to_snake_case(&s.name)).render("", &["type"]).unwrap();
+    code += &template.render("", &["seq"]).unwrap();
+    for field in &s.fields {
+        code += &match field {
+            Field::Fixed(v) => gen_fixed_field(&template, v, type_path_by_name, &mut instances),
+            Field::Array(v) => {
+                let item_bit_size = v.item_bit_size();
+                let ty = match is_byte_aligned(&v.loc, item_bit_size) {
+                    true => get_byte_type(v.raw_item_type()).into(),
+                    false => format!("b{}", item_bit_size)
+                };
+                let repeat_expr = v.array_len.to_string();
+                match &v.view {
+                    FieldView::Enum(e) => gen_field(&template, &v.name, &[("type", &ty), ("enum", &gen_type_path(type_path_by_name, &e.name)), ("repeat", "expr"), ("repeat-expr", &repeat_expr)]),
+                    _ => gen_field(&template, &v.name, &[("type", &ty), ("repeat", "expr"), ("repeat-expr", &repeat_expr)])
+                }
+            },
+            Field::Struct(v) => gen_field(&template, &v.name, &[("type", &gen_type_path(type_path_by_name, &v.r.name))]),
+            Field::StructArray(v) => gen_field(&template, &v.name, &[("type", &gen_type_path(type_path_by_name, &v.r.name)), ("repeat", "expr"), ("repeat-expr", &v.array_len.to_string())])
+        };
+    }
+    if !instances.is_empty() {
+        code += &template.render("", &["instances"]).unwrap();
+        code += &instances;
+    }
+    code
+}
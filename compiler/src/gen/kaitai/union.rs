@@ -0,0 +1,51 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::union::Union;
+use crate::compiler::util::TypePathMap;
+use crate::gen::kaitai::util::{gen_field, gen_type_path, to_snake_case};
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./type.template");
+
+pub fn gen_union_decl(u: &Union, type_path_by_name: &TypePathMap) -> String {
+    let template = Template::compile(TEMPLATE).unwrap();
+    let mut code = template.scope().var("name", to_snake_case(&u.name)).render("", &["type"]).unwrap();
+    code += &template.render("", &["params"]).unwrap();
+    let cases = u.cases.iter().filter_map(|case| case.item_type.as_ref().map(|item_type| template.scope()
+        .var_d("case", case.case).var("type_name", gen_type_path(type_path_by_name, item_type.name()))
+        .render("switch", &["case"]).unwrap())).join("");
+    // Cases without any data simply read nothing.
+    if !cases.is_empty() {
+        code += &template.render("", &["seq"]).unwrap();
+        code += &gen_field(&template, "value", &[]);
+        code += &template.scope().var("on", "discriminant").var("cases", cases.trim_end()).render("", &["switch"]).unwrap();
+    }
+    code
+}
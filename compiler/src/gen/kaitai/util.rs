@@ -0,0 +1,89 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::structure::FixedFieldType;
+use crate::compiler::util::TypePathMap;
+use crate::gen::template::Template;
+
+pub fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).map(|v| v.is_ascii_lowercase()).unwrap_or(false);
+            if prev.is_ascii_lowercase() || prev.is_ascii_digit() || (prev.is_ascii_uppercase() && next_lower) {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
+
+/// Converts a type path (which may point into an imported protocol) to a Kaitai type reference.
+pub fn gen_type_path(type_path_by_name: &TypePathMap, name: &str) -> String {
+    let path = type_path_by_name.get(name);
+    let mut parts: Vec<&str> = path.split("::").collect();
+    let last = parts.pop().unwrap_or_default();
+    parts.into_iter().map(|v| v.to_string()).chain(std::iter::once(to_snake_case(last))).join("::")
+}
+
+pub fn get_byte_type(ty: FixedFieldType) -> &'static str {
+    match ty {
+        FixedFieldType::Int8 => "s1",
+        FixedFieldType::Int16 => "s2",
+        FixedFieldType::Int32 => "s4",
+        FixedFieldType::Int64 => "s8",
+        FixedFieldType::UInt8 => "u1",
+        FixedFieldType::UInt16 => "u2",
+        FixedFieldType::UInt32 => "u4",
+        FixedFieldType::UInt64 => "u8",
+        FixedFieldType::Float32 => "f4",
+        FixedFieldType::Float64 => "f8",
+        FixedFieldType::Bool => "u1"
+    }
+}
+
+pub fn gen_field(template: &Template, id: &str, attrs: &[(&str, &str)]) -> String {
+    let mut code = template.scope().var("id", id).render("", &["field"]).unwrap();
+    for (key, value) in attrs {
+        code += &template.scope().var("key", *key).var("value", *value).render("", &["attr"]).unwrap();
+    }
+    code
+}
+
+/// Formats a float so that it is always a valid Kaitai float literal.
+pub fn gen_float(value: f64) -> String {
+    let s = format!("{:?}", value);
+    match s.find('e') {
+        Some(pos) if !s[..pos].contains('.') => format!("{}.0{}", &s[..pos], &s[pos..]),
+        _ => s
+    }
+}
@@ -31,6 +31,11 @@ use std::path::{Path, PathBuf};
 use crate::compiler::Protocol;
 
 mod rust;
+mod java;
+mod wireshark;
+mod kaitai;
+mod docs;
+mod json_schema;
 pub mod template;
 mod swift;
 mod base;
@@ -39,6 +44,9 @@ mod base;
 pub enum FileType {
     MessageWriting,
     MessageReading,
+    MessageOwned,
+    Serde,
+    SerdeOwned,
     Message,
     Structure,
     Enum,
@@ -89,3 +97,8 @@ pub trait Generator {
 }
 
 pub use rust::GeneratorRust;
+pub use java::GeneratorJava;
+pub use wireshark::GeneratorWireshark;
+pub use kaitai::GeneratorKaitai;
+pub use docs::{GeneratorMarkdown, GeneratorHtml};
+pub use json_schema::GeneratorJsonSchema;
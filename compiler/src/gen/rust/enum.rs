@@ -28,15 +28,37 @@
 
 use itertools::Itertools;
 use crate::compiler::r#enum::Enum;
+use crate::compiler::structure::FixedFieldType;
+use crate::gen::base::structure::Utilities;
+use crate::gen::rust::util::RustUtils;
 use crate::gen::template::Template;
 
 const TEMPLATE: &[u8] = include_bytes!("./enum.template");
 
+const RAW_TYPES: &[FixedFieldType] = &[FixedFieldType::UInt8, FixedFieldType::UInt16, FixedFieldType::UInt32, FixedFieldType::UInt64];
+
+fn gen_fragments(template: &Template, path: &str, fragment: &str, e: &Enum) -> String {
+    e.variants.iter().map(|(k, v)|
+        template.scope().var("key", k).var_d("value", v)
+            .render(path, &[fragment]).unwrap()).join("")
+}
+
 pub fn gen_enum_decl(e: &Enum) -> String {
     let mut template = Template::compile(TEMPLATE).unwrap();
     template.var("name", &e.name);
-    let mut code = e.variants.iter().map(|(k, v)|
-        template.scope().var("key", k).var_d("value", v)
-            .render("enum", &["variant"]).unwrap());
-    template.var("variants", code.join("")).render("", &["enum"]).unwrap()
+    let mut code = template.scope().var("repr", RustUtils::get_field_type(e.ty))
+        .var_d("len", e.variants.len())
+        .var("all", e.variants.iter().map(|(k, _)| format!("Self::{}", k)).join(", "))
+        .var("variants", gen_fragments(&template, "enum", "variant", e))
+        .var("names", gen_fragments(&template, "enum", "name", e))
+        .var("cases", gen_fragments(&template, "enum", "case", e))
+        .render("", &["enum"]).unwrap();
+    let cases = gen_fragments(&template, "raw", "case", e);
+    // Conversions are only generated for raw types able to hold every variant.
+    for ty in RAW_TYPES.iter().filter(|v| v.get_byte_size() >= e.ty.get_byte_size()) {
+        code += "\n";
+        code += &template.scope().var("raw_type", RustUtils::get_field_type(*ty)).var("cases", &*cases)
+            .render("", &["raw"]).unwrap();
+    }
+    code
 }
@@ -0,0 +1,44 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use itertools::Itertools;
+use crate::compiler::flags::Flags;
+use crate::gen::base::structure::Utilities;
+use crate::gen::rust::util::RustUtils;
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./flags.template");
+
+pub fn gen_flags_decl(f: &Flags) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("name", &f.name).var("raw_type", RustUtils::get_field_type(f.ty))
+        .var("all", f.flags.iter().map(|(k, _)| format!("Self::{}.0", k)).join(" | "));
+    let code = f.flags.iter().map(|(k, v)|
+        template.scope().var("key", k).var_d("bit", v)
+            .render("flags", &["flag"]).unwrap()).join("");
+    template.var("flags", code).render("", &["flags"]).unwrap()
+}
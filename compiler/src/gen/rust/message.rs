@@ -27,9 +27,9 @@
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use itertools::Itertools;
-use crate::compiler::message::{FieldType, Message};
+use crate::compiler::message::{Field, FieldType, ItemType, Message, Referenced};
 use crate::compiler::util::TypePathMap;
-use crate::gen::base::message::{generate, Utilities};
+use crate::gen::base::message::{gen_field_type, gen_item_codec, gen_len_codec, Utilities};
 use crate::gen::rust::util::RustUtils;
 use crate::gen::template::Template;
 
@@ -37,26 +37,92 @@ const TEMPLATE: &[u8] = include_bytes!("./message.template");
 const TEMPLATE_EXT: &[u8] = include_bytes!("./message.ext.template");
 
 fn gen_message_array_type_decls(msg: &Message, type_path_by_name: &TypePathMap) -> String {
+    let decl_template = Template::compile(TEMPLATE).unwrap();
     let mut template = Template::compile(TEMPLATE_EXT).unwrap();
     template.var("msg_name", &msg.name);
     msg.fields.iter().filter_map(|field| {
         template.var("name", &field.name);
         match &field.ty {
             FieldType::Array(v) => Some(template.var("item_type", type_path_by_name.get(&v.item_type.name))
-                .var("codec", RustUtils::get_value_type(field.endianness, v.ty))
+                .var("len", gen_len_codec::<RustUtils>(field.endianness, v.ty, v.max_len, v.fixed, &decl_template))
                 .render("", &["decl_array"]).unwrap()),
             FieldType::List(v) => Some(template.var("item_type", type_path_by_name.get(&v.item_type.name))
-                .var("codec", RustUtils::get_value_type(field.endianness, v.ty))
+                .var("len", gen_len_codec::<RustUtils>(field.endianness, v.ty, v.max_len, v.fixed, &decl_template))
                 .render("", &["decl_list"]).unwrap()),
+            FieldType::ItemList(v) => gen_item_codec::<RustUtils>(field.endianness, &v.item_type, &decl_template, type_path_by_name)
+                .map(|item_codec| template.var("item_codec", item_codec)
+                    .var("generics", match v.item_type {
+                        ItemType::List(_) => "'a, T",
+                        _ => "T"
+                    })
+                    .var("len", gen_len_codec::<RustUtils>(field.endianness, v.ty, v.max_len, v.fixed, &decl_template))
+                    .render("", &["decl_items"]).unwrap()),
             _ => None
         }
     }).join("")
 }
 
+/// Returns true if the given field is a union or a union discriminant, which are only exposed
+/// through getters and setters.
+pub fn is_private(msg: &Message, field: &Field) -> bool {
+    msg.is_discriminant(field) || matches!(field.ty, FieldType::Union(_))
+}
+
+fn gen_message_struct(msg: &Message, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let fields = msg.fields.iter().map(|field| {
+        // Unions and their discriminants are private so that they can only be set together.
+        let fragment = if is_private(msg, field) { "field_private" } else { "field" };
+        template.scope().var("name", &field.name)
+            .var("type", gen_field_type::<RustUtils>(field, template, type_path_by_name))
+            .render("decl", &[fragment]).unwrap()
+    }).join("");
+    template.scope().var("fields", fields).render("", &["decl"]).unwrap()
+}
+
+fn gen_message_new(msg: &Message, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let discriminants: Vec<(&str, &str)> = msg.fields.iter().filter_map(|field| match &field.ty {
+        FieldType::Union(v) => Some((&*field.name, &*v.on_name)),
+        _ => None
+    }).collect();
+    if discriminants.is_empty() {
+        return String::new();
+    }
+    let params = msg.fields.iter().map(|field| {
+        let mut scope = template.scope();
+        scope.var("name", &field.name);
+        match &field.ty {
+            FieldType::Ref(Referenced::Struct(v)) if msg.is_discriminant(field) =>
+                scope.var("type_name", type_path_by_name.get(&v.name)).render("new", &["param_discriminant"]),
+            _ => scope.var("type", gen_field_type::<RustUtils>(field, template, type_path_by_name)).render("new", &["param"])
+        }.unwrap().trim_end().to_string()
+    }).join(", ");
+    let set_discriminants = discriminants.iter().map(|(name, on_name)| template.scope()
+        .var("name", *name).var("on_name", *on_name).render("new", &["set_discriminant"]).unwrap()).join("");
+    let fields = msg.fields.iter().map(|field| {
+        let fragment = if msg.is_discriminant(field) { "field_discriminant" } else { "field" };
+        template.scope().var("name", &field.name).render("new", &[fragment]).unwrap()
+    }).join("");
+    let getters = msg.fields.iter().filter(|field| is_private(msg, field)).map(|field| {
+        let mut scope = template.scope();
+        scope.var("name", &field.name).var("type", gen_field_type::<RustUtils>(field, template, type_path_by_name));
+        match &field.ty {
+            FieldType::Union(v) => scope.var("on_name", &v.on_name)
+                .var("type_name", type_path_by_name.get(&v.r.discriminant.root.name))
+                .render("new", &["getter_union"]),
+            _ => scope.render("new", &["getter"])
+        }.unwrap()
+    }).join("");
+    template.scope().var("params", params).var("set_discriminants", set_discriminants).var("fields", fields)
+        .var("getters", getters).render("", &["new"]).unwrap()
+}
+
 pub fn gen_message_decl(msg: &Message, type_path_by_name: &TypePathMap) -> String {
-    let mut code = generate::<RustUtils>(TEMPLATE, msg, type_path_by_name);
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("msg_name", &msg.name).var("generics", RustUtils::gen_generics(msg));
+    let mut code = gen_message_struct(msg, &template, type_path_by_name);
     code += "\n\n";
     code += &gen_message_array_type_decls(msg, type_path_by_name);
     code += "\n";
+    code += &gen_message_new(msg, &template, type_path_by_name);
     code
 }
@@ -28,11 +28,15 @@
 
 use crate::compiler::message::Message;
 use crate::compiler::util::TypePathMap;
-use crate::gen::base::message_from_slice::generate;
+use crate::gen::base::message_from_slice::{generate_measure, generate_with_limits};
 use crate::gen::rust::util::RustUtils;
 
 const TEMPLATE: &[u8] = include_bytes!("./message.from_slice.template");
 
 pub fn gen_message_from_slice_impl(msg: &Message, type_path_by_name: &TypePathMap) -> String {
-    generate::<RustUtils>(TEMPLATE, msg, type_path_by_name)
+    let mut code = generate_with_limits::<RustUtils>(TEMPLATE, msg, type_path_by_name);
+    if msg.is_self_delimited() {
+        code += &generate_measure::<RustUtils>(TEMPLATE, msg, type_path_by_name);
+    }
+    code
 }
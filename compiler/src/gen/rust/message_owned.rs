@@ -0,0 +1,264 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::message::{Field, FieldType, ItemListField, ItemType, Message, Referenced};
+use crate::compiler::util::TypePathMap;
+use crate::model::protocol::Endianness;
+use crate::gen::base::message::{gen_item_list_type, gen_value_type, Utilities};
+use crate::gen::base::structure::Utilities as StructureUtilities;
+use crate::gen::rust::message::is_private;
+use crate::gen::rust::util::{Generics, RustUtils};
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./message.owned.template");
+const TEMPLATE_TYPES: &[u8] = include_bytes!("./message.template");
+
+/// A conversion of a single value; the value is referred to as `{v}` in the expression.
+pub struct Conversion {
+    pub expr: String,
+    pub fallible: bool
+}
+
+impl Conversion {
+    fn new(expr: impl Into<String>, fallible: bool) -> Self {
+        Self {
+            expr: expr.into(),
+            fallible
+        }
+    }
+
+    fn apply(&self, value: &str) -> String {
+        self.expr.replace("{v}", value)
+    }
+
+    /// Generates the conversion of a field, where `value` is the place holding the field and
+    /// `as_ref` indicates whether optional values must be borrowed before being converted.
+    pub fn gen_field(&self, value: &str, optional: bool, as_ref: bool) -> String {
+        let code = match optional {
+            false => self.apply(value),
+            true if self.expr == "{v}" => value.into(),
+            true if as_ref => format!("{}.as_ref().map(|v| {})", value, self.apply("v")),
+            true => format!("{}.map(|v| {})", value, self.apply("v"))
+        };
+        match (self.fallible, optional) {
+            (true, true) => code + ".transpose()?",
+            (true, false) => code + "?",
+            (false, _) => code
+        }
+    }
+}
+
+/// Returns the path of the owned counterpart of a referenced type.
+pub fn gen_owned_ref_type(r: &Referenced, type_path_by_name: &TypePathMap) -> String {
+    match r {
+        Referenced::Struct(v) => format!("{}<[u8; {}]>", type_path_by_name.get(&v.name), v.byte_size),
+        Referenced::Message(v) => format!("{}Owned", type_path_by_name.get(&v.name))
+    }
+}
+
+pub fn gen_ref_to_owned(r: &Referenced) -> Conversion {
+    match r {
+        Referenced::Struct(_) => Conversion::new("{v}.to_owned()", false),
+        Referenced::Message(_) => Conversion::new("{v}.to_owned()", true)
+    }
+}
+
+pub fn gen_ref_as_borrowed(r: &Referenced) -> Conversion {
+    match r {
+        Referenced::Struct(_) => Conversion::new("{v}.to_ref()", false),
+        Referenced::Message(_) => Conversion::new("{v}.as_borrowed(arena)", true)
+    }
+}
+
+/// Returns the owned counterpart of a single list item.
+fn gen_owned_item_type(ty: &ItemType, type_path_by_name: &TypePathMap) -> String {
+    match ty {
+        ItemType::Struct(v) => gen_owned_ref_type(&Referenced::Struct(v.clone()), type_path_by_name),
+        ItemType::Message(v) => gen_owned_ref_type(&Referenced::Message(v.clone()), type_path_by_name),
+        ItemType::Value(v) => gen_value_type::<RustUtils>(v, type_path_by_name),
+        ItemType::NullTerminatedString | ItemType::VarcharString(_) => "bp3d_proto::alloc::string::String".into(),
+        ItemType::List(v) => format!("bp3d_proto::alloc::vec::Vec<{}>", gen_owned_item_type(&v.item_type, type_path_by_name))
+    }
+}
+
+fn gen_item_to_owned(ty: &ItemType, type_path_by_name: &TypePathMap) -> Conversion {
+    match ty {
+        ItemType::Struct(v) => gen_ref_to_owned(&Referenced::Struct(v.clone())),
+        ItemType::Message(v) => gen_ref_to_owned(&Referenced::Message(v.clone())),
+        ItemType::Value(_) => Conversion::new("{v}", false),
+        ItemType::NullTerminatedString | ItemType::VarcharString(_) => Conversion::new("{v}.into()", false),
+        ItemType::List(v) => gen_item_list_to_owned(v, type_path_by_name)
+    }
+}
+
+fn gen_item_list_to_owned(v: &ItemListField, type_path_by_name: &TypePathMap) -> Conversion {
+    if let ItemType::Struct(r) = &v.item_type {
+        return Conversion::new(format!("{{v}}.iter::<{}<&[u8]>>().map(|v| v.to_owned()).collect()",
+            type_path_by_name.get(&r.name)), false);
+    }
+    let item = gen_item_to_owned(&v.item_type, type_path_by_name);
+    let map = match (item.expr == "{v}", item.fallible) {
+        (true, _) => String::new(),
+        (false, true) => format!(".map(|v| v.and_then(|v| {}))", item.apply("v")),
+        (false, false) => format!(".map(|v| v.map(|v| {}))", item.apply("v"))
+    };
+    Conversion::new(format!("{{v}}.iter(){}.collect::<bp3d_proto::message::Result<bp3d_proto::alloc::vec::Vec<_>>>()", map), true)
+}
+
+fn gen_item_as_borrowed(endianness: Endianness, ty: &ItemType, template: &Template, type_path_by_name: &TypePathMap) -> Conversion {
+    match ty {
+        ItemType::Struct(v) => gen_ref_as_borrowed(&Referenced::Struct(v.clone())),
+        ItemType::Message(v) => gen_ref_as_borrowed(&Referenced::Message(v.clone())),
+        ItemType::Value(_) => Conversion::new("*{v}", false),
+        ItemType::NullTerminatedString | ItemType::VarcharString(_) => Conversion::new("{v}.as_str()", false),
+        // The list type must be spelled out as it cannot be inferred from the items.
+        ItemType::List(v) => Conversion::new(format!("<{}>::from_items(arena, {})",
+            gen_item_list_type::<RustUtils>(endianness, v, template, type_path_by_name),
+            gen_item_list_as_borrowed(endianness, v, template, type_path_by_name)), true)
+    }
+}
+
+/// Generates the iterator of borrowed items passed to `from_items`.
+fn gen_item_list_as_borrowed(endianness: Endianness, v: &ItemListField, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let item = gen_item_as_borrowed(endianness, &v.item_type, template, type_path_by_name);
+    match item.fallible {
+        true => format!("{{v}}.iter().map(|v| {})", item.apply("v")),
+        false => format!("{{v}}.iter().map(|v| Ok({}))", item.apply("v"))
+    }
+}
+
+pub fn gen_owned_type(field: &Field, type_path_by_name: &TypePathMap) -> String {
+    let ty = match &field.ty {
+        FieldType::Fixed(v) => RustUtils::get_field_type(v.ty).into(),
+        FieldType::Enum(v) => type_path_by_name.get(&v.name).into(),
+        FieldType::Ref(v) => gen_owned_ref_type(v, type_path_by_name),
+        FieldType::NullTerminatedString | FieldType::VarcharString(_) => "bp3d_proto::alloc::string::String".into(),
+        FieldType::Array(v) => format!("bp3d_proto::alloc::vec::Vec<{}>", gen_owned_ref_type(&Referenced::Struct(v.item_type.clone()), type_path_by_name)),
+        FieldType::List(v) => format!("bp3d_proto::alloc::vec::Vec<{}>", gen_owned_ref_type(&Referenced::Message(v.item_type.clone()), type_path_by_name)),
+        FieldType::ItemList(v) => format!("bp3d_proto::alloc::vec::Vec<{}>", gen_owned_item_type(&v.item_type, type_path_by_name)),
+        FieldType::Union(v) => format!("{}Owned", type_path_by_name.get(&v.r.name)),
+        FieldType::Payload => "bp3d_proto::alloc::vec::Vec<u8>".into()
+    };
+    match field.optional {
+        true => RustUtils::gen_option_type(&ty),
+        false => ty
+    }
+}
+
+fn gen_to_owned(field: &Field, type_path_by_name: &TypePathMap) -> Conversion {
+    match &field.ty {
+        FieldType::Fixed(_) | FieldType::Enum(_) => Conversion::new("{v}", false),
+        FieldType::Ref(v) => gen_ref_to_owned(v),
+        FieldType::NullTerminatedString | FieldType::VarcharString(_) => Conversion::new("bp3d_proto::alloc::string::String::from({v})", false),
+        FieldType::Array(v) => Conversion::new(format!("{{v}}.iter::<{}<&[u8]>>().map(|v| v.to_owned()).collect()",
+            type_path_by_name.get(&v.item_type.name)), false),
+        FieldType::List(_) => Conversion::new("{v}.iter().map(|v| v.and_then(|v| v.to_owned()))\
+            .collect::<bp3d_proto::message::Result<bp3d_proto::alloc::vec::Vec<_>>>()", true),
+        FieldType::ItemList(v) => gen_item_list_to_owned(v, type_path_by_name),
+        FieldType::Union(_) => Conversion::new("{v}.to_owned()", true),
+        FieldType::Payload => Conversion::new("{v}.to_vec()", false)
+    }
+}
+
+fn gen_as_borrowed(field: &Field, template: &Template, type_path_by_name: &TypePathMap) -> Conversion {
+    match &field.ty {
+        FieldType::Fixed(_) | FieldType::Enum(_) => Conversion::new("{v}", false),
+        FieldType::Ref(v) => gen_ref_as_borrowed(v),
+        FieldType::NullTerminatedString | FieldType::VarcharString(_) => Conversion::new("{v}.as_str()", false),
+        FieldType::Array(_) => Conversion::new("bp3d_proto::message::util::Array::from_items(arena, {v}.iter().map(|v| Ok(v.to_ref())))", true),
+        FieldType::List(_) => Conversion::new("bp3d_proto::message::util::List::from_items(arena, {v}.iter().map(|v| v.as_borrowed(arena)))", true),
+        FieldType::ItemList(v) => Conversion::new(format!("bp3d_proto::message::util::List::from_items(arena, {})",
+            gen_item_list_as_borrowed(field.endianness, v, template, type_path_by_name)), true),
+        FieldType::Union(_) => Conversion::new("{v}.as_borrowed(arena)", true),
+        FieldType::Payload => Conversion::new("{v}.as_slice()", false)
+    }
+}
+
+fn uses_arena(field: &Field) -> bool {
+    matches!(&field.ty, FieldType::Ref(Referenced::Message(_)) | FieldType::Array(_)
+        | FieldType::List(_) | FieldType::ItemList(_) | FieldType::Union(_))
+}
+
+fn gen_message_owned_new(msg: &Message, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let unions: Vec<(&str, &str)> = msg.fields.iter().filter_map(|field| match &field.ty {
+        FieldType::Union(v) => Some((&*field.name, &*v.on_name)),
+        _ => None
+    }).collect();
+    if unions.is_empty() {
+        return String::new();
+    }
+    let params = msg.fields.iter().map(|field| {
+        let fragment = if msg.is_discriminant(field) { "param_discriminant" } else { "param" };
+        template.scope().var("name", &field.name).var("type", gen_owned_type(field, type_path_by_name))
+            .render("new", &[fragment]).unwrap().trim_end().to_string()
+    }).join(", ");
+    let set_discriminants = unions.iter().map(|(name, on_name)| template.scope()
+        .var("name", *name).var("on_name", *on_name).render("new", &["set_discriminant"]).unwrap()).join("");
+    let fields = msg.fields.iter().map(|field| template.scope().var("name", &field.name)
+        .render("new", &["field"]).unwrap()).join("");
+    let getters = msg.fields.iter().filter(|field| is_private(msg, field)).map(|field| {
+        let mut scope = template.scope();
+        scope.var("name", &field.name).var("type", gen_owned_type(field, type_path_by_name));
+        match &field.ty {
+            FieldType::Union(v) => scope.var("on_name", &v.on_name).render("new", &["getter_union"]),
+            _ => scope.render("new", &["getter"])
+        }.unwrap()
+    }).join("");
+    template.scope().var("params", params).var("set_discriminants", set_discriminants).var("fields", fields)
+        .var("getters", getters).render("", &["new"]).unwrap()
+}
+
+pub fn gen_message_owned_decl(msg: &Message, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("msg_name", &msg.name);
+    let generics = Generics::from_message(msg);
+    // Messages which do not borrow anything are already owned.
+    if !generics.has_lifetime {
+        return template.render("", &["alias"]).unwrap();
+    }
+    let fields = msg.fields.iter().map(|field| template.scope().var("name", &field.name)
+        .var("type", gen_owned_type(field, type_path_by_name))
+        .render("decl", &[if is_private(msg, field) { "field_private" } else { "field" }]).unwrap()).join("");
+    let to_owned = msg.fields.iter().map(|field| template.scope().var("name", &field.name)
+        .var("value", gen_to_owned(field, type_path_by_name).gen_field(&format!("self.{}", field.name), field.optional, false))
+        .render("", &["convert"]).unwrap()).join("");
+    let types = Template::compile(TEMPLATE_TYPES).unwrap();
+    let as_borrowed = msg.fields.iter().map(|field| template.scope().var("name", &field.name)
+        .var("value", gen_as_borrowed(field, &types, type_path_by_name).gen_field(&format!("self.{}", field.name), field.optional, true))
+        .render("", &["convert"]).unwrap()).join("");
+    let mut code = template.scope()
+        .var("generics", generics.to_code())
+        .var("arena", if msg.fields.iter().any(uses_arena) { "arena" } else { "_" })
+        .var("fields", fields)
+        .var("to_owned", to_owned.trim_end().to_string())
+        .var("as_borrowed", as_borrowed.trim_end().to_string())
+        .render("", &["decl"]).unwrap();
+    code += &gen_message_owned_new(msg, &template, type_path_by_name);
+    code
+}
@@ -28,11 +28,13 @@
 
 use crate::compiler::message::Message;
 use crate::compiler::util::TypePathMap;
-use crate::gen::base::message_write::generate;
+use crate::gen::base::message_write::{generate, generate_fragment};
 use crate::gen::rust::util::RustUtils;
 
 const TEMPLATE: &[u8] = include_bytes!("./message.write.template");
 
 pub fn gen_message_write_impl(msg: &Message, type_path_by_name: &TypePathMap) -> String {
-    generate::<RustUtils>(TEMPLATE, msg, type_path_by_name)
+    let mut code = generate::<RustUtils>(TEMPLATE, msg, type_path_by_name);
+    code += &generate_fragment::<RustUtils>(TEMPLATE, "impl_slice", msg, type_path_by_name);
+    code
 }
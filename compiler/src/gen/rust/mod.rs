@@ -32,8 +32,13 @@ mod util;
 mod message_write;
 pub mod structure;
 mod r#enum;
+mod flags;
 mod union;
 mod message_offsets;
+mod message_owned;
+mod union_owned;
+mod serde;
+mod serde_owned;
 
 use std::path::Path;
 use bp3d_util::simple_error;
@@ -43,10 +48,15 @@ use crate::gen::{File, FileType, Generator};
 use crate::gen::rust::message::gen_message_decl;
 use crate::gen::rust::message_from_slice::gen_message_from_slice_impl;
 use crate::gen::rust::message_offsets::gen_message_offsets_decl;
+use crate::gen::rust::message_owned::gen_message_owned_decl;
 use crate::gen::rust::message_write::gen_message_write_impl;
 use crate::gen::rust::r#enum::gen_enum_decl;
+use crate::gen::rust::flags::gen_flags_decl;
+use crate::gen::rust::serde::{gen_enum_serde, gen_flags_serde, gen_message_serde, gen_structure_serde, gen_union_serde};
+use crate::gen::rust::serde_owned::{gen_message_serde_owned, gen_union_serde_owned};
 use crate::gen::rust::structure::gen_structure_decl;
 use crate::gen::rust::union::gen_union_decl;
+use crate::gen::rust::union_owned::gen_union_owned_decl;
 
 simple_error! {
     pub Error {
@@ -65,15 +75,32 @@ impl Generator for GeneratorRust {
         let impl_write_messages_code = proto.messages.iter().map(|v| gen_message_write_impl(v, &proto.type_path_by_name)).join("\n");
         let decl_structures = proto.structs.iter().map(|v| gen_structure_decl(v, &proto.type_path_by_name)).join("\n");
         let decl_enums = proto.enums.iter().map(|v| gen_enum_decl(v)).join("\n");
+        let decl_flags = proto.flags.iter().map(|v| gen_flags_decl(v)).join("\n");
         let decl_unions = proto.unions.iter().map(|v| gen_union_decl(v, &proto.type_path_by_name)).join("\n");
         let decl_messages_code_offsets = proto.messages.iter().map(|v| gen_message_offsets_decl(v, &proto.type_path_by_name)).join("\n");
+        let decl_messages_owned = proto.messages.iter().map(|v| gen_message_owned_decl(v, &proto.type_path_by_name)).join("\n");
+        let decl_unions_owned = proto.unions.iter().map(|v| gen_union_owned_decl(v, &proto.type_path_by_name)).join("\n");
+        let serde = proto.enums.iter().map(|v| gen_enum_serde(v))
+            .chain(proto.flags.iter().map(|v| gen_flags_serde(v)))
+            .chain(proto.structs.iter().map(|v| gen_structure_serde(v, &proto.type_path_by_name)))
+            .chain(proto.unions.iter().map(|v| gen_union_serde(v)))
+            .chain(proto.messages.iter().map(|v| gen_message_serde(v)))
+            .join("\n");
+        let serde_owned = proto.unions.iter().map(|v| gen_union_serde_owned(v, &proto.type_path_by_name))
+            .chain(proto.messages.iter().map(|v| gen_message_serde_owned(v, &proto.type_path_by_name)))
+            .join("\n");
         Ok(vec![
             File::new(FileType::Message, "messages.rs", decl_messages_code),
             File::new(FileType::MessageReading, "messages_from_slice.rs", impl_from_slice_messages_code),
             File::new(FileType::MessageWriting, "messages_write.rs", impl_write_messages_code),
             File::new(FileType::MessageReading, "messages_offsets.rs", decl_messages_code_offsets),
+            File::new(FileType::MessageOwned, "messages_owned.rs", decl_messages_owned),
+            File::new(FileType::MessageOwned, "unions_owned.rs", decl_unions_owned),
+            File::new(FileType::Serde, "serde.rs", serde),
+            File::new(FileType::SerdeOwned, "serde_owned.rs", serde_owned),
             File::new(FileType::Structure, "structures.rs", decl_structures),
             File::new(FileType::Enum, "enums.rs", decl_enums),
+            File::new(FileType::Enum, "flags.rs", decl_flags),
             File::new(FileType::Union, "unions.rs", decl_unions)
         ])
     }
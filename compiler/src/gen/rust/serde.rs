@@ -0,0 +1,147 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::message::Message;
+use crate::compiler::r#enum::Enum;
+use crate::compiler::flags::Flags;
+use crate::compiler::structure::{Field, FieldView, FixedFieldType, Structure};
+use crate::compiler::union::Union;
+use crate::compiler::util::TypePathMap;
+use crate::gen::base::structure::Utilities;
+use crate::gen::rust::util::{Generics, RustUtils};
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./serde.template");
+
+pub fn gen_enum_serde(e: &Enum) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("name", &e.name)
+        .var("names", e.variants.iter().map(|(k, _)| format!("{:?}", k)).join(", "));
+    let variants = e.variants.iter().enumerate().map(|(i, (k, _))| template.scope()
+        .var("key", k).var_d("index", i).render("enum", &["variant"]).unwrap()).join("");
+    let cases = e.variants.iter().map(|(k, _)| template.scope()
+        .var("key", k).render("enum", &["case"]).unwrap()).join("");
+    template.var("variants", variants.trim_end().to_string()).var("cases", cases.trim_end().to_string()).render("", &["enum"]).unwrap()
+}
+
+pub fn gen_flags_serde(f: &Flags) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("name", &f.name).var("raw_type", RustUtils::get_field_type(f.ty))
+        .render("", &["flags"]).unwrap()
+}
+
+fn gen_view_type(view: &FieldView, ty: FixedFieldType, type_path_by_name: &TypePathMap) -> String {
+    match view {
+        FieldView::Enum(e) => format!("Option<{}>", type_path_by_name.get(&e.name)),
+        FieldView::Flags(f) => type_path_by_name.get(&f.name).into(),
+        _ => RustUtils::get_field_type(ty).into()
+    }
+}
+
+fn gen_field_type(field: &Field, type_path_by_name: &TypePathMap) -> String {
+    match field {
+        Field::Fixed(v) => gen_view_type(&v.view, v.ty, type_path_by_name),
+        Field::Array(v) if v.has_view() => format!("bp3d_proto::alloc::vec::Vec<{}>", gen_view_type(&v.view, v.ty, type_path_by_name)),
+        Field::Array(v) => format!("bp3d_proto::alloc::vec::Vec<{}>", RustUtils::get_field_type(v.raw_item_type())),
+        Field::Struct(v) => format!("{}Serde", type_path_by_name.get(&v.r.name)),
+        Field::StructArray(v) => format!("bp3d_proto::alloc::vec::Vec<{}Serde>", type_path_by_name.get(&v.r.name))
+    }
+}
+
+pub fn gen_structure_serde(s: &Structure, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("name", &s.name).var_d("byte_size", s.byte_size).var_d("len", s.fields.len());
+    let serialize = s.fields.iter().map(|field| {
+        let fragment = match field {
+            Field::StructArray(_) => "serialize_struct_array",
+            _ => "serialize"
+        };
+        template.scope().var("field", field.name()).render("structure", &[fragment]).unwrap()
+    }).join("");
+    let fields = s.fields.iter().map(|field| template.scope().var("field", field.name())
+        .var("type", gen_field_type(field, type_path_by_name))
+        .render("structure", &["field"]).unwrap()).join("");
+    let apply = s.fields.iter().map(|field| {
+        let mut scope = template.scope();
+        scope.var("field", field.name());
+        match field {
+            Field::Fixed(v) if matches!(v.view, FieldView::Enum(_)) => scope.render("structure", &["apply_enum"]),
+            Field::Fixed(_) => scope.render("structure", &["apply_fixed"]),
+            Field::Array(v) if matches!(v.view, FieldView::Enum(_)) => scope.var_d("array_len", v.array_len).render("structure", &["apply_array_enum"]),
+            Field::Array(v) if v.has_view() => scope.var_d("array_len", v.array_len).render("structure", &["apply_array_view"]),
+            Field::Array(v) => scope.var_d("array_len", v.array_len).render("structure", &["apply_array"]),
+            Field::Struct(_) => scope.render("structure", &["apply_struct"]),
+            Field::StructArray(v) => scope.var_d("array_len", v.array_len).render("structure", &["apply_struct_array"])
+        }.unwrap()
+    }).join("");
+    template.var("serialize", serialize.trim_end().to_string()).var("fields", fields.trim_end().to_string()).var("apply", apply.trim_end().to_string())
+        .render("", &["structure"]).unwrap()
+}
+
+/// Generates the Serialize implementation of a message; `ty` is the name of the type to
+/// implement, which is either the message itself or its owned counterpart.
+pub fn gen_message_serialize(msg: &Message, ty: &str, generics: &str) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("name", &msg.name).var("type", ty).var("generics", generics)
+        .var_d("len", msg.fields.len());
+    let fields = msg.fields.iter().map(|field| template.scope().var("field", &field.name)
+        .render("message", &["field"]).unwrap()).join("");
+    template.var("fields", fields.trim_end().to_string()).render("", &["message"]).unwrap()
+}
+
+pub fn gen_message_serde(msg: &Message) -> String {
+    gen_message_serialize(msg, &msg.name, &Generics::from_message(msg).to_code())
+}
+
+/// Generates the Serialize implementation of a union; `ty` is the name of the type to
+/// implement, which is either the union itself or its owned counterpart.
+pub fn gen_union_serialize(u: &Union, ty: &str, generics: &str) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("name", &u.name).var("type", ty).var("generics", generics);
+    let mut cases = u.cases.iter().enumerate().map(|(i, case)| {
+        let mut scope = template.scope();
+        scope.var("case", &case.name).var_d("index", i);
+        match case.item_type {
+            Some(_) => scope.render("union", &["ref"]).unwrap(),
+            None => scope.render("union", &["none"]).unwrap()
+        }
+    }).join("");
+    if u.unknown {
+        cases += &template.scope().var_d("index", u.cases.len()).render("union", &["unknown"]).unwrap();
+    }
+    template.var("cases", cases.trim_end().to_string()).render("", &["union"]).unwrap()
+}
+
+pub fn gen_union_serde(u: &Union) -> String {
+    let generics = match u.has_data() {
+        true => "<'a>",
+        false => ""
+    };
+    gen_union_serialize(u, &u.name, generics)
+}
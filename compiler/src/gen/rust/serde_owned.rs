@@ -0,0 +1,98 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::message::{FieldType, Message};
+use crate::compiler::union::Union;
+use crate::compiler::util::TypePathMap;
+use crate::gen::rust::message_owned::{gen_owned_ref_type, gen_owned_type};
+use crate::gen::rust::serde::{gen_message_serialize, gen_union_serialize};
+use crate::gen::rust::util::Generics;
+use crate::gen::base::structure::Utilities;
+use crate::gen::rust::util::RustUtils;
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./serde.owned.template");
+
+pub fn gen_message_serde_owned(msg: &Message, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("name", &msg.name);
+    let fields = msg.fields.iter().map(|field| template.scope().var("field", &field.name)
+        .var("type", gen_owned_type(field, type_path_by_name))
+        .render("message", &["field"]).unwrap()).join("");
+    let construct = match msg.fields.iter().any(|field| matches!(field.ty, FieldType::Union(_))) {
+        true => template.scope().var("args", msg.fields.iter().map(|field| format!("value.{}", field.name)).join(", "))
+            .render("message", &["construct_new"]).unwrap(),
+        false => {
+            let convert = msg.fields.iter().map(|field| template.scope().var("field", &field.name)
+                .render("message", &["convert"]).unwrap()).join("");
+            template.scope().var("convert", convert.trim_end().to_string()).render("message", &["construct"]).unwrap()
+        }
+    };
+    let mut code = template.var("fields", fields.trim_end().to_string()).var("construct", construct.trim_end().to_string())
+        .render("", &["message"]).unwrap();
+    // Owned aliases are the borrowed type itself which is already serializable.
+    if Generics::from_message(msg).has_lifetime {
+        code += &gen_message_serialize(msg, &format!("{}Owned", msg.name), "");
+    }
+    code
+}
+
+pub fn gen_union_serde_owned(u: &Union, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("name", &u.name);
+    let mut cases = u.cases.iter().map(|case| {
+        let mut scope = template.scope();
+        scope.var("case", &case.name);
+        match &case.item_type {
+            Some(item_type) => scope.var("type", gen_owned_ref_type(item_type, type_path_by_name))
+                .render("union", &["ref"]).unwrap(),
+            None => scope.render("union", &["none"]).unwrap()
+        }
+    }).join("");
+    let mut convert = u.cases.iter().map(|case| {
+        let mut scope = template.scope();
+        scope.var("case", &case.name);
+        match &case.item_type {
+            Some(_) => scope.render("union.convert", &["ref"]).unwrap(),
+            None => scope.render("union.convert", &["none"]).unwrap()
+        }
+    }).join("");
+    if u.unknown {
+        cases += &template.scope().var("discriminant_raw_type", RustUtils::get_field_type(u.discriminant.get_raw_type()))
+            .render("union", &["unknown"]).unwrap();
+        convert += &template.render("union.convert", &["unknown"]).unwrap();
+    }
+    let mut code = template.var("cases", cases.trim_end().to_string()).var("convert", convert.trim_end().to_string())
+        .render("", &["union"]).unwrap();
+    // Owned aliases are the borrowed type itself which is already serializable.
+    if u.has_data() {
+        code += &gen_union_serialize(u, &format!("{}Owned", u.name), "");
+    }
+    code
+}
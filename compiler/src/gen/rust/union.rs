@@ -30,12 +30,14 @@ use itertools::Itertools;
 use crate::compiler::message::Referenced;
 use crate::compiler::union::Union;
 use crate::compiler::util::TypePathMap;
+use crate::gen::base::structure::Utilities;
+use crate::gen::rust::util::RustUtils;
 use crate::gen::template::Template;
 
 const TEMPLATE: &[u8] = include_bytes!("./union.template");
 
 fn get_generics(u: &Union) -> &str {
-    if u.cases.iter().any(|v| v.item_type.is_some()) {
+    if u.has_data() {
         "<'a>"
     } else {
         ""
@@ -58,41 +60,47 @@ fn get_discriminant_path_mut(u: &Union) -> String {
     }).join(".")
 }
 
-fn gen_union_from_slice_impl(u: &Union, template: &Template, type_path_by_name: &TypePathMap) -> String {
+fn gen_union_read_impl(u: &Union, fragment: &str, template: &Template, type_path_by_name: &TypePathMap) -> String {
     let cases = u.cases.iter().map(|case| {
         let mut scope = template.scope();
         scope.var("name", &case.name).var_d("case", case.case);
         match &case.item_type {
-            None => scope.render("from_slice.none", &["case"]).unwrap(),
+            None => scope.render(&format!("{}.none", fragment), &["case"]).unwrap(),
             Some(item_type) => scope.var("type_name", type_path_by_name.get(item_type.name()))
-                .render("from_slice.generics", &["case"]).unwrap()
+                .render(&format!("{}.generics", fragment), &["case"]).unwrap()
         }
     }).join("");
     let mut scope = template.scope();
     scope.var("cases", cases);
     let generics = get_generics(u);
     if generics != "" {
-        scope.render_to_var("from_slice", &["generics"], "fragment").unwrap();
+        scope.render_to_var(fragment, &["generics"], "fragment").unwrap();
     } else {
-        scope.render_to_var("from_slice", &["none"], "fragment").unwrap();
+        scope.render_to_var(fragment, &["none"], "fragment").unwrap();
     }
-    scope.render("", &["from_slice"]).unwrap()
+    scope.render("", &[fragment]).unwrap()
 }
 
 fn gen_union_write_to_impl(u: &Union, template: &Template, type_path_by_name: &TypePathMap) -> String {
     let generics = get_generics(u);
     let mut scope = template.scope();
     if generics != "" {
-        let cases = u.cases.iter().filter_map(|case| {
+        let gen_cases = |fragment: &str| u.cases.iter().filter_map(|case| {
             let mut scope = template.scope();
             scope.var("name", &case.name).var_d("case", case.case);
             match &case.item_type {
                 None => None,
                 Some(item_type) => Some(scope.var("type_name", type_path_by_name.get(item_type.name()))
-                    .render("write_to.generics", &["case"]).unwrap())
+                    .render("write_to.generics", &[fragment]).unwrap())
             }
         }).join("");
-        scope.var("cases", cases).render_to_var("write_to", &["generics"], "fragment").unwrap();
+        let gen_unknown = |fragment: &str| match u.unknown {
+            true => template.render("write_to.generics", &[fragment]).unwrap(),
+            false => String::new()
+        };
+        scope.var("cases", gen_cases("case") + &gen_unknown("case_unknown"))
+            .var("cases_slice", gen_cases("case_slice") + &gen_unknown("case_slice_unknown"))
+            .render_to_var("write_to", &["generics"], "fragment").unwrap();
     } else {
         scope.render_to_var("write_to", &["none"], "fragment").unwrap();
     }
@@ -100,7 +108,7 @@ fn gen_union_write_to_impl(u: &Union, template: &Template, type_path_by_name: &T
 }
 
 fn gen_union_set_discriminant(u: &Union, template: &Template) -> String {
-    let cases = u.cases.iter().map(|case| {
+    let mut cases = u.cases.iter().map(|case| {
         let mut scope = template.scope();
         scope.var("name", &case.name).var_d("case", case.case);
         match &case.item_type {
@@ -108,11 +116,23 @@ fn gen_union_set_discriminant(u: &Union, template: &Template) -> String {
             None => scope.render("setter", &["none"]).unwrap()
         }
     }).join("");
+    if u.unknown {
+        cases += &template.render("setter", &["unknown"]).unwrap();
+    }
     template.scope().var("cases", cases).render("", &["setter"]).unwrap()
 }
 
+/// Generates set_discriminant for the owned counterpart of a union, which has the same cases.
+pub fn gen_union_owned_set_discriminant(u: &Union, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("union_name", format!("{}Owned", u.name)).var("generics", "")
+        .var("discriminant_path_mut", get_discriminant_path_mut(u))
+        .var("discriminant_type", type_path_by_name.get(&u.discriminant.root.name));
+    gen_union_set_discriminant(u, &template)
+}
+
 fn gen_union_as_getters(u: &Union, template: &Template, type_path_by_name: &TypePathMap) -> String {
-    let cases = u.cases.iter().map(|case| {
+    let mut cases = u.cases.iter().map(|case| {
         let mut scope = template.scope();
         scope.var("name_lower", case.name.to_ascii_lowercase()).var("name", &case.name);
         match &case.item_type {
@@ -123,6 +143,9 @@ fn gen_union_as_getters(u: &Union, template: &Template, type_path_by_name: &Type
             None => scope.render("getters", &["none"]).unwrap()
         }
     }).join("");
+    if u.unknown {
+        cases += &template.render("getters", &["unknown"]).unwrap();
+    }
     template.scope().var("cases", cases).render("", &["getters"]).unwrap()
 }
 
@@ -132,8 +155,14 @@ pub fn gen_union_decl(u: &Union, type_path_by_name: &TypePathMap) -> String {
     template.var("union_name", &u.name).var("generics", generics)
         .var("discriminant_path_mut", get_discriminant_path_mut(u))
         .var("discriminant_path", get_discriminant_path(u))
-        .var("discriminant_type", type_path_by_name.get(&u.discriminant.root.name));
-    let cases = u.cases.iter().map(|case| match &case.item_type {
+        .var("discriminant_type", type_path_by_name.get(&u.discriminant.root.name))
+        .var("discriminant_raw_type", RustUtils::get_field_type(u.discriminant.get_raw_type()));
+    let default_case = match u.unknown {
+        true => template.render("", &["default_case_unknown"]).unwrap(),
+        false => template.render("", &["default_case"]).unwrap()
+    };
+    template.var("default_case", default_case.trim_end().to_string());
+    let mut cases = u.cases.iter().map(|case| match &case.item_type {
         None => template.scope().var("name", &case.name).render("decl", &["none"]).unwrap(),
         Some(Referenced::Struct(v)) => template.scope()
             .var("name", &case.name).var("type_name", type_path_by_name.get(&v.name))
@@ -142,8 +171,15 @@ pub fn gen_union_decl(u: &Union, type_path_by_name: &TypePathMap) -> String {
             .var("name", &case.name).var("type_name", type_path_by_name.get(&v.name))
             .render("decl", &["message"]).unwrap()
     }).join("");
+    if u.unknown {
+        cases += &template.render("decl", &["unknown"]).unwrap();
+    }
     let mut code = template.scope().var("cases", cases).render("", &["decl"]).unwrap();
-    code += &gen_union_from_slice_impl(u, &template, type_path_by_name);
+    code += &gen_union_read_impl(u, "from_slice", &template, type_path_by_name);
+    code += &gen_union_read_impl(u, "from_slice_with_limits", &template, type_path_by_name);
+    if u.is_self_delimited() {
+        code += &gen_union_read_impl(u, "measure", &template, type_path_by_name);
+    }
     code += &gen_union_write_to_impl(u, &template, type_path_by_name);
     code += &gen_union_set_discriminant(u, &template);
     code += &gen_union_as_getters(u, &template, type_path_by_name);
@@ -0,0 +1,88 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::message::Referenced;
+use crate::compiler::union::Union;
+use crate::compiler::util::TypePathMap;
+use crate::gen::rust::message_owned::{gen_owned_ref_type, gen_ref_as_borrowed, gen_ref_to_owned, Conversion};
+use crate::gen::base::structure::Utilities;
+use crate::gen::rust::union::gen_union_owned_set_discriminant;
+use crate::gen::rust::util::RustUtils;
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./union.owned.template");
+
+fn gen_conversion(u: &Union, template: &Template, from: &str, to: &str, unknown: &str, f: impl Fn(&Referenced) -> Conversion) -> String {
+    let mut code = u.cases.iter().map(|case| {
+        let mut scope = template.scope();
+        scope.var("name", &case.name).var("from", from).var("to", to);
+        match &case.item_type {
+            None => scope.render("convert", &["none"]).unwrap(),
+            Some(item_type) => scope.var("value", f(item_type).gen_field("v", false, false))
+                .render("convert", &["ref"]).unwrap()
+        }
+    }).join("");
+    if u.unknown {
+        code += &template.scope().var("from", from).var("to", to).render("convert", &[unknown]).unwrap();
+    }
+    code
+}
+
+pub fn gen_union_owned_decl(u: &Union, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("union_name", &u.name);
+    // Unions without any data do not borrow anything and are therefore already owned.
+    if !u.has_data() {
+        return template.render("", &["alias"]).unwrap();
+    }
+    let mut cases = u.cases.iter().map(|case| {
+        let mut scope = template.scope();
+        scope.var("name", &case.name);
+        match &case.item_type {
+            None => scope.render("decl", &["none"]).unwrap(),
+            Some(item_type) => scope.var("type_name", gen_owned_ref_type(item_type, type_path_by_name))
+                .render("decl", &["ref"]).unwrap()
+        }
+    }).join("");
+    if u.unknown {
+        cases += &template.scope().var("discriminant_raw_type", RustUtils::get_field_type(u.discriminant.get_raw_type()))
+            .render("decl", &["unknown"]).unwrap();
+    }
+    let uses_arena = u.cases.iter().any(|v| matches!(v.item_type, Some(Referenced::Message(_))));
+    let owned_name = format!("{}Owned", u.name);
+    let mut code = template.scope()
+        .var("cases", cases)
+        .var("arena", if uses_arena { "arena" } else { "_" })
+        .var("to_owned", gen_conversion(u, &template, "Self", &owned_name, "unknown_to_owned", gen_ref_to_owned).trim_end().to_string())
+        .var("as_borrowed", gen_conversion(u, &template, "Self", &u.name, "unknown_as_borrowed", gen_ref_as_borrowed).trim_end().to_string())
+        .render("", &["decl"]).unwrap();
+    code += "\n";
+    code += &gen_union_owned_set_discriminant(u, type_path_by_name);
+    code
+}
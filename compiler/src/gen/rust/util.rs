@@ -44,6 +44,7 @@ impl Generics {
             FieldType::Array(_) => true,
             FieldType::Union(_) => true,
             FieldType::List(_) => true,
+            FieldType::ItemList(_) => true,
             FieldType::Payload => true,
             _ => false
         });
@@ -133,6 +134,17 @@ impl crate::gen::base::structure::Utilities for RustUtils {
             Endianness::Big => "bp3d_proto::codec::ByteCodecBE"
         }
     }
+
+    fn get_bit_codec(endianness: Endianness) -> &'static str {
+        match endianness {
+            Endianness::Little => "bp3d_proto::codec::BitCodecLE",
+            Endianness::Big => "bp3d_proto::codec::BitCodecBE"
+        }
+    }
+
+    fn get_generic_type(field_type: FixedFieldType) -> &'static str {
+        Self::get_field_type(field_type)
+    }
 }
 
 impl crate::gen::base::message::Utilities for RustUtils {
@@ -192,4 +204,20 @@ impl crate::gen::base::message::Utilities for RustUtils {
     fn gen_union_ref_type(type_name: &str) -> String {
         format!("{}<'a>", type_name)
     }
+
+    fn gen_ref_type_inline(type_name: &str) -> String {
+        type_name.into()
+    }
+
+    fn gen_enum_codec(raw_codec: &str, type_name: &str) -> String {
+        format!("bp3d_proto::message::util::EnumValue<{}, {}>", raw_codec, type_name)
+    }
+
+    fn gen_enum_codec_inline(raw_codec: &str, type_name: &str) -> String {
+        format!("bp3d_proto::message::util::EnumValue::<{}, {}>", raw_codec, type_name)
+    }
+
+    fn gen_list_item_type(ty: &str) -> String {
+        ty.into()
+    }
 }
@@ -92,4 +92,12 @@ impl crate::gen::base::structure::Utilities for SwiftUtils {
             Endianness::Big => "BP3DProto.ByteCodecBE"
         }
     }
+
+    fn get_bit_codec(endianness: Endianness) -> &'static str {
+        Self::get_bit_codec_inline(endianness)
+    }
+
+    fn get_generic_type(field_type: FixedFieldType) -> &'static str {
+        Self::get_field_type(field_type)
+    }
 }
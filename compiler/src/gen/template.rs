@@ -142,6 +142,10 @@ fn capitalize(value: &str) -> Cow<str> {
     }
 }
 
+fn pascal(value: &str) -> Cow<'_, str> {
+    value.split('_').map(capitalize).join("").into()
+}
+
 impl<'fragment> Default for FunctionMap<'fragment> {
     fn default() -> Self {
         let mut map = Self::new();
@@ -160,7 +164,8 @@ impl<'fragment> FunctionMap<'fragment> {
     pub fn add_defaults(&mut self) {
         self.add("upper", |v| v.to_uppercase().into())
             .add("lower", |v| v.to_lowercase().into())
-            .add("capitalize", capitalize);
+            .add("capitalize", capitalize)
+            .add("pascal", pascal);
     }
 
     pub fn add(&mut self, name: &'fragment str, f: fn(&str) -> Cow<str>) -> &mut Self {
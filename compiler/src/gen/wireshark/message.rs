@@ -0,0 +1,161 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::message::{Field, FieldType, ItemListField, ItemType, Message, ValueType};
+use crate::compiler::structure::FixedFieldType;
+use crate::compiler::util::TypePathMap;
+use crate::gen::template::{Scope, Template};
+use crate::gen::wireshark::util::{gen_abbr, gen_mask, get_add_function, get_field_kind, get_le, get_mask, indent};
+
+const TEMPLATE: &[u8] = include_bytes!("./message.template");
+
+fn get_value_kind(ty: &ValueType) -> (String, String) {
+    match ty {
+        ValueType::Scalar(v) => {
+            let (kind, args) = get_field_kind(*v);
+            (kind.into(), args.into())
+        },
+        ValueType::Enum(e) => {
+            let values = e.variants.iter().map(|(k, v)| format!("[{}] = \"{}\"", v, k)).join(", ");
+            (format!("uint{}", e.ty.get_byte_size() * 8), format!(", base.DEC, {{ {} }}", values))
+        }
+    }
+}
+
+/// Returns the kind of the protocol field holding the leaf items of a list, if any.
+fn get_item_kind(ty: &ItemType) -> Option<(String, String)> {
+    match ty {
+        ItemType::Struct(_) | ItemType::Message(_) => None,
+        ItemType::Value(v) => Some(get_value_kind(v)),
+        ItemType::NullTerminatedString => Some(("stringz".into(), String::new())),
+        ItemType::VarcharString(_) => Some(("string".into(), String::new())),
+        ItemType::List(v) => get_item_kind(&v.item_type)
+    }
+}
+
+fn gen_field_decl(proto: &str, msg: &Message, field: &Field, template: &Template) -> String {
+    let (kind, args) = match &field.ty {
+        FieldType::Fixed(v) => get_value_kind(&ValueType::Scalar(v.ty)),
+        FieldType::Enum(v) => get_value_kind(&ValueType::Enum(v.clone())),
+        FieldType::ItemList(v) => match get_item_kind(&v.item_type) {
+            Some(kind) => kind,
+            None => return String::new()
+        },
+        FieldType::NullTerminatedString => ("stringz".into(), String::new()),
+        FieldType::VarcharString(_) => ("string".into(), String::new()),
+        FieldType::Payload => ("bytes".into(), String::new()),
+        _ => return String::new()
+    };
+    template.scope().var("abbr", gen_abbr(proto, &msg.name, &field.name))
+        .var("field_name", &field.name).var("kind", kind).var("args", args)
+        .render("decl", &["field"]).unwrap()
+}
+
+/// Sets the item count expression of a list and the size of its length prefix, which fixed lists
+/// do not have.
+fn set_count(scope: &mut Scope, ty: FixedFieldType, max_len: usize, fixed: bool) {
+    match fixed {
+        true => scope.var_d("count", max_len).var_d("len_size", 0),
+        false => {
+            scope.var_d("len_size", ty.get_byte_size())
+                .var("len_mask", gen_mask(get_mask(ty.get_byte_size() * 8)));
+            let count = scope.render("", &["count"]).unwrap();
+            scope.var("count", count.trim_end().to_string())
+        }
+    };
+}
+
+/// Generates the dissection of a list whose tree is added to `parent` under the given label.
+fn gen_item_list(v: &ItemListField, parent: &str, label: &str, scope: &Scope, type_path_by_name: &TypePathMap, prefix: &str) -> String {
+    let mut item_scope = scope.clone();
+    let item = match &v.item_type {
+        ItemType::Struct(r) => item_scope.var("type_name", type_path_by_name.get_with_default_prefix(&r.name, prefix))
+            .render("", &["item_ref"]).unwrap(),
+        ItemType::Message(r) => item_scope.var("type_name", type_path_by_name.get_with_default_prefix(&r.name, prefix))
+            .render("", &["item_ref"]).unwrap(),
+        ItemType::Value(item) => item_scope.var_d("len", item.raw_type().get_byte_size())
+            .render("", &["item_value"]).unwrap(),
+        ItemType::NullTerminatedString => item_scope.render("", &["item_string"]).unwrap(),
+        ItemType::VarcharString(item) => item_scope.var_d("len_size", item.ty.get_byte_size())
+            .var("len_mask", gen_mask(get_mask(item.ty.get_byte_size() * 8)))
+            .render("", &["item_varchar"]).unwrap(),
+        ItemType::List(item) => gen_item_list(item, "list", "\"[\" .. (i - 1) .. \"]: \"", scope, type_path_by_name, prefix)
+    };
+    let mut scope = scope.clone();
+    set_count(&mut scope, v.ty, v.max_len, v.fixed);
+    scope.var("parent", parent).var("label", label)
+        .var("item", indent(&item, 2).trim_end().to_string())
+        .render("", &["items"]).unwrap()
+}
+
+fn gen_field_item(proto: &str, msg: &Message, field: &Field, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let prefix = format!("{}.", proto);
+    let mut scope = template.scope();
+    scope.var("field_name", &field.name).var("abbr", gen_abbr(proto, &msg.name, &field.name))
+        .var("le", get_le(field.endianness)).var("add", get_add_function(field.endianness));
+    let code = match &field.ty {
+        FieldType::Fixed(v) => scope.var_d("len", v.ty.get_byte_size()).render("", &["fixed"]),
+        FieldType::Enum(v) => scope.var_d("len", v.ty.get_byte_size()).render("", &["fixed"]),
+        FieldType::NullTerminatedString => scope.render("", &["string"]),
+        FieldType::VarcharString(v) => scope.var_d("len_size", v.ty.get_byte_size())
+            .var("len_mask", gen_mask(get_mask(v.ty.get_byte_size() * 8)))
+            .render("", &["varchar"]),
+        FieldType::Ref(v) => scope.var("type_name", type_path_by_name.get_with_default_prefix(v.name(), &prefix))
+            .render("", &["ref"]),
+        FieldType::Array(v) => {
+            set_count(&mut scope, v.ty, v.max_len, v.fixed);
+            scope.var("type_name", type_path_by_name.get_with_default_prefix(&v.item_type.name, &prefix))
+                .render("", &["list"])
+        },
+        FieldType::List(v) => {
+            set_count(&mut scope, v.ty, v.max_len, v.fixed);
+            scope.var("type_name", type_path_by_name.get_with_default_prefix(&v.item_type.name, &prefix))
+                .render("", &["list"])
+        },
+        FieldType::ItemList(v) => Ok(gen_item_list(v, "subtree", &format!("\"{}: \"", field.name), &scope, type_path_by_name, &prefix)),
+        FieldType::Union(v) => scope.var("on_name", &v.on_name)
+            .var("type_name", type_path_by_name.get_with_default_prefix(&v.r.name, &prefix))
+            .render("", &["union"]),
+        FieldType::Payload => scope.render("", &["payload"])
+    }.unwrap();
+    match field.optional {
+        true => template.scope().var("fragment", indent(&code, 2)).render("", &["optional"]).unwrap(),
+        false => code
+    }
+}
+
+pub fn gen_message_decl(proto: &str, msg: &Message, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("proto", proto).var("name", &msg.name);
+    let fields = msg.fields.iter().map(|field| gen_field_decl(proto, msg, field, &template)).join("");
+    let items = msg.fields.iter()
+        .map(|field| gen_field_item(proto, msg, field, &template, type_path_by_name))
+        .join("");
+    template.var("fields", fields).var("items", items).render("", &["decl"]).unwrap()
+}
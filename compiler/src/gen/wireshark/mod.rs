@@ -0,0 +1,89 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod util;
+mod structure;
+mod r#enum;
+mod union;
+mod message;
+
+use bp3d_util::simple_error;
+use itertools::Itertools;
+use crate::compiler::Protocol;
+use crate::gen::{File, FileType, Generator};
+use crate::gen::template::Template;
+use crate::gen::wireshark::message::gen_message_decl;
+use crate::gen::wireshark::r#enum::gen_enum_decl;
+use crate::gen::wireshark::structure::gen_structure_decl;
+use crate::gen::wireshark::union::gen_union_decl;
+
+const TEMPLATE: &[u8] = include_bytes!("./protocol.template");
+
+simple_error! {
+    pub Error {
+        Unknown => "unknown"
+    }
+}
+
+fn gen_dissector(proto: &Protocol, template: &Template) -> String {
+    if proto.messages.is_empty() {
+        return String::new();
+    }
+    let messages = proto.messages.iter().map(|v| template.scope().var("msg_name", &v.name)
+        .render("dissector", &["message"]).unwrap()).join("");
+    let choices = proto.messages.iter().enumerate().map(|(i, v)| template.scope()
+        .var("msg_name", &v.name).var_d("index", i + 1)
+        .render("dissector", &["choice"]).unwrap()).join("");
+    template.scope().var("messages", messages).var("choices", choices)
+        .render("", &["dissector"]).unwrap()
+}
+
+/// A generator producing a Wireshark Lua dissector for each protocol.
+///
+/// Each protocol is emitted as a single `<name>.lua` plugin which stores its types in a global
+/// table named after the protocol; imported protocols must therefore use their protocol name as
+/// import path. The top-level message can be chosen per port through the protocol preferences or
+/// by calling `<name>.register_port(table, port, message)`.
+pub struct GeneratorWireshark;
+
+impl Generator for GeneratorWireshark {
+    type Error = Error;
+
+    fn generate(proto: Protocol) -> Result<Vec<File>, Self::Error> {
+        let mut template = Template::compile(TEMPLATE).unwrap();
+        template.var("name", &proto.name);
+        let mut code = template.render("", &["header"]).unwrap();
+        code += &proto.enums.iter().map(|v| gen_enum_decl(&proto.name, v)).join("");
+        code += &proto.structs.iter().map(|v| gen_structure_decl(&proto.name, v, &proto.type_path_by_name)).join("");
+        code += &proto.unions.iter().map(|v| gen_union_decl(&proto.name, v, &proto.type_path_by_name)).join("");
+        code += &proto.messages.iter().map(|v| gen_message_decl(&proto.name, v, &proto.type_path_by_name)).join("");
+        code += &template.render("", &["fields"]).unwrap();
+        code += &gen_dissector(&proto, &template);
+        Ok(vec![File::new(FileType::Message, format!("{}.lua", proto.name), code)])
+    }
+}
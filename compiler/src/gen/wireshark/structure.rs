@@ -0,0 +1,138 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::structure::{Field, FieldView, FixedField, FixedFieldType, Structure};
+use crate::compiler::util::TypePathMap;
+use crate::gen::template::Template;
+use crate::gen::wireshark::util::{gen_abbr, gen_mask, get_add_function, get_le, get_mask, get_range_bit_size};
+use crate::model::protocol::Endianness;
+
+const TEMPLATE: &[u8] = include_bytes!("./structure.template");
+
+/// Computes the shift and mask to apply to the integer read from the field byte range, mirroring
+/// the runtime bit codecs.
+fn get_shift_and_mask(field: &FixedField) -> (isize, u64) {
+    let mask = get_mask(field.loc.bit_size);
+    if !field.loc.bit_size.is_multiple_of(8) {
+        match field.endianness {
+            Endianness::Little => (field.loc.bit_offset as isize, mask),
+            Endianness::Big => {
+                let raw_size = field.loc.get_unsigned_integer_type().get_byte_size();
+                let shift = 8 - (field.loc.bit_size % 8) - field.loc.bit_offset;
+                (shift as isize - 8 * (raw_size - field.loc.byte_size) as isize, mask)
+            }
+        }
+    } else {
+        (0, mask)
+    }
+}
+
+/// Generates the trailing ProtoField arguments, appending the field mask for non byte-aligned fields.
+fn gen_field_args(field: &FixedField, value_string: &str) -> String {
+    let (shift, mask) = get_shift_and_mask(field);
+    if !field.loc.bit_size.is_multiple_of(8) {
+        let mask = match shift >= 0 {
+            true => mask << shift,
+            false => mask >> -shift
+        };
+        format!(", {}, {}", value_string, gen_mask(mask))
+    } else {
+        format!(", {}", value_string)
+    }
+}
+
+fn gen_fixed_field_decl(proto: &str, s: &Structure, field: &FixedField, template: &Template) -> String {
+    let bits = get_range_bit_size(field.loc.byte_size);
+    let abbr = gen_abbr(proto, &s.name, &field.name);
+    let (kind, args) = match &field.view {
+        FieldView::Enum(e) => {
+            let values = e.variants.iter().map(|(k, v)| format!("[{}] = \"{}\"", v, k)).join(", ");
+            (format!("uint{}", bits), gen_field_args(field, &format!("base.DEC, {{ {} }}", values)))
+        },
+        FieldView::Flags(_) => (format!("uint{}", bits), gen_field_args(field, "base.HEX, nil")),
+        FieldView::SignedCast(_) => (format!("int{}", bits), gen_field_args(field, "base.DEC, nil")),
+        FieldView::Transmute => match field.ty {
+            FixedFieldType::Float32 => ("float".into(), String::new()),
+            FixedFieldType::Float64 => ("double".into(), String::new()),
+            FixedFieldType::Bool if !field.loc.bit_size.is_multiple_of(8) => ("bool".into(), gen_field_args(field, &format!("{}, nil", bits))),
+            FixedFieldType::Bool => ("bool".into(), String::new()),
+            _ => (format!("int{}", bits), gen_field_args(field, "base.DEC"))
+        },
+        FieldView::Float { .. } | FieldView::None => (format!("uint{}", bits), gen_field_args(field, "base.DEC, nil"))
+    };
+    let mut code = template.scope().var("abbr", &abbr).var("field_name", &field.name)
+        .var("kind", kind).var("args", args).render("decl", &["field"]).unwrap();
+    if let FieldView::Float { .. } = &field.view {
+        code += &template.scope().var("abbr", format!("{}.value", abbr))
+            .var("field_name", format!("{} (value)", field.name))
+            .var("kind", "double").var("args", "").render("decl", &["field"]).unwrap();
+    }
+    code
+}
+
+fn gen_field_item(proto: &str, s: &Structure, field: &Field, template: &Template, type_path_by_name: &TypePathMap) -> String {
+    let mut scope = template.scope();
+    scope.var_d("start", field.loc().byte_offset).var_d("len", field.loc().byte_size)
+        .var("field_name", field.name()).var("abbr", gen_abbr(proto, &s.name, field.name()));
+    match field {
+        Field::Fixed(v) => {
+            let (shift, mask) = get_shift_and_mask(v);
+            scope.var("le", get_le(v.endianness)).var("add", get_add_function(v.endianness))
+                .var_d("shift", shift).var("mask", gen_mask(mask));
+            let mut code = scope.render("decl", &["fixed"]).unwrap();
+            if let FieldView::Float { a, b, .. } = &v.view {
+                code += &scope.var("a", format!("{:?}", a)).var("b", format!("{:?}", b))
+                    .render("decl", &["float"]).unwrap();
+            }
+            code
+        },
+        Field::Array(v) => scope.var_d("len", v.byte_span()).render("decl", &["array"]).unwrap(),
+        Field::Struct(v) => scope.var("type_name", type_path_by_name.get_with_default_prefix(&v.r.name, &format!("{}.", proto)))
+            .render("decl", &["struct"]).unwrap(),
+        Field::StructArray(v) => scope.var("type_name", type_path_by_name.get_with_default_prefix(&v.r.name, &format!("{}.", proto)))
+            .var_d("array_len", v.array_len).var_d("item_size", v.r.byte_size)
+            .render("decl", &["struct_array"]).unwrap()
+    }
+}
+
+pub fn gen_structure_decl(proto: &str, s: &Structure, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("proto", proto).var("name", &s.name).var_d("byte_size", s.byte_size);
+    let fields = s.fields.iter().map(|field| match field {
+        Field::Fixed(v) => gen_fixed_field_decl(proto, s, v, &template),
+        Field::Array(v) => template.scope().var("abbr", gen_abbr(proto, &s.name, &v.name))
+            .var("field_name", &v.name).var("kind", "bytes").var("args", "")
+            .render("decl", &["field"]).unwrap(),
+        Field::Struct(_) | Field::StructArray(_) => String::new()
+    }).join("");
+    let items = s.fields.iter()
+        .map(|field| gen_field_item(proto, s, field, &template, type_path_by_name))
+        .join("");
+    template.var("fields", fields).var("items", items).render("", &["decl"]).unwrap()
+}
@@ -0,0 +1,55 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use itertools::Itertools;
+use crate::compiler::union::Union;
+use crate::compiler::util::TypePathMap;
+use crate::gen::template::Template;
+
+const TEMPLATE: &[u8] = include_bytes!("./union.template");
+
+fn get_discriminant_path(u: &Union) -> String {
+    u.discriminant.iter().map(|(f, _)| format!("[\"{}\"]", f.name())).join("")
+}
+
+pub fn gen_union_decl(proto: &str, u: &Union, type_path_by_name: &TypePathMap) -> String {
+    let mut template = Template::compile(TEMPLATE).unwrap();
+    template.var("proto", proto).var("name", &u.name)
+        .var("discriminant_path", get_discriminant_path(u));
+    let prefix = format!("{}.", proto);
+    let cases = u.cases.iter().map(|case| {
+        let mut scope = template.scope();
+        scope.var("case_name", &case.name).var_d("case", case.case);
+        match &case.item_type {
+            None => scope.render("decl", &["none"]).unwrap(),
+            Some(item_type) => scope.var("type_name", type_path_by_name.get_with_default_prefix(item_type.name(), &prefix))
+                .render("decl", &["ref"]).unwrap()
+        }
+    }).join("");
+    template.var("cases", cases).render("", &["decl"]).unwrap()
+}
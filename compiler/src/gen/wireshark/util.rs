@@ -0,0 +1,97 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::compiler::structure::FixedFieldType;
+use crate::model::protocol::Endianness;
+
+pub fn get_add_function(endianness: Endianness) -> &'static str {
+    match endianness {
+        Endianness::Little => "add_le",
+        Endianness::Big => "add"
+    }
+}
+
+pub fn get_le(endianness: Endianness) -> &'static str {
+    match endianness {
+        Endianness::Little => "true",
+        Endianness::Big => "false"
+    }
+}
+
+/// Returns the bit size of the Wireshark integer type able to hold a range of the given byte size.
+pub fn get_range_bit_size(byte_size: usize) -> usize {
+    match byte_size {
+        1 => 8,
+        2 => 16,
+        3 => 24,
+        4 => 32,
+        _ => 64
+    }
+}
+
+pub fn get_mask(bit_size: usize) -> u64 {
+    if bit_size >= 64 {
+        u64::MAX
+    } else {
+        (1 << bit_size) - 1
+    }
+}
+
+/// Formats a mask as a Lua expression; masks which do not fit in a double are emitted as UInt64.
+pub fn gen_mask(mask: u64) -> String {
+    if mask < (1 << 53) {
+        format!("0x{:x}", mask)
+    } else {
+        format!("UInt64.fromhex(\"{:x}\")", mask)
+    }
+}
+
+pub fn get_field_kind(ty: FixedFieldType) -> (&'static str, &'static str) {
+    match ty {
+        FixedFieldType::Int8 => ("int8", ", base.DEC"),
+        FixedFieldType::Int16 => ("int16", ", base.DEC"),
+        FixedFieldType::Int32 => ("int32", ", base.DEC"),
+        FixedFieldType::Int64 => ("int64", ", base.DEC"),
+        FixedFieldType::UInt8 => ("uint8", ", base.DEC"),
+        FixedFieldType::UInt16 => ("uint16", ", base.DEC"),
+        FixedFieldType::UInt32 => ("uint32", ", base.DEC"),
+        FixedFieldType::UInt64 => ("uint64", ", base.DEC"),
+        FixedFieldType::Float32 => ("float", ""),
+        FixedFieldType::Float64 => ("double", ""),
+        FixedFieldType::Bool => ("bool", "")
+    }
+}
+
+pub fn gen_abbr(proto: &str, type_name: &str, field_name: &str) -> String {
+    format!("{}.{}.{}", proto, type_name.to_ascii_lowercase(), field_name)
+}
+
+pub fn indent(code: &str, level: usize) -> String {
+    let prefix = " ".repeat(level * 4);
+    code.lines().map(|v| format!("{}{}\n", prefix, v)).collect()
+}
@@ -83,6 +83,8 @@ pub struct Protoc {
     protocols: Vec<compiler::Protocol>,
     write_messages: bool,
     read_messages: bool,
+    owned_messages: bool,
+    serde: bool,
     use_enums: bool,
     use_structs: bool,
     use_messages: bool,
@@ -95,6 +97,8 @@ impl Protoc {
             protocols,
             write_messages: false,
             read_messages: false,
+            owned_messages: false,
+            serde: false,
             use_enums: true,
             use_structs: true,
             use_messages: true,
@@ -112,6 +116,26 @@ impl Protoc {
         self
     }
 
+    /// Enables generation of owned counterparts of messages and unions for generators which
+    /// support it.
+    ///
+    /// Owned messages containing lists rely on both reading and writing of messages to convert
+    /// from and to their borrowed form.
+    pub fn set_owned_messages(mut self, flag: bool) -> Self {
+        self.owned_messages = flag;
+        self
+    }
+
+    /// Enables generation of serde implementations for generators which support it.
+    ///
+    /// The generated code requires the `serde` feature of `bp3d-proto`. Owned messages and
+    /// unions are only made deserializable when owned messages are also enabled.
+    pub fn set_serde(mut self, flag: bool) -> Self {
+        self.serde = flag;
+        self
+    }
+
+    /// Enables generation of enums and flags.
     pub fn set_use_enums(mut self, flag: bool) -> Self {
         self.use_enums = flag;
         self
@@ -145,6 +169,9 @@ impl Protoc {
                 match v.ty() {
                     FileType::MessageWriting => self.write_messages,
                     FileType::MessageReading => self.read_messages,
+                    FileType::MessageOwned => self.owned_messages,
+                    FileType::Serde => self.serde,
+                    FileType::SerdeOwned => self.serde && self.owned_messages,
                     FileType::Message => self.use_messages,
                     FileType::Structure => self.use_structs,
                     FileType::Enum => self.use_enums,
@@ -36,8 +36,9 @@ pub enum MessageFieldType {
         item_type: String
     },
     List {
-        max_len: usize,
-        item_type: String
+        max_len: Option<usize>,
+        fixed_len: Option<usize>,
+        item_type: ItemType
     },
     String {
         max_len: Option<usize>
@@ -49,15 +50,25 @@ pub enum MessageFieldType {
     }
 }
 
+/// The type of the items of a list, either the name of a type or an inline type descriptor.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ItemType {
+    Name(String),
+    Inline(Box<MessageFieldType>)
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageField {
     pub name: String,
     pub info: MessageFieldType,
-    pub optional: Option<bool>
+    pub optional: Option<bool>,
+    pub doc: Option<String>
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct Message {
     pub name: String,
-    pub fields: Vec<MessageField>
+    pub fields: Vec<MessageField>,
+    pub doc: Option<String>
 }
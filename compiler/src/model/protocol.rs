@@ -49,7 +49,15 @@ pub struct Import {
 #[derive(Clone, Debug, Deserialize)]
 pub struct Enum {
     pub name: String,
-    pub variants: HashMap<String, usize>
+    pub variants: HashMap<String, usize>,
+    pub doc: Option<String>
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Flags {
+    pub name: String,
+    pub flags: HashMap<String, usize>,
+    pub doc: Option<String>
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -60,5 +68,7 @@ pub struct Protocol {
     pub structs: Option<Vec<Structure>>,
     pub messages: Option<Vec<Message>>,
     pub enums: Option<Vec<Enum>>,
-    pub unions: Option<Vec<Union>>
+    pub flags: Option<Vec<Flags>>,
+    pub unions: Option<Vec<Union>>,
+    pub doc: Option<String>
 }
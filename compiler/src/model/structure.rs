@@ -35,6 +35,9 @@ pub enum StructFieldView {
     Enum {
         name: String
     },
+    Flags {
+        name: String
+    },
     FloatRange {
         min: f64,
         max: f64
@@ -101,11 +104,13 @@ pub struct StructField {
     pub name: String,
     pub info: StructFieldType,
     pub view: Option<StructFieldView>,
-    pub array_len: Option<usize>
+    pub array_len: Option<usize>,
+    pub doc: Option<String>
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct Structure {
     pub name: String,
-    pub fields: Vec<StructField>
+    pub fields: Vec<StructField>,
+    pub doc: Option<String>
 }
@@ -32,12 +32,15 @@ use serde::Deserialize;
 pub struct UnionField {
     pub name: String,
     pub case: String,
-    pub item_type: Option<String>
+    pub item_type: Option<String>,
+    pub doc: Option<String>
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct Union  {
     pub name: String,
     pub discriminant: String,
-    pub cases: Vec<UnionField>
+    pub cases: Vec<UnionField>,
+    pub unknown: Option<bool>,
+    pub doc: Option<String>
 }
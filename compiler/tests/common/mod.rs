@@ -0,0 +1,92 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::{Path, PathBuf};
+use bp3d_protoc::gen::Generator;
+use bp3d_protoc::Loader;
+use bp3d_protoc::util::SimpleImportSolver;
+
+/// Protocols from testprog which do not import other protocols.
+pub const PROTOCOLS: &[&str] = &[
+    "test", "structs", "bits", "bits2", "views", "struct_arrays", "enums", "values", "unions2", "arrays", "nested"
+];
+
+fn testprog(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../testprog/src").join(format!("{}.json5", name))
+}
+
+fn check_snapshots(generated: &Path, snapshots: &Path) {
+    let update = std::env::var_os("BP3D_UPDATE_SNAPSHOTS").is_some();
+    let mut files: Vec<PathBuf> = std::fs::read_dir(generated).unwrap().map(|v| v.unwrap().path()).collect();
+    files.sort();
+    if update {
+        // Recreate the directory so that snapshots which are no longer generated get removed.
+        let _ = std::fs::remove_dir_all(snapshots);
+        std::fs::create_dir_all(snapshots).unwrap();
+    } else if snapshots.exists() {
+        let names: Vec<_> = files.iter().map(|v| v.file_name().unwrap()).collect();
+        for snapshot in std::fs::read_dir(snapshots).unwrap() {
+            let snapshot = snapshot.unwrap().path();
+            assert!(names.contains(&snapshot.file_name().unwrap()), "stale snapshot {:?}", snapshot);
+        }
+    }
+    for file in files {
+        let name = file.file_name().unwrap();
+        let actual = std::fs::read_to_string(&file).unwrap();
+        let snapshot = snapshots.join(name);
+        if update {
+            std::fs::write(&snapshot, actual).unwrap();
+        } else {
+            let expected = std::fs::read_to_string(&snapshot)
+                .unwrap_or_else(|_| panic!("missing snapshot {:?}", snapshot));
+            assert_eq!(actual, expected, "snapshot mismatch for {:?}", snapshot);
+        }
+    }
+}
+
+/// Generates the given testprog protocol and compares the output against the snapshots stored
+/// in tests/snapshots/<generator>/<name>; set BP3D_UPDATE_SNAPSHOTS to regenerate them.
+pub fn generate<T: Generator>(generator: &str, name: &str, imports: &[&str]) {
+    generate_with_separator::<T>(generator, name, imports, ".")
+}
+
+/// Same as [generate] but with a custom type path separator for imported types.
+pub fn generate_with_separator<T: Generator>(generator: &str, name: &str, imports: &[&str], separator: &str) {
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join(generator);
+    let _ = std::fs::remove_dir_all(out.join(name));
+    std::fs::create_dir_all(&out).unwrap();
+    let mut loader = Loader::new();
+    for import in imports {
+        loader.import(testprog(import), *import).unwrap();
+    }
+    loader.load(testprog(name)).unwrap();
+    loader.compile(SimpleImportSolver::new(separator)).unwrap()
+        .generate::<T>(&out).unwrap();
+    let snapshots = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots").join(generator).join(name);
+    check_snapshots(&out.join(name), &snapshots);
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod common;
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use bp3d_protoc::gen::GeneratorJava;
+
+fn java_sources(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            java_sources(&path, files);
+        } else if path.extension().is_some_and(|v| v == "java") {
+            files.push(path);
+        }
+    }
+}
+
+#[test]
+fn java_protocols() {
+    for name in common::PROTOCOLS {
+        common::generate::<GeneratorJava>("java", name, &[]);
+    }
+}
+
+#[test]
+fn java_imports() {
+    common::generate::<GeneratorJava>("java", "unions", &["enums", "values"]);
+    common::generate::<GeneratorJava>("java", "lists", &["enums", "values", "unions"]);
+    common::generate::<GeneratorJava>("java", "scalars", &["enums"]);
+}
+
+/// Compiles the Java runtime together with all Java snapshots, then runs the runtime round trip
+/// tests; skipped when no JDK is installed.
+#[test]
+fn java_runtime() {
+    if Command::new("javac").arg("-version").output().is_err() {
+        eprintln!("javac not found, skipping Java runtime tests");
+        return;
+    }
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let runtime = root.join("../Java/BP3DProto/src");
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("java_runtime");
+    let _ = std::fs::remove_dir_all(&out);
+    let mut files = Vec::new();
+    java_sources(&runtime, &mut files);
+    java_sources(&root.join("tests/snapshots/java"), &mut files);
+    let status = Command::new("javac").args(["-encoding", "UTF-8", "--release", "8", "-nowarn", "-d"])
+        .arg(&out).args(&files).status().unwrap();
+    assert!(status.success(), "failed to compile the Java runtime and snapshots");
+    let status = Command::new("java").arg("-cp").arg(&out).arg("bp3d.proto.RoundTripTest").status().unwrap();
+    assert!(status.success(), "Java runtime round trip tests failed");
+}
@@ -0,0 +1,24 @@
+package arrays;
+
+public final class Block {
+    public java.util.List<Item> items;
+    public java.util.List<Short> samples;
+
+    public static final bp3d.proto.Codec<Block> CODEC = new bp3d.proto.Codec<Block>() {
+        @Override
+        public bp3d.proto.Message<Block> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Block data = new Block();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.items = reader.read(bp3d.proto.ListCodec.of(bp3d.proto.FixedLen.of(4), Item.CODEC));
+            data.samples = reader.read(bp3d.proto.ListCodec.of(bp3d.proto.FixedLen.of(16), bp3d.proto.Scalar.I16_LE));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Block input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.ListCodec.of(bp3d.proto.FixedLen.of(4), Item.CODEC).writeTo(input.items, out);
+            bp3d.proto.ListCodec.of(bp3d.proto.FixedLen.of(16), bp3d.proto.Scalar.I16_LE).writeTo(input.samples, out);
+
+        }
+    };
+}
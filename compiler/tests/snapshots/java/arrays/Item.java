@@ -0,0 +1,63 @@
+package arrays;
+
+public final class Item {
+    public static final int SIZE = 3;
+    public static final bp3d.proto.StructCodec<Item> CODEC = new bp3d.proto.StructCodec<>(SIZE, Item::new, Item::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Item(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Item allocate() {
+        return new Item(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawId() {
+        return (short) bp3d.proto.ByteCodec.LE.read(data, 0, 1, 1);
+
+    }
+    public short getId() {
+        return getRawId();
+    }
+    public int getRawCount() {
+        return (int) bp3d.proto.BitCodec.LE.read(data, 1, 3, 2, 0, 11);
+
+    }
+    public int getCount() {
+        return getRawCount();
+    }
+    public short getRawSlot() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 2, 3, 1, 3, 5);
+
+    }
+    public short getSlot() {
+        return getRawSlot();
+    }
+
+    public void setRawId(short value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 1, 1, value);
+
+    }
+    public Item setId(short value) {
+        setRawId(value);
+        return this;
+    }
+    public void setRawCount(int value) {
+        bp3d.proto.BitCodec.LE.write(data, 1, 3, 2, 0, 11, value);
+
+    }
+    public Item setCount(int value) {
+        setRawCount(value);
+        return this;
+    }
+    public void setRawSlot(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 2, 3, 1, 3, 5, value);
+
+    }
+    public Item setSlot(short value) {
+        setRawSlot(value);
+        return this;
+    }
+
+}
@@ -0,0 +1,21 @@
+package arrays;
+
+public final class Msg1 {
+    public java.util.List<Item> items;
+
+    public static final bp3d.proto.Codec<Msg1> CODEC = new bp3d.proto.Codec<Msg1>() {
+        @Override
+        public bp3d.proto.Message<Msg1> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Msg1 data = new Msg1();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.items = reader.read(bp3d.proto.Optional.of(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, Item.CODEC)));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Msg1 input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.Optional.of(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, Item.CODEC)).writeTo(input.items, out);
+
+        }
+    };
+}
@@ -0,0 +1,24 @@
+package arrays;
+
+public final class Pair {
+    public short id;
+    public Item item;
+
+    public static final bp3d.proto.Codec<Pair> CODEC = new bp3d.proto.Codec<Pair>() {
+        @Override
+        public bp3d.proto.Message<Pair> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Pair data = new Pair();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.id = reader.read(bp3d.proto.Scalar.U8_LE);
+            data.item = reader.read(Item.CODEC);
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Pair input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.Scalar.U8_LE.writeTo(input.id, out);
+            Item.CODEC.writeTo(input.item, out);
+
+        }
+    };
+}
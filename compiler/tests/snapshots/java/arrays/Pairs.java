@@ -0,0 +1,21 @@
+package arrays;
+
+public final class Pairs {
+    public java.util.List<Pair> pairs;
+
+    public static final bp3d.proto.Codec<Pairs> CODEC = new bp3d.proto.Codec<Pairs>() {
+        @Override
+        public bp3d.proto.Message<Pairs> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Pairs data = new Pairs();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.pairs = reader.read(bp3d.proto.ListCodec.of(bp3d.proto.FixedLen.of(2), Pair.CODEC));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Pairs input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.ListCodec.of(bp3d.proto.FixedLen.of(2), Pair.CODEC).writeTo(input.pairs, out);
+
+        }
+    };
+}
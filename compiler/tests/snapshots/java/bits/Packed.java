@@ -0,0 +1,54 @@
+package bits;
+
+public final class Packed {
+    public static final int SIZE = 13;
+    public static final bp3d.proto.StructCodec<Packed> CODEC = new bp3d.proto.StructCodec<>(SIZE, Packed::new, Packed::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Packed(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Packed allocate() {
+        return new Packed(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawTag() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 0, 1, 1, 0, 4);
+
+    }
+    public short getTag() {
+        return getRawTag();
+    }
+    public bp3d.proto.BitArrayCodec getNibbles() {
+        return new bp3d.proto.BitArrayCodec(bp3d.proto.Buffers.range(data, 0, 7), bp3d.proto.BitCodec.LE, 4, 4, 12);
+    }
+    public bp3d.proto.BitArrayCodec getFives() {
+        return new bp3d.proto.BitArrayCodec(bp3d.proto.Buffers.range(data, 6, 13), bp3d.proto.BitCodec.LE, 4, 5, 10);
+    }
+    public short getRawTail() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 12, 13, 1, 6, 2);
+
+    }
+    public short getTail() {
+        return getRawTail();
+    }
+
+    public void setRawTag(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 0, 1, 1, 0, 4, value);
+
+    }
+    public Packed setTag(short value) {
+        setRawTag(value);
+        return this;
+    }
+    public void setRawTail(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 12, 13, 1, 6, 2, value);
+
+    }
+    public Packed setTail(short value) {
+        setRawTail(value);
+        return this;
+    }
+
+}
@@ -0,0 +1,90 @@
+package bits2;
+
+public final class Numbers {
+    public static final int SIZE = 4;
+    public static final bp3d.proto.StructCodec<Numbers> CODEC = new bp3d.proto.StructCodec<>(SIZE, Numbers::new, Numbers::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Numbers(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Numbers allocate() {
+        return new Numbers(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawA() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 0, 1, 1, 0, 4);
+
+    }
+    public byte getA() {
+        long rawValue = getRawA();
+        if (rawValue > 7L) {
+            return (byte) -(((~rawValue) & 7L) + 1);
+        } else {
+            return (byte) (rawValue & 7L);
+        }
+    }
+    public short getRawB() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 0, 1, 1, 4, 4);
+
+    }
+    public short getB() {
+        return getRawB();
+    }
+    public long getRawC() {
+        return (long) bp3d.proto.BitCodec.LE.read(data, 1, 4, 4, 0, 17);
+
+    }
+    public int getC() {
+        long rawValue = getRawC();
+        if (rawValue > 65535L) {
+            return (int) -(((~rawValue) & 65535L) + 1);
+        } else {
+            return (int) (rawValue & 65535L);
+        }
+    }
+    public short getRawD() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 3, 4, 1, 1, 7);
+
+    }
+    public short getD() {
+        return getRawD();
+    }
+
+    public void setRawA(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 0, 1, 1, 0, 4, value);
+
+    }
+    public Numbers setA(byte value) {
+        setRawA((short) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+    public void setRawB(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 0, 1, 1, 4, 4, value);
+
+    }
+    public Numbers setB(short value) {
+        setRawB(value);
+        return this;
+    }
+    public void setRawC(long value) {
+        bp3d.proto.BitCodec.LE.write(data, 1, 4, 4, 0, 17, value);
+
+    }
+    public Numbers setC(int value) {
+        setRawC((long) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+    public void setRawD(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 3, 4, 1, 1, 7, value);
+
+    }
+    public Numbers setD(short value) {
+        setRawD(value);
+        return this;
+    }
+
+}
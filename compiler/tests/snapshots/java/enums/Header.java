@@ -0,0 +1,33 @@
+package enums;
+
+public final class Header {
+    public static final int SIZE = 1;
+    public static final bp3d.proto.StructCodec<Header> CODEC = new bp3d.proto.StructCodec<>(SIZE, Header::new, Header::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Header(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Header allocate() {
+        return new Header(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawType() {
+        return (short) bp3d.proto.ByteCodec.LE.read(data, 0, 1, 1);
+
+    }
+    public Type getType() {
+        return Type.fromRaw(getRawType());
+    }
+
+    public void setRawType(short value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 1, 1, value);
+
+    }
+    public Header setType(Type value) {
+        setRawType((short) value.raw);
+        return this;
+    }
+
+}
@@ -0,0 +1,48 @@
+package enums;
+
+public final class Record {
+    public static final int SIZE = 1;
+    public static final bp3d.proto.StructCodec<Record> CODEC = new bp3d.proto.StructCodec<>(SIZE, Record::new, Record::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Record(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Record allocate() {
+        return new Record(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawSeverity() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 0, 1, 1, 0, 3);
+
+    }
+    public Severity getSeverity() {
+        return Severity.fromRaw(getRawSeverity());
+    }
+    public short getRawCount() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 0, 1, 1, 3, 5);
+
+    }
+    public short getCount() {
+        return getRawCount();
+    }
+
+    public void setRawSeverity(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 0, 1, 1, 0, 3, value);
+
+    }
+    public Record setSeverity(Severity value) {
+        setRawSeverity((short) value.raw);
+        return this;
+    }
+    public void setRawCount(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 0, 1, 1, 3, 5, value);
+
+    }
+    public Record setCount(short value) {
+        setRawCount(value);
+        return this;
+    }
+
+}
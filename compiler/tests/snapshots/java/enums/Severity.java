@@ -0,0 +1,21 @@
+package enums;
+
+public enum Severity {
+    Debug(0L),
+    Info(1L),
+    Error(5L),
+
+    ;
+    public final long raw;
+    Severity(long raw) {
+        this.raw = raw;
+    }
+    public static Severity fromRaw(long raw) {
+        for (Severity v : values()) {
+            if (v.raw == raw) {
+                return v;
+            }
+        }
+        return null;
+    }
+}
@@ -0,0 +1,30 @@
+package enums;
+
+public enum Type {
+    Null(0L),
+    String(1L),
+    Int8(2L),
+    Int16(3L),
+    Int32(4L),
+    Int64(5L),
+    UInt8(6L),
+    UInt16(7L),
+    UInt32(8L),
+    UInt64(9L),
+    Float(10L),
+    Double(11L),
+
+    ;
+    public final long raw;
+    Type(long raw) {
+        this.raw = raw;
+    }
+    public static Type fromRaw(long raw) {
+        for (Type v : values()) {
+            if (v.raw == raw) {
+                return v;
+            }
+        }
+        return null;
+    }
+}
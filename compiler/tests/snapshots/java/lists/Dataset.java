@@ -0,0 +1,21 @@
+package lists;
+
+public final class Dataset {
+    public java.util.List<SpanRun> runs;
+
+    public static final bp3d.proto.Codec<Dataset> CODEC = new bp3d.proto.Codec<Dataset>() {
+        @Override
+        public bp3d.proto.Message<Dataset> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Dataset data = new Dataset();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.runs = reader.read(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U32_LE, SpanRun.CODEC));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Dataset input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U32_LE, SpanRun.CODEC).writeTo(input.runs, out);
+
+        }
+    };
+}
@@ -0,0 +1,24 @@
+package lists;
+
+public final class SpanRun {
+    public Times times;
+    public java.util.List<unions.Item> vars;
+
+    public static final bp3d.proto.Codec<SpanRun> CODEC = new bp3d.proto.Codec<SpanRun>() {
+        @Override
+        public bp3d.proto.Message<SpanRun> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            SpanRun data = new SpanRun();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.times = reader.read(Times.CODEC);
+            data.vars = reader.read(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, unions.Item.CODEC));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(SpanRun input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            Times.CODEC.writeTo(input.times, out);
+            bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, unions.Item.CODEC).writeTo(input.vars, out);
+
+        }
+    };
+}
@@ -0,0 +1,48 @@
+package lists;
+
+public final class Times {
+    public static final int SIZE = 16;
+    public static final bp3d.proto.StructCodec<Times> CODEC = new bp3d.proto.StructCodec<>(SIZE, Times::new, Times::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Times(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Times allocate() {
+        return new Times(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawStart() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 8, 8);
+
+    }
+    public long getStart() {
+        return getRawStart();
+    }
+    public long getRawEnd() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 8, 16, 8);
+
+    }
+    public long getEnd() {
+        return getRawEnd();
+    }
+
+    public void setRawStart(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 8, 8, value);
+
+    }
+    public Times setStart(long value) {
+        setRawStart(value);
+        return this;
+    }
+    public void setRawEnd(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 8, 16, 8, value);
+
+    }
+    public Times setEnd(long value) {
+        setRawEnd(value);
+        return this;
+    }
+
+}
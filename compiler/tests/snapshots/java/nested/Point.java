@@ -0,0 +1,52 @@
+package nested;
+
+public final class Point {
+    public static final int SIZE = 4;
+    public static final bp3d.proto.StructCodec<Point> CODEC = new bp3d.proto.StructCodec<>(SIZE, Point::new, Point::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Point(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Point allocate() {
+        return new Point(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public int getRawX() {
+        return (int) bp3d.proto.ByteCodec.LE.read(data, 0, 2, 2);
+
+    }
+    public short getX() {
+        return bp3d.proto.Transmute.shortValue(getRawX());
+
+    }
+    public int getRawY() {
+        return (int) bp3d.proto.ByteCodec.LE.read(data, 2, 4, 2);
+
+    }
+    public short getY() {
+        return bp3d.proto.Transmute.shortValue(getRawY());
+
+    }
+
+    public void setRawX(int value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 2, 2, value);
+
+    }
+    public Point setX(short value) {
+        setRawX((int) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+    public void setRawY(int value) {
+        bp3d.proto.ByteCodec.LE.write(data, 2, 4, 2, value);
+
+    }
+    public Point setY(short value) {
+        setRawY((int) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+
+}
@@ -0,0 +1,27 @@
+package nested;
+
+public final class Samples {
+    public java.util.List<java.util.List<Integer>> channels;
+    public java.util.List<java.util.List<Point>> shapes;
+    public java.util.List<java.util.List<Tags>> groups;
+
+    public static final bp3d.proto.Codec<Samples> CODEC = new bp3d.proto.Codec<Samples>() {
+        @Override
+        public bp3d.proto.Message<Samples> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Samples data = new Samples();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.channels = reader.read(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U16_LE, bp3d.proto.Scalar.I32_LE)));
+            data.shapes = reader.read(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, Point.CODEC)));
+            data.groups = reader.read(bp3d.proto.Optional.of(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, Tags.CODEC))));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Samples input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U16_LE, bp3d.proto.Scalar.I32_LE)).writeTo(input.channels, out);
+            bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, Point.CODEC)).writeTo(input.shapes, out);
+            bp3d.proto.Optional.of(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, Tags.CODEC))).writeTo(input.groups, out);
+
+        }
+    };
+}
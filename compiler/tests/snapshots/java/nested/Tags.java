@@ -0,0 +1,24 @@
+package nested;
+
+public final class Tags {
+    public java.util.List<String> names;
+    public java.util.List<String> labels;
+
+    public static final bp3d.proto.Codec<Tags> CODEC = new bp3d.proto.Codec<Tags>() {
+        @Override
+        public bp3d.proto.Message<Tags> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Tags data = new Tags();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.names = reader.read(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.VarcharString.of(bp3d.proto.Scalar.U8_LE)));
+            data.labels = reader.read(bp3d.proto.Optional.of(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.NullTerminatedString.CODEC)));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Tags input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.VarcharString.of(bp3d.proto.Scalar.U8_LE)).writeTo(input.names, out);
+            bp3d.proto.Optional.of(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.NullTerminatedString.CODEC)).writeTo(input.labels, out);
+
+        }
+    };
+}
@@ -0,0 +1,24 @@
+package scalars;
+
+public final class Log {
+    public java.util.List<enums.Severity> levels;
+    public java.util.List<Boolean> flags;
+
+    public static final bp3d.proto.Codec<Log> CODEC = new bp3d.proto.Codec<Log>() {
+        @Override
+        public bp3d.proto.Message<Log> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Log data = new Log();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.levels = reader.read(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U16_LE, bp3d.proto.EnumCodec.of(bp3d.proto.Scalar.U8_LE, enums.Severity::fromRaw, v -> v.raw)));
+            data.flags = reader.read(bp3d.proto.Optional.of(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.Scalar.BOOL_LE)));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Log input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U16_LE, bp3d.proto.EnumCodec.of(bp3d.proto.Scalar.U8_LE, enums.Severity::fromRaw, v -> v.raw)).writeTo(input.levels, out);
+            bp3d.proto.Optional.of(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.Scalar.BOOL_LE)).writeTo(input.flags, out);
+
+        }
+    };
+}
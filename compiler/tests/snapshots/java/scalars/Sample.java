@@ -0,0 +1,30 @@
+package scalars;
+
+public final class Sample {
+    public long id;
+    public enums.Severity severity;
+    public Double scale;
+    public java.util.List<Short> values;
+
+    public static final bp3d.proto.Codec<Sample> CODEC = new bp3d.proto.Codec<Sample>() {
+        @Override
+        public bp3d.proto.Message<Sample> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Sample data = new Sample();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.id = reader.read(bp3d.proto.Scalar.U32_LE);
+            data.severity = reader.read(bp3d.proto.EnumCodec.of(bp3d.proto.Scalar.U8_LE, enums.Severity::fromRaw, v -> v.raw));
+            data.scale = reader.read(bp3d.proto.Optional.of(bp3d.proto.Scalar.F64_LE));
+            data.values = reader.read(bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.Scalar.I16_LE));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Sample input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.Scalar.U32_LE.writeTo(input.id, out);
+            bp3d.proto.EnumCodec.of(bp3d.proto.Scalar.U8_LE, enums.Severity::fromRaw, v -> v.raw).writeTo(input.severity, out);
+            bp3d.proto.Optional.of(bp3d.proto.Scalar.F64_LE).writeTo(input.scale, out);
+            bp3d.proto.ListCodec.of(bp3d.proto.Scalar.U8_LE, bp3d.proto.Scalar.I16_LE).writeTo(input.values, out);
+
+        }
+    };
+}
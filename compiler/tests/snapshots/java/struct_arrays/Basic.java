@@ -0,0 +1,42 @@
+package struct_arrays;
+
+public final class Basic {
+    public static final int SIZE = 58;
+    public static final bp3d.proto.StructCodec<Basic> CODEC = new bp3d.proto.StructCodec<>(SIZE, Basic::new, Basic::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Basic(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Basic allocate() {
+        return new Basic(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawP1() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 4, 4);
+
+    }
+    public long getP1() {
+        return getRawP1();
+    }
+    public bp3d.proto.ArrayCodec.Short getP2() {
+        return new bp3d.proto.ArrayCodec.Short(bp3d.proto.Buffers.range(data, 4, 36), bp3d.proto.ByteCodec.LE, 1, 8);
+    }
+    public bp3d.proto.ArrayCodec.Float getP3() {
+        return new bp3d.proto.ArrayCodec.Float(bp3d.proto.Buffers.range(data, 36, 52), bp3d.proto.ByteCodec.LE, 4, 32);
+    }
+    public bp3d.proto.ArrayCodec.Long getP4() {
+        return new bp3d.proto.ArrayCodec.Long(bp3d.proto.Buffers.range(data, 52, 58), bp3d.proto.ByteCodec.LE, 4, 24);
+    }
+
+    public void setRawP1(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 4, 4, value);
+
+    }
+    public Basic setP1(long value) {
+        setRawP1(value);
+        return this;
+    }
+
+}
@@ -0,0 +1,39 @@
+package struct_arrays;
+
+public final class Triangle {
+    public static final int SIZE = 16;
+    public static final bp3d.proto.StructCodec<Triangle> CODEC = new bp3d.proto.StructCodec<>(SIZE, Triangle::new, Triangle::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Triangle(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Triangle allocate() {
+        return new Triangle(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawId() {
+        return (short) bp3d.proto.ByteCodec.LE.read(data, 0, 1, 1);
+
+    }
+    public short getId() {
+        return getRawId();
+    }
+    public Vertex getVertices(int index) {
+        if (index < 0 || index >= 3) {
+            throw new IndexOutOfBoundsException("index " + index + " out of bounds for length 3");
+        }
+        return new Vertex(bp3d.proto.Buffers.range(data, 1 + index * 5, 1 + (index + 1) * 5));
+    }
+
+    public void setRawId(short value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 1, 1, value);
+
+    }
+    public Triangle setId(short value) {
+        setRawId(value);
+        return this;
+    }
+
+}
@@ -0,0 +1,84 @@
+package struct_arrays;
+
+public final class Vertex {
+    public static final int SIZE = 5;
+    public static final bp3d.proto.StructCodec<Vertex> CODEC = new bp3d.proto.StructCodec<>(SIZE, Vertex::new, Vertex::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Vertex(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Vertex allocate() {
+        return new Vertex(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public int getRawX() {
+        return (int) bp3d.proto.ByteCodec.LE.read(data, 0, 2, 2);
+
+    }
+    public short getX() {
+        return bp3d.proto.Transmute.shortValue(getRawX());
+
+    }
+    public int getRawY() {
+        return (int) bp3d.proto.ByteCodec.LE.read(data, 2, 4, 2);
+
+    }
+    public short getY() {
+        return bp3d.proto.Transmute.shortValue(getRawY());
+
+    }
+    public short getRawVisible() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 4, 5, 1, 0, 1);
+
+    }
+    public boolean getVisible() {
+        return getRawVisible() != 0;
+
+    }
+    public short getRawLayer() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 4, 5, 1, 1, 7);
+
+    }
+    public short getLayer() {
+        return getRawLayer();
+    }
+
+    public void setRawX(int value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 2, 2, value);
+
+    }
+    public Vertex setX(short value) {
+        setRawX((int) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+    public void setRawY(int value) {
+        bp3d.proto.ByteCodec.LE.write(data, 2, 4, 2, value);
+
+    }
+    public Vertex setY(short value) {
+        setRawY((int) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+    public void setRawVisible(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 4, 5, 1, 0, 1, value);
+
+    }
+    public Vertex setVisible(boolean value) {
+        setRawVisible((short) (value ? 1 : 0));
+
+        return this;
+    }
+    public void setRawLayer(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 4, 5, 1, 1, 7, value);
+
+    }
+    public Vertex setLayer(short value) {
+        setRawLayer(value);
+        return this;
+    }
+
+}
@@ -0,0 +1,86 @@
+package structs;
+
+public final class Flags {
+    public static final int SIZE = 15;
+    public static final bp3d.proto.StructCodec<Flags> CODEC = new bp3d.proto.StructCodec<>(SIZE, Flags::new, Flags::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Flags(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Flags allocate() {
+        return new Flags(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawA() {
+        return (short) bp3d.proto.ByteCodec.LE.read(data, 0, 1, 1);
+
+    }
+    public boolean getA() {
+        return getRawA() != 0;
+
+    }
+    public int getRawB() {
+        return (int) bp3d.proto.ByteCodec.LE.read(data, 1, 3, 2);
+
+    }
+    public boolean getB() {
+        return getRawB() != 0;
+
+    }
+    public long getRawC() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 3, 7, 4);
+
+    }
+    public boolean getC() {
+        return getRawC() != 0;
+
+    }
+    public long getRawD() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 7, 15, 8);
+
+    }
+    public boolean getD() {
+        return getRawD() != 0;
+
+    }
+
+    public void setRawA(short value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 1, 1, value);
+
+    }
+    public Flags setA(boolean value) {
+        setRawA((short) (value ? 1 : 0));
+
+        return this;
+    }
+    public void setRawB(int value) {
+        bp3d.proto.ByteCodec.LE.write(data, 1, 3, 2, value);
+
+    }
+    public Flags setB(boolean value) {
+        setRawB((int) (value ? 1 : 0));
+
+        return this;
+    }
+    public void setRawC(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 3, 7, 4, value);
+
+    }
+    public Flags setC(boolean value) {
+        setRawC((long) (value ? 1 : 0));
+
+        return this;
+    }
+    public void setRawD(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 7, 15, 8, value);
+
+    }
+    public Flags setD(boolean value) {
+        setRawD((long) (value ? 1 : 0));
+
+        return this;
+    }
+
+}
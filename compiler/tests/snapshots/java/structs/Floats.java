@@ -0,0 +1,52 @@
+package structs;
+
+public final class Floats {
+    public static final int SIZE = 12;
+    public static final bp3d.proto.StructCodec<Floats> CODEC = new bp3d.proto.StructCodec<>(SIZE, Floats::new, Floats::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Floats(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Floats allocate() {
+        return new Floats(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawA() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 4, 4);
+
+    }
+    public float getA() {
+        return bp3d.proto.Transmute.floatValue(getRawA());
+
+    }
+    public long getRawB() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 4, 12, 8);
+
+    }
+    public double getB() {
+        return bp3d.proto.Transmute.doubleValue(getRawB());
+
+    }
+
+    public void setRawA(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 4, 4, value);
+
+    }
+    public Floats setA(float value) {
+        setRawA((long) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+    public void setRawB(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 4, 12, 8, value);
+
+    }
+    public Floats setB(double value) {
+        setRawB((long) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+
+}
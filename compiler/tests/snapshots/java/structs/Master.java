@@ -0,0 +1,27 @@
+package structs;
+
+public final class Master {
+    public static final int SIZE = 41;
+    public static final bp3d.proto.StructCodec<Master> CODEC = new bp3d.proto.StructCodec<>(SIZE, Master::new, Master::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Master(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Master allocate() {
+        return new Master(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public Floats getFloats() {
+        return new Floats(bp3d.proto.Buffers.range(data, 0, 12));
+    }
+    public Numbers getNums() {
+        return new Numbers(bp3d.proto.Buffers.range(data, 12, 26));
+    }
+    public Flags getFlags() {
+        return new Flags(bp3d.proto.Buffers.range(data, 26, 41));
+    }
+
+
+}
@@ -0,0 +1,114 @@
+package structs;
+
+public final class Numbers {
+    public static final int SIZE = 14;
+    public static final bp3d.proto.StructCodec<Numbers> CODEC = new bp3d.proto.StructCodec<>(SIZE, Numbers::new, Numbers::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Numbers(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Numbers allocate() {
+        return new Numbers(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawUA() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 4, 4);
+
+    }
+    public long getUA() {
+        return getRawUA();
+    }
+    public long getRawA() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 4, 8, 4);
+
+    }
+    public int getA() {
+        return bp3d.proto.Transmute.intValue(getRawA());
+
+    }
+    public int getRawUB() {
+        return (int) bp3d.proto.ByteCodec.LE.read(data, 8, 10, 2);
+
+    }
+    public int getUB() {
+        return getRawUB();
+    }
+    public int getRawB() {
+        return (int) bp3d.proto.ByteCodec.LE.read(data, 10, 12, 2);
+
+    }
+    public short getB() {
+        return bp3d.proto.Transmute.shortValue(getRawB());
+
+    }
+    public short getRawUC() {
+        return (short) bp3d.proto.ByteCodec.LE.read(data, 12, 13, 1);
+
+    }
+    public short getUC() {
+        return getRawUC();
+    }
+    public short getRawC() {
+        return (short) bp3d.proto.ByteCodec.LE.read(data, 13, 14, 1);
+
+    }
+    public byte getC() {
+        return bp3d.proto.Transmute.byteValue(getRawC());
+
+    }
+
+    public void setRawUA(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 4, 4, value);
+
+    }
+    public Numbers setUA(long value) {
+        setRawUA(value);
+        return this;
+    }
+    public void setRawA(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 4, 8, 4, value);
+
+    }
+    public Numbers setA(int value) {
+        setRawA((long) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+    public void setRawUB(int value) {
+        bp3d.proto.ByteCodec.LE.write(data, 8, 10, 2, value);
+
+    }
+    public Numbers setUB(int value) {
+        setRawUB(value);
+        return this;
+    }
+    public void setRawB(int value) {
+        bp3d.proto.ByteCodec.LE.write(data, 10, 12, 2, value);
+
+    }
+    public Numbers setB(short value) {
+        setRawB((int) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+    public void setRawUC(short value) {
+        bp3d.proto.ByteCodec.LE.write(data, 12, 13, 1, value);
+
+    }
+    public Numbers setUC(short value) {
+        setRawUC(value);
+        return this;
+    }
+    public void setRawC(short value) {
+        bp3d.proto.ByteCodec.LE.write(data, 13, 14, 1, value);
+
+    }
+    public Numbers setC(byte value) {
+        setRawC((short) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+
+}
@@ -0,0 +1,33 @@
+package test;
+
+public final class IntContainer {
+    public static final int SIZE = 4;
+    public static final bp3d.proto.StructCodec<IntContainer> CODEC = new bp3d.proto.StructCodec<>(SIZE, IntContainer::new, IntContainer::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public IntContainer(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static IntContainer allocate() {
+        return new IntContainer(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawTestInt() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 4, 4);
+
+    }
+    public long getTestInt() {
+        return getRawTestInt();
+    }
+
+    public void setRawTestInt(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 4, 4, value);
+
+    }
+    public IntContainer setTestInt(long value) {
+        setRawTestInt(value);
+        return this;
+    }
+
+}
@@ -0,0 +1,27 @@
+package test;
+
+public final class Test {
+    public String s1;
+    public String s2;
+    public Test1 p1;
+
+    public static final bp3d.proto.Codec<Test> CODEC = new bp3d.proto.Codec<Test>() {
+        @Override
+        public bp3d.proto.Message<Test> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Test data = new Test();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.s1 = reader.read(bp3d.proto.NullTerminatedString.CODEC);
+            data.s2 = reader.read(bp3d.proto.VarcharString.of(bp3d.proto.Scalar.U8_LE));
+            data.p1 = reader.read(bp3d.proto.Optional.of(Test1.CODEC));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Test input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.NullTerminatedString.CODEC.writeTo(input.s1, out);
+            bp3d.proto.VarcharString.of(bp3d.proto.Scalar.U8_LE).writeTo(input.s2, out);
+            bp3d.proto.Optional.of(Test1.CODEC).writeTo(input.p1, out);
+
+        }
+    };
+}
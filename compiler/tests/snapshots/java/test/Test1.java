@@ -0,0 +1,24 @@
+package test;
+
+public final class Test1 {
+    public String s1;
+    public long p1;
+
+    public static final bp3d.proto.Codec<Test1> CODEC = new bp3d.proto.Codec<Test1>() {
+        @Override
+        public bp3d.proto.Message<Test1> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Test1 data = new Test1();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.s1 = reader.read(bp3d.proto.NullTerminatedString.CODEC);
+            data.p1 = reader.read(bp3d.proto.Scalar.U32_LE);
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Test1 input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.NullTerminatedString.CODEC.writeTo(input.s1, out);
+            bp3d.proto.Scalar.U32_LE.writeTo(input.p1, out);
+
+        }
+    };
+}
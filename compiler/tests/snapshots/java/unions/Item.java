@@ -0,0 +1,27 @@
+package unions;
+
+public final class Item {
+    public enums.Header header;
+    public String name;
+    public Value value;
+
+    public static final bp3d.proto.Codec<Item> CODEC = new bp3d.proto.Codec<Item>() {
+        @Override
+        public bp3d.proto.Message<Item> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            Item data = new Item();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.header = reader.read(enums.Header.CODEC);
+            data.name = reader.read(bp3d.proto.NullTerminatedString.CODEC);
+            data.value = reader.read(v -> Value.fromSlice(v, data.header));
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(Item input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            enums.Header.CODEC.writeTo(input.header, out);
+            bp3d.proto.NullTerminatedString.CODEC.writeTo(input.name, out);
+            Value.writeTo(input.value, input.header, out);
+
+        }
+    };
+}
@@ -0,0 +1,77 @@
+package unions;
+
+public abstract class PartialValue {
+    private PartialValue() {
+    }
+    public abstract long getDiscriminant();
+    public static final class Null extends PartialValue {
+        @Override
+        public long getDiscriminant() {
+            return 0L;
+        }
+    }
+    public static final class String extends PartialValue {
+        public final values.ValueString value;
+        public String(values.ValueString value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 1L;
+        }
+    }
+    public static final class Int8 extends PartialValue {
+        public final values.ValueInt8 value;
+        public Int8(values.ValueInt8 value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 2L;
+        }
+    }
+
+    public boolean isNull() {
+        return this instanceof Null;
+    }
+    public values.ValueString asString() {
+        return this instanceof String ? ((String) this).value : null;
+    }
+    public values.ValueInt8 asInt8() {
+        return this instanceof Int8 ? ((Int8) this).value : null;
+    }
+
+    public void setDiscriminant(enums.Header discriminant) {
+        discriminant.setRawType((short) getDiscriminant());
+    }
+    public static bp3d.proto.Message<PartialValue> fromSlice(java.nio.ByteBuffer slice, enums.Header discriminant) throws bp3d.proto.ProtoException {
+        long value = discriminant.getRawType();
+        if (value == 0L) {
+            return new bp3d.proto.Message<PartialValue>(0, new Null());
+        }
+        if (value == 1L) {
+            return values.ValueString.CODEC.fromSlice(slice).<PartialValue>map(String::new);
+        }
+        if (value == 2L) {
+            return values.ValueInt8.CODEC.fromSlice(slice).<PartialValue>map(Int8::new);
+        }
+
+        throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+    }
+    public static void writeTo(PartialValue input, enums.Header discriminant, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+        long value = discriminant.getRawType();
+        if (input instanceof String) {
+            if (value != 1L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueString.CODEC.writeTo(((String) input).value, out);
+        }
+        if (input instanceof Int8) {
+            if (value != 2L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueInt8.CODEC.writeTo(((Int8) input).value, out);
+        }
+
+    }
+}
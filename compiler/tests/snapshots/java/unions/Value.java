@@ -0,0 +1,275 @@
+package unions;
+
+public abstract class Value {
+    private Value() {
+    }
+    public abstract long getDiscriminant();
+    public static final class Null extends Value {
+        @Override
+        public long getDiscriminant() {
+            return 0L;
+        }
+    }
+    public static final class String extends Value {
+        public final values.ValueString value;
+        public String(values.ValueString value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 1L;
+        }
+    }
+    public static final class Int8 extends Value {
+        public final values.ValueInt8 value;
+        public Int8(values.ValueInt8 value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 2L;
+        }
+    }
+    public static final class Int16 extends Value {
+        public final values.ValueInt16 value;
+        public Int16(values.ValueInt16 value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 3L;
+        }
+    }
+    public static final class Int32 extends Value {
+        public final values.ValueInt32 value;
+        public Int32(values.ValueInt32 value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 4L;
+        }
+    }
+    public static final class Int64 extends Value {
+        public final values.ValueInt64 value;
+        public Int64(values.ValueInt64 value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 5L;
+        }
+    }
+    public static final class UInt8 extends Value {
+        public final values.ValueUInt8 value;
+        public UInt8(values.ValueUInt8 value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 6L;
+        }
+    }
+    public static final class UInt16 extends Value {
+        public final values.ValueUInt16 value;
+        public UInt16(values.ValueUInt16 value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 7L;
+        }
+    }
+    public static final class UInt32 extends Value {
+        public final values.ValueUInt32 value;
+        public UInt32(values.ValueUInt32 value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 8L;
+        }
+    }
+    public static final class UInt64 extends Value {
+        public final values.ValueUInt64 value;
+        public UInt64(values.ValueUInt64 value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 9L;
+        }
+    }
+    public static final class Float extends Value {
+        public final values.ValueFloat value;
+        public Float(values.ValueFloat value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 10L;
+        }
+    }
+    public static final class Double extends Value {
+        public final values.ValueDouble value;
+        public Double(values.ValueDouble value) {
+            this.value = value;
+        }
+        @Override
+        public long getDiscriminant() {
+            return 11L;
+        }
+    }
+
+    public boolean isNull() {
+        return this instanceof Null;
+    }
+    public values.ValueString asString() {
+        return this instanceof String ? ((String) this).value : null;
+    }
+    public values.ValueInt8 asInt8() {
+        return this instanceof Int8 ? ((Int8) this).value : null;
+    }
+    public values.ValueInt16 asInt16() {
+        return this instanceof Int16 ? ((Int16) this).value : null;
+    }
+    public values.ValueInt32 asInt32() {
+        return this instanceof Int32 ? ((Int32) this).value : null;
+    }
+    public values.ValueInt64 asInt64() {
+        return this instanceof Int64 ? ((Int64) this).value : null;
+    }
+    public values.ValueUInt8 asUInt8() {
+        return this instanceof UInt8 ? ((UInt8) this).value : null;
+    }
+    public values.ValueUInt16 asUInt16() {
+        return this instanceof UInt16 ? ((UInt16) this).value : null;
+    }
+    public values.ValueUInt32 asUInt32() {
+        return this instanceof UInt32 ? ((UInt32) this).value : null;
+    }
+    public values.ValueUInt64 asUInt64() {
+        return this instanceof UInt64 ? ((UInt64) this).value : null;
+    }
+    public values.ValueFloat asFloat() {
+        return this instanceof Float ? ((Float) this).value : null;
+    }
+    public values.ValueDouble asDouble() {
+        return this instanceof Double ? ((Double) this).value : null;
+    }
+
+    public void setDiscriminant(enums.Header discriminant) {
+        discriminant.setRawType((short) getDiscriminant());
+    }
+    public static bp3d.proto.Message<Value> fromSlice(java.nio.ByteBuffer slice, enums.Header discriminant) throws bp3d.proto.ProtoException {
+        long value = discriminant.getRawType();
+        if (value == 0L) {
+            return new bp3d.proto.Message<Value>(0, new Null());
+        }
+        if (value == 1L) {
+            return values.ValueString.CODEC.fromSlice(slice).<Value>map(String::new);
+        }
+        if (value == 2L) {
+            return values.ValueInt8.CODEC.fromSlice(slice).<Value>map(Int8::new);
+        }
+        if (value == 3L) {
+            return values.ValueInt16.CODEC.fromSlice(slice).<Value>map(Int16::new);
+        }
+        if (value == 4L) {
+            return values.ValueInt32.CODEC.fromSlice(slice).<Value>map(Int32::new);
+        }
+        if (value == 5L) {
+            return values.ValueInt64.CODEC.fromSlice(slice).<Value>map(Int64::new);
+        }
+        if (value == 6L) {
+            return values.ValueUInt8.CODEC.fromSlice(slice).<Value>map(UInt8::new);
+        }
+        if (value == 7L) {
+            return values.ValueUInt16.CODEC.fromSlice(slice).<Value>map(UInt16::new);
+        }
+        if (value == 8L) {
+            return values.ValueUInt32.CODEC.fromSlice(slice).<Value>map(UInt32::new);
+        }
+        if (value == 9L) {
+            return values.ValueUInt64.CODEC.fromSlice(slice).<Value>map(UInt64::new);
+        }
+        if (value == 10L) {
+            return values.ValueFloat.CODEC.fromSlice(slice).<Value>map(Float::new);
+        }
+        if (value == 11L) {
+            return values.ValueDouble.CODEC.fromSlice(slice).<Value>map(Double::new);
+        }
+
+        throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+    }
+    public static void writeTo(Value input, enums.Header discriminant, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+        long value = discriminant.getRawType();
+        if (input instanceof String) {
+            if (value != 1L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueString.CODEC.writeTo(((String) input).value, out);
+        }
+        if (input instanceof Int8) {
+            if (value != 2L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueInt8.CODEC.writeTo(((Int8) input).value, out);
+        }
+        if (input instanceof Int16) {
+            if (value != 3L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueInt16.CODEC.writeTo(((Int16) input).value, out);
+        }
+        if (input instanceof Int32) {
+            if (value != 4L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueInt32.CODEC.writeTo(((Int32) input).value, out);
+        }
+        if (input instanceof Int64) {
+            if (value != 5L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueInt64.CODEC.writeTo(((Int64) input).value, out);
+        }
+        if (input instanceof UInt8) {
+            if (value != 6L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueUInt8.CODEC.writeTo(((UInt8) input).value, out);
+        }
+        if (input instanceof UInt16) {
+            if (value != 7L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueUInt16.CODEC.writeTo(((UInt16) input).value, out);
+        }
+        if (input instanceof UInt32) {
+            if (value != 8L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueUInt32.CODEC.writeTo(((UInt32) input).value, out);
+        }
+        if (input instanceof UInt64) {
+            if (value != 9L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueUInt64.CODEC.writeTo(((UInt64) input).value, out);
+        }
+        if (input instanceof Float) {
+            if (value != 10L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueFloat.CODEC.writeTo(((Float) input).value, out);
+        }
+        if (input instanceof Double) {
+            if (value != 11L) {
+                throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+            }
+            values.ValueDouble.CODEC.writeTo(((Double) input).value, out);
+        }
+
+    }
+}
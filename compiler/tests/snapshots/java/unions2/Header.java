@@ -0,0 +1,54 @@
+package unions2;
+
+public final class Header {
+    public static final int SIZE = 1;
+    public static final bp3d.proto.StructCodec<Header> CODEC = new bp3d.proto.StructCodec<>(SIZE, Header::new, Header::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Header(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Header allocate() {
+        return new Header(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawTest() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 0, 1, 1, 0, 1);
+
+    }
+    public Test getTest() {
+        return Test.fromRaw(getRawTest());
+    }
+    public short getRawTest2() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 0, 1, 1, 1, 7);
+
+    }
+    public byte getTest2() {
+        long rawValue = getRawTest2();
+        if (rawValue > 63L) {
+            return (byte) -(((~rawValue) & 63L) + 1);
+        } else {
+            return (byte) (rawValue & 63L);
+        }
+    }
+
+    public void setRawTest(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 0, 1, 1, 0, 1, value);
+
+    }
+    public Header setTest(Test value) {
+        setRawTest((short) value.raw);
+        return this;
+    }
+    public void setRawTest2(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 0, 1, 1, 1, 7, value);
+
+    }
+    public Header setTest2(byte value) {
+        setRawTest2((short) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+
+}
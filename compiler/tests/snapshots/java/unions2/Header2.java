@@ -0,0 +1,21 @@
+package unions2;
+
+public final class Header2 {
+    public static final int SIZE = 1;
+    public static final bp3d.proto.StructCodec<Header2> CODEC = new bp3d.proto.StructCodec<>(SIZE, Header2::new, Header2::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Header2(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Header2 allocate() {
+        return new Header2(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public Header getInner() {
+        return new Header(bp3d.proto.Buffers.range(data, 0, 1));
+    }
+
+
+}
@@ -0,0 +1,45 @@
+package unions2;
+
+public abstract class NestedDiscriminant {
+    private NestedDiscriminant() {
+    }
+    public abstract long getDiscriminant();
+    public static final class V1 extends NestedDiscriminant {
+        @Override
+        public long getDiscriminant() {
+            return 0L;
+        }
+    }
+    public static final class V2 extends NestedDiscriminant {
+        @Override
+        public long getDiscriminant() {
+            return 1L;
+        }
+    }
+
+    public boolean isV1() {
+        return this instanceof V1;
+    }
+    public boolean isV2() {
+        return this instanceof V2;
+    }
+
+    public void setDiscriminant(Header2 discriminant) {
+        discriminant.getInner().setRawTest((short) getDiscriminant());
+    }
+    public static bp3d.proto.Message<NestedDiscriminant> fromSlice(java.nio.ByteBuffer slice, Header2 discriminant) throws bp3d.proto.ProtoException {
+        long value = discriminant.getInner().getRawTest();
+        if (value == 0L) {
+            return new bp3d.proto.Message<NestedDiscriminant>(0, new V1());
+        }
+        if (value == 1L) {
+            return new bp3d.proto.Message<NestedDiscriminant>(0, new V2());
+        }
+
+        throw new bp3d.proto.ProtoException.InvalidUnionDiscriminant(value);
+    }
+    public static void writeTo(NestedDiscriminant input, Header2 discriminant, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+        long value = discriminant.getInner().getRawTest();
+
+    }
+}
@@ -0,0 +1,20 @@
+package unions2;
+
+public enum Test {
+    V1(0L),
+    V2(1L),
+
+    ;
+    public final long raw;
+    Test(long raw) {
+        this.raw = raw;
+    }
+    public static Test fromRaw(long raw) {
+        for (Test v : values()) {
+            if (v.raw == raw) {
+                return v;
+            }
+        }
+        return null;
+    }
+}
@@ -0,0 +1,35 @@
+package values;
+
+public final class ValueDouble {
+    public static final int SIZE = 8;
+    public static final bp3d.proto.StructCodec<ValueDouble> CODEC = new bp3d.proto.StructCodec<>(SIZE, ValueDouble::new, ValueDouble::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public ValueDouble(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static ValueDouble allocate() {
+        return new ValueDouble(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawData() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 8, 8);
+
+    }
+    public double getData() {
+        return bp3d.proto.Transmute.doubleValue(getRawData());
+
+    }
+
+    public void setRawData(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 8, 8, value);
+
+    }
+    public ValueDouble setData(double value) {
+        setRawData((long) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+
+}
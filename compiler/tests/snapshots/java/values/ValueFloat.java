@@ -0,0 +1,35 @@
+package values;
+
+public final class ValueFloat {
+    public static final int SIZE = 4;
+    public static final bp3d.proto.StructCodec<ValueFloat> CODEC = new bp3d.proto.StructCodec<>(SIZE, ValueFloat::new, ValueFloat::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public ValueFloat(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static ValueFloat allocate() {
+        return new ValueFloat(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawData() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 4, 4);
+
+    }
+    public float getData() {
+        return bp3d.proto.Transmute.floatValue(getRawData());
+
+    }
+
+    public void setRawData(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 4, 4, value);
+
+    }
+    public ValueFloat setData(float value) {
+        setRawData((long) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+
+}
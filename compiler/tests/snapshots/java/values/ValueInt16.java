@@ -0,0 +1,35 @@
+package values;
+
+public final class ValueInt16 {
+    public static final int SIZE = 2;
+    public static final bp3d.proto.StructCodec<ValueInt16> CODEC = new bp3d.proto.StructCodec<>(SIZE, ValueInt16::new, ValueInt16::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public ValueInt16(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static ValueInt16 allocate() {
+        return new ValueInt16(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public int getRawData() {
+        return (int) bp3d.proto.ByteCodec.LE.read(data, 0, 2, 2);
+
+    }
+    public short getData() {
+        return bp3d.proto.Transmute.shortValue(getRawData());
+
+    }
+
+    public void setRawData(int value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 2, 2, value);
+
+    }
+    public ValueInt16 setData(short value) {
+        setRawData((int) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+
+}
@@ -0,0 +1,35 @@
+package values;
+
+public final class ValueInt32 {
+    public static final int SIZE = 4;
+    public static final bp3d.proto.StructCodec<ValueInt32> CODEC = new bp3d.proto.StructCodec<>(SIZE, ValueInt32::new, ValueInt32::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public ValueInt32(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static ValueInt32 allocate() {
+        return new ValueInt32(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawData() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 4, 4);
+
+    }
+    public int getData() {
+        return bp3d.proto.Transmute.intValue(getRawData());
+
+    }
+
+    public void setRawData(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 4, 4, value);
+
+    }
+    public ValueInt32 setData(int value) {
+        setRawData((long) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+
+}
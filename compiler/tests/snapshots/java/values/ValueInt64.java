@@ -0,0 +1,35 @@
+package values;
+
+public final class ValueInt64 {
+    public static final int SIZE = 8;
+    public static final bp3d.proto.StructCodec<ValueInt64> CODEC = new bp3d.proto.StructCodec<>(SIZE, ValueInt64::new, ValueInt64::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public ValueInt64(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static ValueInt64 allocate() {
+        return new ValueInt64(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawData() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 8, 8);
+
+    }
+    public long getData() {
+        return bp3d.proto.Transmute.longValue(getRawData());
+
+    }
+
+    public void setRawData(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 8, 8, value);
+
+    }
+    public ValueInt64 setData(long value) {
+        setRawData((long) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+
+}
@@ -0,0 +1,35 @@
+package values;
+
+public final class ValueInt8 {
+    public static final int SIZE = 1;
+    public static final bp3d.proto.StructCodec<ValueInt8> CODEC = new bp3d.proto.StructCodec<>(SIZE, ValueInt8::new, ValueInt8::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public ValueInt8(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static ValueInt8 allocate() {
+        return new ValueInt8(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawData() {
+        return (short) bp3d.proto.ByteCodec.LE.read(data, 0, 1, 1);
+
+    }
+    public byte getData() {
+        return bp3d.proto.Transmute.byteValue(getRawData());
+
+    }
+
+    public void setRawData(short value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 1, 1, value);
+
+    }
+    public ValueInt8 setData(byte value) {
+        setRawData((short) bp3d.proto.Transmute.fromValue(value));
+
+        return this;
+    }
+
+}
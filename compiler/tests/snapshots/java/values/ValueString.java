@@ -0,0 +1,21 @@
+package values;
+
+public final class ValueString {
+    public String data;
+
+    public static final bp3d.proto.Codec<ValueString> CODEC = new bp3d.proto.Codec<ValueString>() {
+        @Override
+        public bp3d.proto.Message<ValueString> fromSlice(java.nio.ByteBuffer slice) throws bp3d.proto.ProtoException {
+            ValueString data = new ValueString();
+            bp3d.proto.Reader reader = new bp3d.proto.Reader(slice);
+            data.data = reader.read(bp3d.proto.NullTerminatedString.CODEC);
+
+            return new bp3d.proto.Message<>(reader.offset(), data);
+        }
+        @Override
+        public void writeTo(ValueString input, java.io.OutputStream out) throws java.io.IOException, bp3d.proto.ProtoException {
+            bp3d.proto.NullTerminatedString.CODEC.writeTo(input.data, out);
+
+        }
+    };
+}
@@ -0,0 +1,33 @@
+package values;
+
+public final class ValueUInt16 {
+    public static final int SIZE = 2;
+    public static final bp3d.proto.StructCodec<ValueUInt16> CODEC = new bp3d.proto.StructCodec<>(SIZE, ValueUInt16::new, ValueUInt16::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public ValueUInt16(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static ValueUInt16 allocate() {
+        return new ValueUInt16(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public int getRawData() {
+        return (int) bp3d.proto.ByteCodec.LE.read(data, 0, 2, 2);
+
+    }
+    public int getData() {
+        return getRawData();
+    }
+
+    public void setRawData(int value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 2, 2, value);
+
+    }
+    public ValueUInt16 setData(int value) {
+        setRawData(value);
+        return this;
+    }
+
+}
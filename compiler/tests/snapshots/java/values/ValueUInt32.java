@@ -0,0 +1,33 @@
+package values;
+
+public final class ValueUInt32 {
+    public static final int SIZE = 4;
+    public static final bp3d.proto.StructCodec<ValueUInt32> CODEC = new bp3d.proto.StructCodec<>(SIZE, ValueUInt32::new, ValueUInt32::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public ValueUInt32(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static ValueUInt32 allocate() {
+        return new ValueUInt32(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawData() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 4, 4);
+
+    }
+    public long getData() {
+        return getRawData();
+    }
+
+    public void setRawData(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 4, 4, value);
+
+    }
+    public ValueUInt32 setData(long value) {
+        setRawData(value);
+        return this;
+    }
+
+}
@@ -0,0 +1,33 @@
+package values;
+
+public final class ValueUInt64 {
+    public static final int SIZE = 8;
+    public static final bp3d.proto.StructCodec<ValueUInt64> CODEC = new bp3d.proto.StructCodec<>(SIZE, ValueUInt64::new, ValueUInt64::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public ValueUInt64(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static ValueUInt64 allocate() {
+        return new ValueUInt64(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawData() {
+        return (long) bp3d.proto.ByteCodec.LE.read(data, 0, 8, 8);
+
+    }
+    public long getData() {
+        return getRawData();
+    }
+
+    public void setRawData(long value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 8, 8, value);
+
+    }
+    public ValueUInt64 setData(long value) {
+        setRawData(value);
+        return this;
+    }
+
+}
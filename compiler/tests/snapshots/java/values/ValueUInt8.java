@@ -0,0 +1,33 @@
+package values;
+
+public final class ValueUInt8 {
+    public static final int SIZE = 1;
+    public static final bp3d.proto.StructCodec<ValueUInt8> CODEC = new bp3d.proto.StructCodec<>(SIZE, ValueUInt8::new, ValueUInt8::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public ValueUInt8(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static ValueUInt8 allocate() {
+        return new ValueUInt8(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawData() {
+        return (short) bp3d.proto.ByteCodec.LE.read(data, 0, 1, 1);
+
+    }
+    public short getData() {
+        return getRawData();
+    }
+
+    public void setRawData(short value) {
+        bp3d.proto.ByteCodec.LE.write(data, 0, 1, 1, value);
+
+    }
+    public ValueUInt8 setData(short value) {
+        setRawData(value);
+        return this;
+    }
+
+}
@@ -0,0 +1,63 @@
+package views;
+
+public final class Access {
+    public static final int SIZE = 4;
+    public static final bp3d.proto.StructCodec<Access> CODEC = new bp3d.proto.StructCodec<>(SIZE, Access::new, Access::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Access(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Access allocate() {
+        return new Access(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public short getRawOwner() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 0, 1, 1, 0, 3);
+
+    }
+    public Permissions getOwner() {
+        return new Permissions(getRawOwner());
+    }
+    public int getRawFeatures() {
+        return (int) bp3d.proto.BitCodec.LE.read(data, 0, 2, 2, 3, 13);
+
+    }
+    public Features getFeatures() {
+        return new Features(getRawFeatures());
+    }
+    public int getRawGroup() {
+        return (int) bp3d.proto.ByteCodec.LE.read(data, 2, 4, 2);
+
+    }
+    public Permissions getGroup() {
+        return new Permissions(getRawGroup());
+    }
+
+    public void setRawOwner(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 0, 1, 1, 0, 3, value);
+
+    }
+    public Access setOwner(Permissions value) {
+        setRawOwner((short) value.bits());
+        return this;
+    }
+    public void setRawFeatures(int value) {
+        bp3d.proto.BitCodec.LE.write(data, 0, 2, 2, 3, 13, value);
+
+    }
+    public Access setFeatures(Features value) {
+        setRawFeatures((int) value.bits());
+        return this;
+    }
+    public void setRawGroup(int value) {
+        bp3d.proto.ByteCodec.LE.write(data, 2, 4, 2, value);
+
+    }
+    public Access setGroup(Permissions value) {
+        setRawGroup((int) value.bits());
+        return this;
+    }
+
+}
@@ -0,0 +1,37 @@
+package views;
+
+public final class Features {
+    public static final long COMPRESSED = 1L << 0;
+    public static final long ENCRYPTED = 1L << 1;
+    public static final long SIGNED = 1L << 9;
+
+    private long bits;
+    public Features() {
+        this(0);
+    }
+    public Features(long bits) {
+        this.bits = bits;
+    }
+    public long bits() {
+        return bits;
+    }
+    public boolean contains(long flags) {
+        return (bits & flags) == flags;
+    }
+    public Features insert(long flags) {
+        bits |= flags;
+        return this;
+    }
+    public Features remove(long flags) {
+        bits &= ~flags;
+        return this;
+    }
+    @Override
+    public boolean equals(Object other) {
+        return other instanceof Features && ((Features) other).bits == bits;
+    }
+    @Override
+    public int hashCode() {
+        return Long.hashCode(bits);
+    }
+}
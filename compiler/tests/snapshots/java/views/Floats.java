@@ -0,0 +1,52 @@
+package views;
+
+public final class Floats {
+    public static final int SIZE = 3;
+    public static final bp3d.proto.StructCodec<Floats> CODEC = new bp3d.proto.StructCodec<>(SIZE, Floats::new, Floats::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Floats(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Floats allocate() {
+        return new Floats(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public long getRawA() {
+        return (long) bp3d.proto.BitCodec.LE.read(data, 0, 3, 4, 0, 17);
+
+    }
+    public double getA() {
+        double rawValue = getRawA();
+        return (double) (rawValue * 7.629452739355007e-5 + 0.0);
+    }
+    public short getRawB() {
+        return (short) bp3d.proto.BitCodec.LE.read(data, 2, 3, 1, 1, 7);
+
+    }
+    public float getB() {
+        double rawValue = getRawB();
+        return (float) (rawValue * 0.1 + 0.0);
+    }
+
+    public void setRawA(long value) {
+        bp3d.proto.BitCodec.LE.write(data, 0, 3, 4, 0, 17, value);
+
+    }
+    public Floats setA(double value) {
+        double rawValue = value * 13107.099999999999 + -0.0;
+        setRawA((long) rawValue);
+        return this;
+    }
+    public void setRawB(short value) {
+        bp3d.proto.BitCodec.LE.write(data, 2, 3, 1, 1, 7, value);
+
+    }
+    public Floats setB(float value) {
+        double rawValue = value * 10.0 + 0.0;
+        setRawB((short) rawValue);
+        return this;
+    }
+
+}
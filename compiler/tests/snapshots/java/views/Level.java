@@ -0,0 +1,21 @@
+package views;
+
+public enum Level {
+    Low(0L),
+    Mid(1L),
+    High(2L),
+
+    ;
+    public final long raw;
+    Level(long raw) {
+        this.raw = raw;
+    }
+    public static Level fromRaw(long raw) {
+        for (Level v : values()) {
+            if (v.raw == raw) {
+                return v;
+            }
+        }
+        return null;
+    }
+}
@@ -0,0 +1,37 @@
+package views;
+
+public final class Permissions {
+    public static final long READ = 1L << 0;
+    public static final long WRITE = 1L << 1;
+    public static final long EXECUTE = 1L << 2;
+
+    private long bits;
+    public Permissions() {
+        this(0);
+    }
+    public Permissions(long bits) {
+        this.bits = bits;
+    }
+    public long bits() {
+        return bits;
+    }
+    public boolean contains(long flags) {
+        return (bits & flags) == flags;
+    }
+    public Permissions insert(long flags) {
+        bits |= flags;
+        return this;
+    }
+    public Permissions remove(long flags) {
+        bits &= ~flags;
+        return this;
+    }
+    @Override
+    public boolean equals(Object other) {
+        return other instanceof Permissions && ((Permissions) other).bits == bits;
+    }
+    @Override
+    public int hashCode() {
+        return Long.hashCode(bits);
+    }
+}
@@ -0,0 +1,48 @@
+package views;
+
+public final class Readings {
+    public static final int SIZE = 14;
+    public static final bp3d.proto.StructCodec<Readings> CODEC = new bp3d.proto.StructCodec<>(SIZE, Readings::new, Readings::toByteBuffer);
+    private final java.nio.ByteBuffer data;
+    public Readings(java.nio.ByteBuffer data) {
+        this.data = data;
+    }
+    public java.nio.ByteBuffer toByteBuffer() {
+        return data;
+    }
+    public static Readings allocate() {
+        return new Readings(java.nio.ByteBuffer.allocate(SIZE));
+    }
+    public bp3d.proto.BitArrayCodec getRawLevels() {
+        return new bp3d.proto.BitArrayCodec(bp3d.proto.Buffers.range(data, 0, 2), bp3d.proto.BitCodec.LE, 0, 3, 5);
+    }
+    public bp3d.proto.ViewArray<Level> getLevels() {
+        return new bp3d.proto.ViewArray<>(getRawLevels(), raw -> Level.fromRaw(raw), value -> value.raw);
+    }
+    public bp3d.proto.BitArrayCodec getRawValues() {
+        return new bp3d.proto.BitArrayCodec(bp3d.proto.Buffers.range(data, 1, 8), bp3d.proto.BitCodec.LE, 7, 12, 4);
+    }
+    public bp3d.proto.ViewArray<Float> getValues() {
+        return new bp3d.proto.ViewArray<>(getRawValues(), raw -> (float) (raw * 0.0002442002442002442 + 0.0), value -> (long) (value * 4095.0 + -0.0));
+    }
+    public bp3d.proto.BitArrayCodec getRawDeltas() {
+        return new bp3d.proto.BitArrayCodec(bp3d.proto.Buffers.range(data, 7, 10), bp3d.proto.BitCodec.LE, 7, 5, 3);
+    }
+    public bp3d.proto.ViewArray<Byte> getDeltas() {
+        return new bp3d.proto.ViewArray<>(getRawDeltas(), raw -> (byte) (raw > 15L ? -(((~raw) & 15L) + 1) : (raw & 15L)), value -> (long) value);
+    }
+    public bp3d.proto.BitArrayCodec getRawMask() {
+        return new bp3d.proto.BitArrayCodec(bp3d.proto.Buffers.range(data, 9, 10), bp3d.proto.BitCodec.LE, 6, 1, 2);
+    }
+    public bp3d.proto.ViewArray<Boolean> getMask() {
+        return new bp3d.proto.ViewArray<>(getRawMask(), raw -> raw != 0, value -> value ? 1L : 0L);
+    }
+    public bp3d.proto.ArrayCodec.Int getRawGains() {
+        return new bp3d.proto.ArrayCodec.Int(bp3d.proto.Buffers.range(data, 10, 14), bp3d.proto.ByteCodec.LE, 2, 16);
+    }
+    public bp3d.proto.ViewArray<Float> getGains() {
+        return new bp3d.proto.ViewArray<>(getRawGains(), raw -> (float) (raw * 0.01 + 0.0), value -> (long) (value * 100.0 + 0.0));
+    }
+
+
+}
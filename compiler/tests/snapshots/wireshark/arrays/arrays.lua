@@ -0,0 +1,217 @@
+-- Wireshark dissector for the arrays protocol.
+arrays = arrays or {}
+local proto = Proto("arrays", "arrays protocol")
+arrays.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+arrays.Item = { size = 3 }
+fields["arrays.item.id"] = ProtoField.uint8("arrays.item.id", "id", base.DEC, nil)
+fields["arrays.item.count"] = ProtoField.uint16("arrays.item.count", "count", base.DEC, nil, 0x7ff)
+fields["arrays.item.slot"] = ProtoField.uint8("arrays.item.slot", "slot", base.DEC, nil, 0xf8)
+
+function arrays.Item.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 3), label .. ": Item")
+    local field_values = {}
+    field_values["id"] = read_bits(tvb(offset + 0, 1), true, 0, 0xff)
+    subtree:add_le(fields["arrays.item.id"], tvb(offset + 0, 1))
+    field_values["count"] = read_bits(tvb(offset + 1, 2), true, 0, 0x7ff)
+    subtree:add_le(fields["arrays.item.count"], tvb(offset + 1, 2))
+    field_values["slot"] = read_bits(tvb(offset + 2, 1), true, 3, 0x1f)
+    subtree:add_le(fields["arrays.item.slot"], tvb(offset + 2, 1))
+
+    return 3, field_values
+end
+arrays.Msg = {}
+
+function arrays.Msg.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Msg")
+    local field_values = {}
+    do
+        local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+        local list = subtree:add(proto, tvb(offset, 0), "items: " .. count .. " item(s)")
+        local list_start = offset
+        local items = {}
+        offset = offset + 1
+        for i = 1, count do
+            local size, v = arrays.Item.dissect(tvb, offset, list, "[" .. (i - 1) .. "]")
+            items[i] = v
+            offset = offset + size
+        end
+        list:set_len(offset - list_start)
+        field_values["items"] = items
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+arrays.Msg1 = {}
+
+function arrays.Msg1.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Msg1")
+    local field_values = {}
+    do
+        local present = tvb(offset, 1):uint() > 0
+        offset = offset + 1
+        if present then
+            do
+                local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+                local list = subtree:add(proto, tvb(offset, 0), "items: " .. count .. " item(s)")
+                local list_start = offset
+                local items = {}
+                offset = offset + 1
+                for i = 1, count do
+                    local size, v = arrays.Item.dissect(tvb, offset, list, "[" .. (i - 1) .. "]")
+                    items[i] = v
+                    offset = offset + size
+                end
+                list:set_len(offset - list_start)
+                field_values["items"] = items
+            end
+
+        end
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+arrays.Pair = {}
+fields["arrays.pair.id"] = ProtoField.uint8("arrays.pair.id", "id", base.DEC)
+
+function arrays.Pair.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Pair")
+    local field_values = {}
+    subtree:add_le(fields["arrays.pair.id"], tvb(offset, 1))
+    offset = offset + 1
+    do
+        local size, v = arrays.Item.dissect(tvb, offset, subtree, "item")
+        field_values["item"] = v
+        offset = offset + size
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+arrays.Block = {}
+fields["arrays.block.samples"] = ProtoField.int16("arrays.block.samples", "samples", base.DEC)
+
+function arrays.Block.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Block")
+    local field_values = {}
+    do
+        local count = 4
+        local list = subtree:add(proto, tvb(offset, 0), "items: " .. count .. " item(s)")
+        local list_start = offset
+        local items = {}
+        offset = offset + 0
+        for i = 1, count do
+            local size, v = arrays.Item.dissect(tvb, offset, list, "[" .. (i - 1) .. "]")
+            items[i] = v
+            offset = offset + size
+        end
+        list:set_len(offset - list_start)
+        field_values["items"] = items
+    end
+    do
+        local count = 16
+        local list = subtree:add(proto, tvb(offset, 0), "samples: " .. count .. " item(s)")
+        local list_start = offset
+        offset = offset + 0
+        for i = 1, count do
+            list:add_le(fields["arrays.block.samples"], tvb(offset, 2))
+            offset = offset + 2
+        end
+        list:set_len(offset - list_start)
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+arrays.Pairs = {}
+
+function arrays.Pairs.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Pairs")
+    local field_values = {}
+    do
+        local count = 2
+        local list = subtree:add(proto, tvb(offset, 0), "pairs: " .. count .. " item(s)")
+        local list_start = offset
+        local items = {}
+        offset = offset + 0
+        for i = 1, count do
+            local size, v = arrays.Pair.dissect(tvb, offset, list, "[" .. (i - 1) .. "]")
+            items[i] = v
+            offset = offset + size
+        end
+        list:set_len(offset - list_start)
+        field_values["pairs"] = items
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+proto.fields = fields
+arrays.messages = {
+    "Msg",
+    "Msg1",
+    "Pair",
+    "Block",
+    "Pairs",
+
+}
+arrays.ports = arrays.ports or {}
+local message_choices = {
+    { 1, "Msg", 1 },
+    { 2, "Msg1", 2 },
+    { 3, "Pair", 3 },
+    { 4, "Block", 4 },
+    { 5, "Pairs", 5 },
+
+}
+proto.prefs.message = Pref.enum("Message", 1, "Top-level message decoded on the configured ports", message_choices, false)
+proto.prefs.udp_port = Pref.uint("UDP port", 0, "UDP port to decode (0 to disable)")
+proto.prefs.tcp_port = Pref.uint("TCP port", 0, "TCP port to decode (0 to disable)")
+function arrays.register_port(table_name, port, message)
+    arrays.ports[port] = message
+    DissectorTable.get(table_name):add(port, proto)
+end
+local current_ports = {}
+function proto.prefs_changed()
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):remove(port, proto)
+    end
+    current_ports = {}
+    if proto.prefs.udp_port ~= 0 then
+        current_ports["udp.port"] = proto.prefs.udp_port
+    end
+    if proto.prefs.tcp_port ~= 0 then
+        current_ports["tcp.port"] = proto.prefs.tcp_port
+    end
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):add(port, proto)
+    end
+end
+function proto.dissector(tvb, pinfo, tree)
+    local message = arrays.ports[pinfo.match_uint] or arrays.messages[proto.prefs.message]
+    pinfo.cols.protocol = "arrays"
+    pinfo.cols.info = message
+    local size = arrays[message].dissect(tvb, 0, tree, message)
+    return size
+end
@@ -0,0 +1,58 @@
+-- Wireshark dissector for the bits protocol.
+bits = bits or {}
+local proto = Proto("bits", "bits protocol")
+bits.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+bits.Numbers = { size = 4 }
+fields["bits.numbers.a"] = ProtoField.int8("bits.numbers.a", "a", base.DEC, nil, 0xf)
+fields["bits.numbers.b"] = ProtoField.uint8("bits.numbers.b", "b", base.DEC, nil, 0xf0)
+fields["bits.numbers.c"] = ProtoField.int24("bits.numbers.c", "c", base.DEC, nil, 0x1ffff)
+fields["bits.numbers.d"] = ProtoField.uint8("bits.numbers.d", "d", base.DEC, nil, 0xfe)
+
+function bits.Numbers.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 4), label .. ": Numbers")
+    local field_values = {}
+    field_values["a"] = read_bits(tvb(offset + 0, 1), true, 0, 0xf)
+    subtree:add_le(fields["bits.numbers.a"], tvb(offset + 0, 1))
+    field_values["b"] = read_bits(tvb(offset + 0, 1), true, 4, 0xf)
+    subtree:add_le(fields["bits.numbers.b"], tvb(offset + 0, 1))
+    field_values["c"] = read_bits(tvb(offset + 1, 3), true, 0, 0x1ffff)
+    subtree:add_le(fields["bits.numbers.c"], tvb(offset + 1, 3))
+    field_values["d"] = read_bits(tvb(offset + 3, 1), true, 1, 0x7f)
+    subtree:add_le(fields["bits.numbers.d"], tvb(offset + 3, 1))
+
+    return 4, field_values
+end
+bits.Packed = { size = 13 }
+fields["bits.packed.tag"] = ProtoField.uint8("bits.packed.tag", "tag", base.DEC, nil, 0xf)
+fields["bits.packed.nibbles"] = ProtoField.bytes("bits.packed.nibbles", "nibbles")
+fields["bits.packed.fives"] = ProtoField.bytes("bits.packed.fives", "fives")
+fields["bits.packed.tail"] = ProtoField.uint8("bits.packed.tail", "tail", base.DEC, nil, 0xc0)
+
+function bits.Packed.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 13), label .. ": Packed")
+    local field_values = {}
+    field_values["tag"] = read_bits(tvb(offset + 0, 1), true, 0, 0xf)
+    subtree:add_le(fields["bits.packed.tag"], tvb(offset + 0, 1))
+    subtree:add(fields["bits.packed.nibbles"], tvb(offset + 0, 7))
+    subtree:add(fields["bits.packed.fives"], tvb(offset + 6, 7))
+    field_values["tail"] = read_bits(tvb(offset + 12, 1), true, 6, 0x3)
+    subtree:add_le(fields["bits.packed.tail"], tvb(offset + 12, 1))
+
+    return 13, field_values
+end
+proto.fields = fields
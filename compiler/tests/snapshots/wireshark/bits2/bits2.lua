@@ -0,0 +1,40 @@
+-- Wireshark dissector for the bits2 protocol.
+bits2 = bits2 or {}
+local proto = Proto("bits2", "bits2 protocol")
+bits2.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+bits2.Numbers = { size = 4 }
+fields["bits2.numbers.a"] = ProtoField.int8("bits2.numbers.a", "a", base.DEC, nil, 0xf)
+fields["bits2.numbers.b"] = ProtoField.uint8("bits2.numbers.b", "b", base.DEC, nil, 0xf0)
+fields["bits2.numbers.c"] = ProtoField.int24("bits2.numbers.c", "c", base.DEC, nil, 0x1ffff)
+fields["bits2.numbers.d"] = ProtoField.uint8("bits2.numbers.d", "d", base.DEC, nil, 0xfe)
+
+function bits2.Numbers.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 4), label .. ": Numbers")
+    local field_values = {}
+    field_values["a"] = read_bits(tvb(offset + 0, 1), true, 0, 0xf)
+    subtree:add_le(fields["bits2.numbers.a"], tvb(offset + 0, 1))
+    field_values["b"] = read_bits(tvb(offset + 0, 1), true, 4, 0xf)
+    subtree:add_le(fields["bits2.numbers.b"], tvb(offset + 0, 1))
+    field_values["c"] = read_bits(tvb(offset + 1, 3), true, 0, 0x1ffff)
+    subtree:add_le(fields["bits2.numbers.c"], tvb(offset + 1, 3))
+    field_values["d"] = read_bits(tvb(offset + 3, 1), true, 1, 0x7f)
+    subtree:add_le(fields["bits2.numbers.d"], tvb(offset + 3, 1))
+
+    return 4, field_values
+end
+proto.fields = fields
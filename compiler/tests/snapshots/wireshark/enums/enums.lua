@@ -0,0 +1,66 @@
+-- Wireshark dissector for the enums protocol.
+enums = enums or {}
+local proto = Proto("enums", "enums protocol")
+enums.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+enums.Type = {
+    [0] = "Null",
+    [1] = "String",
+    [2] = "Int8",
+    [3] = "Int16",
+    [4] = "Int32",
+    [5] = "Int64",
+    [6] = "UInt8",
+    [7] = "UInt16",
+    [8] = "UInt32",
+    [9] = "UInt64",
+    [10] = "Float",
+    [11] = "Double",
+
+}
+enums.Severity = {
+    [0] = "Debug",
+    [1] = "Info",
+    [5] = "Error",
+
+}
+enums.Header = { size = 1 }
+fields["enums.header.type"] = ProtoField.uint8("enums.header.type", "type", base.DEC, { [0] = "Null", [1] = "String", [2] = "Int8", [3] = "Int16", [4] = "Int32", [5] = "Int64", [6] = "UInt8", [7] = "UInt16", [8] = "UInt32", [9] = "UInt64", [10] = "Float", [11] = "Double" })
+
+function enums.Header.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 1), label .. ": Header")
+    local field_values = {}
+    field_values["type"] = read_bits(tvb(offset + 0, 1), true, 0, 0xff)
+    subtree:add_le(fields["enums.header.type"], tvb(offset + 0, 1))
+
+    return 1, field_values
+end
+enums.Record = { size = 1 }
+fields["enums.record.severity"] = ProtoField.uint8("enums.record.severity", "severity", base.DEC, { [0] = "Debug", [1] = "Info", [5] = "Error" }, 0x7)
+fields["enums.record.count"] = ProtoField.uint8("enums.record.count", "count", base.DEC, nil, 0xf8)
+
+function enums.Record.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 1), label .. ": Record")
+    local field_values = {}
+    field_values["severity"] = read_bits(tvb(offset + 0, 1), true, 0, 0x7)
+    subtree:add_le(fields["enums.record.severity"], tvb(offset + 0, 1))
+    field_values["count"] = read_bits(tvb(offset + 0, 1), true, 3, 0x1f)
+    subtree:add_le(fields["enums.record.count"], tvb(offset + 0, 1))
+
+    return 1, field_values
+end
+proto.fields = fields
@@ -0,0 +1,128 @@
+-- Wireshark dissector for the lists protocol.
+lists = lists or {}
+local proto = Proto("lists", "lists protocol")
+lists.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+lists.Times = { size = 16 }
+fields["lists.times.start"] = ProtoField.uint64("lists.times.start", "start", base.DEC, nil)
+fields["lists.times.end"] = ProtoField.uint64("lists.times.end", "end", base.DEC, nil)
+
+function lists.Times.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 16), label .. ": Times")
+    local field_values = {}
+    field_values["start"] = read_bits(tvb(offset + 0, 8), true, 0, UInt64.fromhex("ffffffffffffffff"))
+    subtree:add_le(fields["lists.times.start"], tvb(offset + 0, 8))
+    field_values["end"] = read_bits(tvb(offset + 8, 8), true, 0, UInt64.fromhex("ffffffffffffffff"))
+    subtree:add_le(fields["lists.times.end"], tvb(offset + 8, 8))
+
+    return 16, field_values
+end
+lists.SpanRun = {}
+
+function lists.SpanRun.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": SpanRun")
+    local field_values = {}
+    do
+        local size, v = lists.Times.dissect(tvb, offset, subtree, "times")
+        field_values["times"] = v
+        offset = offset + size
+    end
+    do
+        local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+        local list = subtree:add(proto, tvb(offset, 0), "vars: " .. count .. " item(s)")
+        local list_start = offset
+        local items = {}
+        offset = offset + 1
+        for i = 1, count do
+            local size, v = unions.Item.dissect(tvb, offset, list, "[" .. (i - 1) .. "]")
+            items[i] = v
+            offset = offset + size
+        end
+        list:set_len(offset - list_start)
+        field_values["vars"] = items
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+lists.Dataset = {}
+
+function lists.Dataset.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Dataset")
+    local field_values = {}
+    do
+        local count = read_bits(tvb(offset, 4), true, 0, 0xffffffff)
+        local list = subtree:add(proto, tvb(offset, 0), "runs: " .. count .. " item(s)")
+        local list_start = offset
+        local items = {}
+        offset = offset + 4
+        for i = 1, count do
+            local size, v = lists.SpanRun.dissect(tvb, offset, list, "[" .. (i - 1) .. "]")
+            items[i] = v
+            offset = offset + size
+        end
+        list:set_len(offset - list_start)
+        field_values["runs"] = items
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+proto.fields = fields
+lists.messages = {
+    "SpanRun",
+    "Dataset",
+
+}
+lists.ports = lists.ports or {}
+local message_choices = {
+    { 1, "SpanRun", 1 },
+    { 2, "Dataset", 2 },
+
+}
+proto.prefs.message = Pref.enum("Message", 1, "Top-level message decoded on the configured ports", message_choices, false)
+proto.prefs.udp_port = Pref.uint("UDP port", 0, "UDP port to decode (0 to disable)")
+proto.prefs.tcp_port = Pref.uint("TCP port", 0, "TCP port to decode (0 to disable)")
+function lists.register_port(table_name, port, message)
+    lists.ports[port] = message
+    DissectorTable.get(table_name):add(port, proto)
+end
+local current_ports = {}
+function proto.prefs_changed()
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):remove(port, proto)
+    end
+    current_ports = {}
+    if proto.prefs.udp_port ~= 0 then
+        current_ports["udp.port"] = proto.prefs.udp_port
+    end
+    if proto.prefs.tcp_port ~= 0 then
+        current_ports["tcp.port"] = proto.prefs.tcp_port
+    end
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):add(port, proto)
+    end
+end
+function proto.dissector(tvb, pinfo, tree)
+    local message = lists.ports[pinfo.match_uint] or lists.messages[proto.prefs.message]
+    pinfo.cols.protocol = "lists"
+    pinfo.cols.info = message
+    local size = lists[message].dissect(tvb, 0, tree, message)
+    return size
+end
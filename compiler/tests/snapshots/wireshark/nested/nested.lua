@@ -0,0 +1,196 @@
+-- Wireshark dissector for the nested protocol.
+nested = nested or {}
+local proto = Proto("nested", "nested protocol")
+nested.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+nested.Point = { size = 4 }
+fields["nested.point.x"] = ProtoField.int16("nested.point.x", "x", base.DEC)
+fields["nested.point.y"] = ProtoField.int16("nested.point.y", "y", base.DEC)
+
+function nested.Point.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 4), label .. ": Point")
+    local field_values = {}
+    field_values["x"] = read_bits(tvb(offset + 0, 2), true, 0, 0xffff)
+    subtree:add_le(fields["nested.point.x"], tvb(offset + 0, 2))
+    field_values["y"] = read_bits(tvb(offset + 2, 2), true, 0, 0xffff)
+    subtree:add_le(fields["nested.point.y"], tvb(offset + 2, 2))
+
+    return 4, field_values
+end
+nested.Tags = {}
+fields["nested.tags.names"] = ProtoField.string("nested.tags.names", "names")
+fields["nested.tags.labels"] = ProtoField.stringz("nested.tags.labels", "labels")
+
+function nested.Tags.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Tags")
+    local field_values = {}
+    do
+        local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+        local list = subtree:add(proto, tvb(offset, 0), "names: " .. count .. " item(s)")
+        local list_start = offset
+        offset = offset + 1
+        for i = 1, count do
+            local len = read_bits(tvb(offset, 1), true, 0, 0xff)
+            list:add(fields["nested.tags.names"], tvb(offset + 1, len))
+            offset = offset + 1 + len
+        end
+        list:set_len(offset - list_start)
+    end
+    do
+        local present = tvb(offset, 1):uint() > 0
+        offset = offset + 1
+        if present then
+            do
+                local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+                local list = subtree:add(proto, tvb(offset, 0), "labels: " .. count .. " item(s)")
+                local list_start = offset
+                offset = offset + 1
+                for i = 1, count do
+                    local size = tvb(offset):strsize()
+                    list:add(fields["nested.tags.labels"], tvb(offset, size))
+                    offset = offset + size
+                end
+                list:set_len(offset - list_start)
+            end
+
+        end
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+nested.Samples = {}
+fields["nested.samples.channels"] = ProtoField.int32("nested.samples.channels", "channels", base.DEC)
+
+function nested.Samples.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Samples")
+    local field_values = {}
+    do
+        local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+        local list = subtree:add(proto, tvb(offset, 0), "channels: " .. count .. " item(s)")
+        local list_start = offset
+        offset = offset + 1
+        for i = 1, count do
+            do
+                local count = read_bits(tvb(offset, 2), true, 0, 0xffff)
+                local list = list:add(proto, tvb(offset, 0), "[" .. (i - 1) .. "]: " .. count .. " item(s)")
+                local list_start = offset
+                offset = offset + 2
+                for i = 1, count do
+                    list:add_le(fields["nested.samples.channels"], tvb(offset, 4))
+                    offset = offset + 4
+                end
+                list:set_len(offset - list_start)
+            end
+        end
+        list:set_len(offset - list_start)
+    end
+    do
+        local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+        local list = subtree:add(proto, tvb(offset, 0), "shapes: " .. count .. " item(s)")
+        local list_start = offset
+        offset = offset + 1
+        for i = 1, count do
+            do
+                local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+                local list = list:add(proto, tvb(offset, 0), "[" .. (i - 1) .. "]: " .. count .. " item(s)")
+                local list_start = offset
+                offset = offset + 1
+                for i = 1, count do
+                    local size = nested.Point.dissect(tvb, offset, list, "[" .. (i - 1) .. "]")
+                    offset = offset + size
+                end
+                list:set_len(offset - list_start)
+            end
+        end
+        list:set_len(offset - list_start)
+    end
+    do
+        local present = tvb(offset, 1):uint() > 0
+        offset = offset + 1
+        if present then
+            do
+                local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+                local list = subtree:add(proto, tvb(offset, 0), "groups: " .. count .. " item(s)")
+                local list_start = offset
+                offset = offset + 1
+                for i = 1, count do
+                    do
+                        local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+                        local list = list:add(proto, tvb(offset, 0), "[" .. (i - 1) .. "]: " .. count .. " item(s)")
+                        local list_start = offset
+                        offset = offset + 1
+                        for i = 1, count do
+                            local size = nested.Tags.dissect(tvb, offset, list, "[" .. (i - 1) .. "]")
+                            offset = offset + size
+                        end
+                        list:set_len(offset - list_start)
+                    end
+                end
+                list:set_len(offset - list_start)
+            end
+
+        end
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+proto.fields = fields
+nested.messages = {
+    "Tags",
+    "Samples",
+
+}
+nested.ports = nested.ports or {}
+local message_choices = {
+    { 1, "Tags", 1 },
+    { 2, "Samples", 2 },
+
+}
+proto.prefs.message = Pref.enum("Message", 1, "Top-level message decoded on the configured ports", message_choices, false)
+proto.prefs.udp_port = Pref.uint("UDP port", 0, "UDP port to decode (0 to disable)")
+proto.prefs.tcp_port = Pref.uint("TCP port", 0, "TCP port to decode (0 to disable)")
+function nested.register_port(table_name, port, message)
+    nested.ports[port] = message
+    DissectorTable.get(table_name):add(port, proto)
+end
+local current_ports = {}
+function proto.prefs_changed()
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):remove(port, proto)
+    end
+    current_ports = {}
+    if proto.prefs.udp_port ~= 0 then
+        current_ports["udp.port"] = proto.prefs.udp_port
+    end
+    if proto.prefs.tcp_port ~= 0 then
+        current_ports["tcp.port"] = proto.prefs.tcp_port
+    end
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):add(port, proto)
+    end
+end
+function proto.dissector(tvb, pinfo, tree)
+    local message = nested.ports[pinfo.match_uint] or nested.messages[proto.prefs.message]
+    pinfo.cols.protocol = "nested"
+    pinfo.cols.info = message
+    local size = nested[message].dissect(tvb, 0, tree, message)
+    return size
+end
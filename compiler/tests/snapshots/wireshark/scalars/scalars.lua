@@ -0,0 +1,140 @@
+-- Wireshark dissector for the scalars protocol.
+scalars = scalars or {}
+local proto = Proto("scalars", "scalars protocol")
+scalars.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+scalars.Sample = {}
+fields["scalars.sample.id"] = ProtoField.uint32("scalars.sample.id", "id", base.DEC)
+fields["scalars.sample.severity"] = ProtoField.uint8("scalars.sample.severity", "severity", base.DEC, { [0] = "Debug", [1] = "Info", [5] = "Error" })
+fields["scalars.sample.scale"] = ProtoField.double("scalars.sample.scale", "scale")
+fields["scalars.sample.values"] = ProtoField.int16("scalars.sample.values", "values", base.DEC)
+
+function scalars.Sample.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Sample")
+    local field_values = {}
+    subtree:add_le(fields["scalars.sample.id"], tvb(offset, 4))
+    offset = offset + 4
+    subtree:add_le(fields["scalars.sample.severity"], tvb(offset, 1))
+    offset = offset + 1
+    do
+        local present = tvb(offset, 1):uint() > 0
+        offset = offset + 1
+        if present then
+            subtree:add_le(fields["scalars.sample.scale"], tvb(offset, 8))
+            offset = offset + 8
+
+        end
+    end
+    do
+        local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+        local list = subtree:add(proto, tvb(offset, 0), "values: " .. count .. " item(s)")
+        local list_start = offset
+        offset = offset + 1
+        for i = 1, count do
+            list:add_le(fields["scalars.sample.values"], tvb(offset, 2))
+            offset = offset + 2
+        end
+        list:set_len(offset - list_start)
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+scalars.Log = {}
+fields["scalars.log.levels"] = ProtoField.uint8("scalars.log.levels", "levels", base.DEC, { [0] = "Debug", [1] = "Info", [5] = "Error" })
+fields["scalars.log.flags"] = ProtoField.bool("scalars.log.flags", "flags")
+
+function scalars.Log.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Log")
+    local field_values = {}
+    do
+        local count = read_bits(tvb(offset, 2), true, 0, 0xffff)
+        local list = subtree:add(proto, tvb(offset, 0), "levels: " .. count .. " item(s)")
+        local list_start = offset
+        offset = offset + 2
+        for i = 1, count do
+            list:add_le(fields["scalars.log.levels"], tvb(offset, 1))
+            offset = offset + 1
+        end
+        list:set_len(offset - list_start)
+    end
+    do
+        local present = tvb(offset, 1):uint() > 0
+        offset = offset + 1
+        if present then
+            do
+                local count = read_bits(tvb(offset, 1), true, 0, 0xff)
+                local list = subtree:add(proto, tvb(offset, 0), "flags: " .. count .. " item(s)")
+                local list_start = offset
+                offset = offset + 1
+                for i = 1, count do
+                    list:add_le(fields["scalars.log.flags"], tvb(offset, 1))
+                    offset = offset + 1
+                end
+                list:set_len(offset - list_start)
+            end
+
+        end
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+proto.fields = fields
+scalars.messages = {
+    "Sample",
+    "Log",
+
+}
+scalars.ports = scalars.ports or {}
+local message_choices = {
+    { 1, "Sample", 1 },
+    { 2, "Log", 2 },
+
+}
+proto.prefs.message = Pref.enum("Message", 1, "Top-level message decoded on the configured ports", message_choices, false)
+proto.prefs.udp_port = Pref.uint("UDP port", 0, "UDP port to decode (0 to disable)")
+proto.prefs.tcp_port = Pref.uint("TCP port", 0, "TCP port to decode (0 to disable)")
+function scalars.register_port(table_name, port, message)
+    scalars.ports[port] = message
+    DissectorTable.get(table_name):add(port, proto)
+end
+local current_ports = {}
+function proto.prefs_changed()
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):remove(port, proto)
+    end
+    current_ports = {}
+    if proto.prefs.udp_port ~= 0 then
+        current_ports["udp.port"] = proto.prefs.udp_port
+    end
+    if proto.prefs.tcp_port ~= 0 then
+        current_ports["tcp.port"] = proto.prefs.tcp_port
+    end
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):add(port, proto)
+    end
+end
+function proto.dissector(tvb, pinfo, tree)
+    local message = scalars.ports[pinfo.match_uint] or scalars.messages[proto.prefs.message]
+    pinfo.cols.protocol = "scalars"
+    pinfo.cols.info = message
+    local size = scalars[message].dissect(tvb, 0, tree, message)
+    return size
+end
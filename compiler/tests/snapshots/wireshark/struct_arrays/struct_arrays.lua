@@ -0,0 +1,76 @@
+-- Wireshark dissector for the struct_arrays protocol.
+struct_arrays = struct_arrays or {}
+local proto = Proto("struct_arrays", "struct_arrays protocol")
+struct_arrays.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+struct_arrays.Basic = { size = 58 }
+fields["struct_arrays.basic.p1"] = ProtoField.uint32("struct_arrays.basic.p1", "p1", base.DEC, nil)
+fields["struct_arrays.basic.p2"] = ProtoField.bytes("struct_arrays.basic.p2", "p2")
+fields["struct_arrays.basic.p3"] = ProtoField.bytes("struct_arrays.basic.p3", "p3")
+fields["struct_arrays.basic.p4"] = ProtoField.bytes("struct_arrays.basic.p4", "p4")
+
+function struct_arrays.Basic.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 58), label .. ": Basic")
+    local field_values = {}
+    field_values["p1"] = read_bits(tvb(offset + 0, 4), true, 0, 0xffffffff)
+    subtree:add_le(fields["struct_arrays.basic.p1"], tvb(offset + 0, 4))
+    subtree:add(fields["struct_arrays.basic.p2"], tvb(offset + 4, 32))
+    subtree:add(fields["struct_arrays.basic.p3"], tvb(offset + 36, 16))
+    subtree:add(fields["struct_arrays.basic.p4"], tvb(offset + 52, 6))
+
+    return 58, field_values
+end
+struct_arrays.Vertex = { size = 5 }
+fields["struct_arrays.vertex.x"] = ProtoField.int16("struct_arrays.vertex.x", "x", base.DEC)
+fields["struct_arrays.vertex.y"] = ProtoField.int16("struct_arrays.vertex.y", "y", base.DEC)
+fields["struct_arrays.vertex.visible"] = ProtoField.bool("struct_arrays.vertex.visible", "visible", 8, nil, 0x1)
+fields["struct_arrays.vertex.layer"] = ProtoField.uint8("struct_arrays.vertex.layer", "layer", base.DEC, nil, 0xfe)
+
+function struct_arrays.Vertex.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 5), label .. ": Vertex")
+    local field_values = {}
+    field_values["x"] = read_bits(tvb(offset + 0, 2), true, 0, 0xffff)
+    subtree:add_le(fields["struct_arrays.vertex.x"], tvb(offset + 0, 2))
+    field_values["y"] = read_bits(tvb(offset + 2, 2), true, 0, 0xffff)
+    subtree:add_le(fields["struct_arrays.vertex.y"], tvb(offset + 2, 2))
+    field_values["visible"] = read_bits(tvb(offset + 4, 1), true, 0, 0x1)
+    subtree:add_le(fields["struct_arrays.vertex.visible"], tvb(offset + 4, 1))
+    field_values["layer"] = read_bits(tvb(offset + 4, 1), true, 1, 0x7f)
+    subtree:add_le(fields["struct_arrays.vertex.layer"], tvb(offset + 4, 1))
+
+    return 5, field_values
+end
+struct_arrays.Triangle = { size = 16 }
+fields["struct_arrays.triangle.id"] = ProtoField.uint8("struct_arrays.triangle.id", "id", base.DEC, nil)
+
+function struct_arrays.Triangle.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 16), label .. ": Triangle")
+    local field_values = {}
+    field_values["id"] = read_bits(tvb(offset + 0, 1), true, 0, 0xff)
+    subtree:add_le(fields["struct_arrays.triangle.id"], tvb(offset + 0, 1))
+    do
+        local list = subtree:add(proto, tvb(offset + 1, 15), "vertices: 3 item(s)")
+        local items = {}
+        for i = 1, 3 do
+            items[i] = select(2, struct_arrays.Vertex.dissect(tvb, offset + 1 + (i - 1) * 5, list, "[" .. (i - 1) .. "]"))
+        end
+        field_values["vertices"] = items
+    end
+
+    return 16, field_values
+end
+proto.fields = fields
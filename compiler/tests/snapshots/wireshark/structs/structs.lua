@@ -0,0 +1,91 @@
+-- Wireshark dissector for the structs protocol.
+structs = structs or {}
+local proto = Proto("structs", "structs protocol")
+structs.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+structs.Numbers = { size = 14 }
+fields["structs.numbers.u_a"] = ProtoField.uint32("structs.numbers.u_a", "u_a", base.DEC, nil)
+fields["structs.numbers.a"] = ProtoField.int32("structs.numbers.a", "a", base.DEC)
+fields["structs.numbers.u_b"] = ProtoField.uint16("structs.numbers.u_b", "u_b", base.DEC, nil)
+fields["structs.numbers.b"] = ProtoField.int16("structs.numbers.b", "b", base.DEC)
+fields["structs.numbers.u_c"] = ProtoField.uint8("structs.numbers.u_c", "u_c", base.DEC, nil)
+fields["structs.numbers.c"] = ProtoField.int8("structs.numbers.c", "c", base.DEC)
+
+function structs.Numbers.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 14), label .. ": Numbers")
+    local field_values = {}
+    field_values["u_a"] = read_bits(tvb(offset + 0, 4), true, 0, 0xffffffff)
+    subtree:add_le(fields["structs.numbers.u_a"], tvb(offset + 0, 4))
+    field_values["a"] = read_bits(tvb(offset + 4, 4), true, 0, 0xffffffff)
+    subtree:add_le(fields["structs.numbers.a"], tvb(offset + 4, 4))
+    field_values["u_b"] = read_bits(tvb(offset + 8, 2), true, 0, 0xffff)
+    subtree:add_le(fields["structs.numbers.u_b"], tvb(offset + 8, 2))
+    field_values["b"] = read_bits(tvb(offset + 10, 2), true, 0, 0xffff)
+    subtree:add_le(fields["structs.numbers.b"], tvb(offset + 10, 2))
+    field_values["u_c"] = read_bits(tvb(offset + 12, 1), true, 0, 0xff)
+    subtree:add_le(fields["structs.numbers.u_c"], tvb(offset + 12, 1))
+    field_values["c"] = read_bits(tvb(offset + 13, 1), true, 0, 0xff)
+    subtree:add_le(fields["structs.numbers.c"], tvb(offset + 13, 1))
+
+    return 14, field_values
+end
+structs.Flags = { size = 15 }
+fields["structs.flags.a"] = ProtoField.bool("structs.flags.a", "a")
+fields["structs.flags.b"] = ProtoField.bool("structs.flags.b", "b")
+fields["structs.flags.c"] = ProtoField.bool("structs.flags.c", "c")
+fields["structs.flags.d"] = ProtoField.bool("structs.flags.d", "d")
+
+function structs.Flags.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 15), label .. ": Flags")
+    local field_values = {}
+    field_values["a"] = read_bits(tvb(offset + 0, 1), true, 0, 0xff)
+    subtree:add_le(fields["structs.flags.a"], tvb(offset + 0, 1))
+    field_values["b"] = read_bits(tvb(offset + 1, 2), true, 0, 0xffff)
+    subtree:add_le(fields["structs.flags.b"], tvb(offset + 1, 2))
+    field_values["c"] = read_bits(tvb(offset + 3, 4), true, 0, 0xffffffff)
+    subtree:add_le(fields["structs.flags.c"], tvb(offset + 3, 4))
+    field_values["d"] = read_bits(tvb(offset + 7, 8), true, 0, UInt64.fromhex("ffffffffffffffff"))
+    subtree:add_le(fields["structs.flags.d"], tvb(offset + 7, 8))
+
+    return 15, field_values
+end
+structs.Floats = { size = 12 }
+fields["structs.floats.a"] = ProtoField.float("structs.floats.a", "a")
+fields["structs.floats.b"] = ProtoField.double("structs.floats.b", "b")
+
+function structs.Floats.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 12), label .. ": Floats")
+    local field_values = {}
+    field_values["a"] = read_bits(tvb(offset + 0, 4), true, 0, 0xffffffff)
+    subtree:add_le(fields["structs.floats.a"], tvb(offset + 0, 4))
+    field_values["b"] = read_bits(tvb(offset + 4, 8), true, 0, UInt64.fromhex("ffffffffffffffff"))
+    subtree:add_le(fields["structs.floats.b"], tvb(offset + 4, 8))
+
+    return 12, field_values
+end
+structs.Master = { size = 41 }
+
+function structs.Master.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 41), label .. ": Master")
+    local field_values = {}
+    field_values["floats"] = select(2, structs.Floats.dissect(tvb, offset + 0, subtree, "floats"))
+    field_values["nums"] = select(2, structs.Numbers.dissect(tvb, offset + 12, subtree, "nums"))
+    field_values["flags"] = select(2, structs.Flags.dissect(tvb, offset + 26, subtree, "flags"))
+
+    return 41, field_values
+end
+proto.fields = fields
@@ -0,0 +1,125 @@
+-- Wireshark dissector for the test protocol.
+test = test or {}
+local proto = Proto("test", "test protocol")
+test.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+test.IntContainer = { size = 4 }
+fields["test.intcontainer.test_int"] = ProtoField.uint32("test.intcontainer.test_int", "test_int", base.DEC, nil)
+
+function test.IntContainer.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 4), label .. ": IntContainer")
+    local field_values = {}
+    field_values["test_int"] = read_bits(tvb(offset + 0, 4), true, 0, 0xffffffff)
+    subtree:add_le(fields["test.intcontainer.test_int"], tvb(offset + 0, 4))
+
+    return 4, field_values
+end
+test.Test1 = {}
+fields["test.test1.s1"] = ProtoField.stringz("test.test1.s1", "s1")
+fields["test.test1.p1"] = ProtoField.uint32("test.test1.p1", "p1", base.DEC)
+
+function test.Test1.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Test1")
+    local field_values = {}
+    do
+        local size = tvb(offset):strsize()
+        subtree:add(fields["test.test1.s1"], tvb(offset, size))
+        offset = offset + size
+    end
+    subtree:add_le(fields["test.test1.p1"], tvb(offset, 4))
+    offset = offset + 4
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+test.Test = {}
+fields["test.test.s1"] = ProtoField.stringz("test.test.s1", "s1")
+fields["test.test.s2"] = ProtoField.string("test.test.s2", "s2")
+
+function test.Test.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Test")
+    local field_values = {}
+    do
+        local size = tvb(offset):strsize()
+        subtree:add(fields["test.test.s1"], tvb(offset, size))
+        offset = offset + size
+    end
+    do
+        local len = read_bits(tvb(offset, 1), true, 0, 0xff)
+        subtree:add(fields["test.test.s2"], tvb(offset + 1, len))
+        offset = offset + 1 + len
+    end
+    do
+        local present = tvb(offset, 1):uint() > 0
+        offset = offset + 1
+        if present then
+            do
+                local size, v = test.Test1.dissect(tvb, offset, subtree, "p1")
+                field_values["p1"] = v
+                offset = offset + size
+            end
+
+        end
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+proto.fields = fields
+test.messages = {
+    "Test1",
+    "Test",
+
+}
+test.ports = test.ports or {}
+local message_choices = {
+    { 1, "Test1", 1 },
+    { 2, "Test", 2 },
+
+}
+proto.prefs.message = Pref.enum("Message", 1, "Top-level message decoded on the configured ports", message_choices, false)
+proto.prefs.udp_port = Pref.uint("UDP port", 0, "UDP port to decode (0 to disable)")
+proto.prefs.tcp_port = Pref.uint("TCP port", 0, "TCP port to decode (0 to disable)")
+function test.register_port(table_name, port, message)
+    test.ports[port] = message
+    DissectorTable.get(table_name):add(port, proto)
+end
+local current_ports = {}
+function proto.prefs_changed()
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):remove(port, proto)
+    end
+    current_ports = {}
+    if proto.prefs.udp_port ~= 0 then
+        current_ports["udp.port"] = proto.prefs.udp_port
+    end
+    if proto.prefs.tcp_port ~= 0 then
+        current_ports["tcp.port"] = proto.prefs.tcp_port
+    end
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):add(port, proto)
+    end
+end
+function proto.dissector(tvb, pinfo, tree)
+    local message = test.ports[pinfo.match_uint] or test.messages[proto.prefs.message]
+    pinfo.cols.protocol = "test"
+    pinfo.cols.info = message
+    local size = test[message].dissect(tvb, 0, tree, message)
+    return size
+end
@@ -0,0 +1,174 @@
+-- Wireshark dissector for the unions protocol.
+unions = unions or {}
+local proto = Proto("unions", "unions protocol")
+unions.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+unions.Value = {}
+function unions.Value.dissect(tvb, offset, tree, label, discriminant)
+    local discriminant_value = discriminant["type"]
+    if discriminant_value == 0 then
+        tree:add(proto, tvb(offset, 0), label .. ": Null")
+        return 0, nil
+    end
+    if discriminant_value == 1 then
+        return values.ValueString.dissect(tvb, offset, tree, label .. " (String)")
+    end
+    if discriminant_value == 2 then
+        return values.ValueInt8.dissect(tvb, offset, tree, label .. " (Int8)")
+    end
+    if discriminant_value == 3 then
+        return values.ValueInt16.dissect(tvb, offset, tree, label .. " (Int16)")
+    end
+    if discriminant_value == 4 then
+        return values.ValueInt32.dissect(tvb, offset, tree, label .. " (Int32)")
+    end
+    if discriminant_value == 5 then
+        return values.ValueInt64.dissect(tvb, offset, tree, label .. " (Int64)")
+    end
+    if discriminant_value == 6 then
+        return values.ValueUInt8.dissect(tvb, offset, tree, label .. " (UInt8)")
+    end
+    if discriminant_value == 7 then
+        return values.ValueUInt16.dissect(tvb, offset, tree, label .. " (UInt16)")
+    end
+    if discriminant_value == 8 then
+        return values.ValueUInt32.dissect(tvb, offset, tree, label .. " (UInt32)")
+    end
+    if discriminant_value == 9 then
+        return values.ValueUInt64.dissect(tvb, offset, tree, label .. " (UInt64)")
+    end
+    if discriminant_value == 10 then
+        return values.ValueFloat.dissect(tvb, offset, tree, label .. " (Float)")
+    end
+    if discriminant_value == 11 then
+        return values.ValueDouble.dissect(tvb, offset, tree, label .. " (Double)")
+    end
+
+    tree:add_expert_info(PI_MALFORMED, PI_ERROR, "Invalid union discriminant " .. discriminant_value)
+    return 0, nil
+end
+unions.PartialValue = {}
+function unions.PartialValue.dissect(tvb, offset, tree, label, discriminant)
+    local discriminant_value = discriminant["type"]
+    if discriminant_value == 0 then
+        tree:add(proto, tvb(offset, 0), label .. ": Null")
+        return 0, nil
+    end
+    if discriminant_value == 1 then
+        return values.ValueString.dissect(tvb, offset, tree, label .. " (String)")
+    end
+    if discriminant_value == 2 then
+        return values.ValueInt8.dissect(tvb, offset, tree, label .. " (Int8)")
+    end
+
+    tree:add_expert_info(PI_MALFORMED, PI_ERROR, "Invalid union discriminant " .. discriminant_value)
+    return 0, nil
+end
+unions.Item = {}
+fields["unions.item.name"] = ProtoField.stringz("unions.item.name", "name")
+
+function unions.Item.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": Item")
+    local field_values = {}
+    do
+        local size, v = enums.Header.dissect(tvb, offset, subtree, "header")
+        field_values["header"] = v
+        offset = offset + size
+    end
+    do
+        local size = tvb(offset):strsize()
+        subtree:add(fields["unions.item.name"], tvb(offset, size))
+        offset = offset + size
+    end
+    do
+        local size, v = unions.Value.dissect(tvb, offset, subtree, "value", field_values["header"])
+        field_values["value"] = v
+        offset = offset + size
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+unions.PartialItem = {}
+fields["unions.partialitem.name"] = ProtoField.stringz("unions.partialitem.name", "name")
+
+function unions.PartialItem.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": PartialItem")
+    local field_values = {}
+    do
+        local size, v = enums.Header.dissect(tvb, offset, subtree, "header")
+        field_values["header"] = v
+        offset = offset + size
+    end
+    do
+        local size = tvb(offset):strsize()
+        subtree:add(fields["unions.partialitem.name"], tvb(offset, size))
+        offset = offset + size
+    end
+    do
+        local size, v = unions.PartialValue.dissect(tvb, offset, subtree, "value", field_values["header"])
+        field_values["value"] = v
+        offset = offset + size
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+proto.fields = fields
+unions.messages = {
+    "Item",
+    "PartialItem",
+
+}
+unions.ports = unions.ports or {}
+local message_choices = {
+    { 1, "Item", 1 },
+    { 2, "PartialItem", 2 },
+
+}
+proto.prefs.message = Pref.enum("Message", 1, "Top-level message decoded on the configured ports", message_choices, false)
+proto.prefs.udp_port = Pref.uint("UDP port", 0, "UDP port to decode (0 to disable)")
+proto.prefs.tcp_port = Pref.uint("TCP port", 0, "TCP port to decode (0 to disable)")
+function unions.register_port(table_name, port, message)
+    unions.ports[port] = message
+    DissectorTable.get(table_name):add(port, proto)
+end
+local current_ports = {}
+function proto.prefs_changed()
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):remove(port, proto)
+    end
+    current_ports = {}
+    if proto.prefs.udp_port ~= 0 then
+        current_ports["udp.port"] = proto.prefs.udp_port
+    end
+    if proto.prefs.tcp_port ~= 0 then
+        current_ports["tcp.port"] = proto.prefs.tcp_port
+    end
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):add(port, proto)
+    end
+end
+function proto.dissector(tvb, pinfo, tree)
+    local message = unions.ports[pinfo.match_uint] or unions.messages[proto.prefs.message]
+    pinfo.cols.protocol = "unions"
+    pinfo.cols.info = message
+    local size = unions[message].dissect(tvb, 0, tree, message)
+    return size
+end
@@ -0,0 +1,63 @@
+-- Wireshark dissector for the unions2 protocol.
+unions2 = unions2 or {}
+local proto = Proto("unions2", "unions2 protocol")
+unions2.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+unions2.Test = {
+    [0] = "V1",
+    [1] = "V2",
+
+}
+unions2.Header = { size = 1 }
+fields["unions2.header.test"] = ProtoField.uint8("unions2.header.test", "test", base.DEC, { [0] = "V1", [1] = "V2" }, 0x1)
+fields["unions2.header.test2"] = ProtoField.int8("unions2.header.test2", "test2", base.DEC, nil, 0xfe)
+
+function unions2.Header.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 1), label .. ": Header")
+    local field_values = {}
+    field_values["test"] = read_bits(tvb(offset + 0, 1), true, 0, 0x1)
+    subtree:add_le(fields["unions2.header.test"], tvb(offset + 0, 1))
+    field_values["test2"] = read_bits(tvb(offset + 0, 1), true, 1, 0x7f)
+    subtree:add_le(fields["unions2.header.test2"], tvb(offset + 0, 1))
+
+    return 1, field_values
+end
+unions2.Header2 = { size = 1 }
+
+function unions2.Header2.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 1), label .. ": Header2")
+    local field_values = {}
+    field_values["inner"] = select(2, unions2.Header.dissect(tvb, offset + 0, subtree, "inner"))
+
+    return 1, field_values
+end
+unions2.NestedDiscriminant = {}
+function unions2.NestedDiscriminant.dissect(tvb, offset, tree, label, discriminant)
+    local discriminant_value = discriminant["inner"]["test"]
+    if discriminant_value == 0 then
+        tree:add(proto, tvb(offset, 0), label .. ": V1")
+        return 0, nil
+    end
+    if discriminant_value == 1 then
+        tree:add(proto, tvb(offset, 0), label .. ": V2")
+        return 0, nil
+    end
+
+    tree:add_expert_info(PI_MALFORMED, PI_ERROR, "Invalid union discriminant " .. discriminant_value)
+    return 0, nil
+end
+proto.fields = fields
@@ -0,0 +1,185 @@
+-- Wireshark dissector for the values protocol.
+values = values or {}
+local proto = Proto("values", "values protocol")
+values.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+values.ValueInt8 = { size = 1 }
+fields["values.valueint8.data"] = ProtoField.int8("values.valueint8.data", "data", base.DEC)
+
+function values.ValueInt8.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 1), label .. ": ValueInt8")
+    local field_values = {}
+    field_values["data"] = read_bits(tvb(offset + 0, 1), true, 0, 0xff)
+    subtree:add_le(fields["values.valueint8.data"], tvb(offset + 0, 1))
+
+    return 1, field_values
+end
+values.ValueInt16 = { size = 2 }
+fields["values.valueint16.data"] = ProtoField.int16("values.valueint16.data", "data", base.DEC)
+
+function values.ValueInt16.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 2), label .. ": ValueInt16")
+    local field_values = {}
+    field_values["data"] = read_bits(tvb(offset + 0, 2), true, 0, 0xffff)
+    subtree:add_le(fields["values.valueint16.data"], tvb(offset + 0, 2))
+
+    return 2, field_values
+end
+values.ValueInt32 = { size = 4 }
+fields["values.valueint32.data"] = ProtoField.int32("values.valueint32.data", "data", base.DEC)
+
+function values.ValueInt32.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 4), label .. ": ValueInt32")
+    local field_values = {}
+    field_values["data"] = read_bits(tvb(offset + 0, 4), true, 0, 0xffffffff)
+    subtree:add_le(fields["values.valueint32.data"], tvb(offset + 0, 4))
+
+    return 4, field_values
+end
+values.ValueInt64 = { size = 8 }
+fields["values.valueint64.data"] = ProtoField.int64("values.valueint64.data", "data", base.DEC)
+
+function values.ValueInt64.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 8), label .. ": ValueInt64")
+    local field_values = {}
+    field_values["data"] = read_bits(tvb(offset + 0, 8), true, 0, UInt64.fromhex("ffffffffffffffff"))
+    subtree:add_le(fields["values.valueint64.data"], tvb(offset + 0, 8))
+
+    return 8, field_values
+end
+values.ValueUInt8 = { size = 1 }
+fields["values.valueuint8.data"] = ProtoField.uint8("values.valueuint8.data", "data", base.DEC, nil)
+
+function values.ValueUInt8.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 1), label .. ": ValueUInt8")
+    local field_values = {}
+    field_values["data"] = read_bits(tvb(offset + 0, 1), true, 0, 0xff)
+    subtree:add_le(fields["values.valueuint8.data"], tvb(offset + 0, 1))
+
+    return 1, field_values
+end
+values.ValueUInt16 = { size = 2 }
+fields["values.valueuint16.data"] = ProtoField.uint16("values.valueuint16.data", "data", base.DEC, nil)
+
+function values.ValueUInt16.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 2), label .. ": ValueUInt16")
+    local field_values = {}
+    field_values["data"] = read_bits(tvb(offset + 0, 2), true, 0, 0xffff)
+    subtree:add_le(fields["values.valueuint16.data"], tvb(offset + 0, 2))
+
+    return 2, field_values
+end
+values.ValueUInt32 = { size = 4 }
+fields["values.valueuint32.data"] = ProtoField.uint32("values.valueuint32.data", "data", base.DEC, nil)
+
+function values.ValueUInt32.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 4), label .. ": ValueUInt32")
+    local field_values = {}
+    field_values["data"] = read_bits(tvb(offset + 0, 4), true, 0, 0xffffffff)
+    subtree:add_le(fields["values.valueuint32.data"], tvb(offset + 0, 4))
+
+    return 4, field_values
+end
+values.ValueUInt64 = { size = 8 }
+fields["values.valueuint64.data"] = ProtoField.uint64("values.valueuint64.data", "data", base.DEC, nil)
+
+function values.ValueUInt64.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 8), label .. ": ValueUInt64")
+    local field_values = {}
+    field_values["data"] = read_bits(tvb(offset + 0, 8), true, 0, UInt64.fromhex("ffffffffffffffff"))
+    subtree:add_le(fields["values.valueuint64.data"], tvb(offset + 0, 8))
+
+    return 8, field_values
+end
+values.ValueFloat = { size = 4 }
+fields["values.valuefloat.data"] = ProtoField.float("values.valuefloat.data", "data")
+
+function values.ValueFloat.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 4), label .. ": ValueFloat")
+    local field_values = {}
+    field_values["data"] = read_bits(tvb(offset + 0, 4), true, 0, 0xffffffff)
+    subtree:add_le(fields["values.valuefloat.data"], tvb(offset + 0, 4))
+
+    return 4, field_values
+end
+values.ValueDouble = { size = 8 }
+fields["values.valuedouble.data"] = ProtoField.double("values.valuedouble.data", "data")
+
+function values.ValueDouble.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 8), label .. ": ValueDouble")
+    local field_values = {}
+    field_values["data"] = read_bits(tvb(offset + 0, 8), true, 0, UInt64.fromhex("ffffffffffffffff"))
+    subtree:add_le(fields["values.valuedouble.data"], tvb(offset + 0, 8))
+
+    return 8, field_values
+end
+values.ValueString = {}
+fields["values.valuestring.data"] = ProtoField.stringz("values.valuestring.data", "data")
+
+function values.ValueString.dissect(tvb, offset, tree, label)
+    local start = offset
+    local subtree = tree:add(proto, tvb(offset, 0), label .. ": ValueString")
+    local field_values = {}
+    do
+        local size = tvb(offset):strsize()
+        subtree:add(fields["values.valuestring.data"], tvb(offset, size))
+        offset = offset + size
+    end
+
+    subtree:set_len(offset - start)
+    return offset - start, field_values
+end
+proto.fields = fields
+values.messages = {
+    "ValueString",
+
+}
+values.ports = values.ports or {}
+local message_choices = {
+    { 1, "ValueString", 1 },
+
+}
+proto.prefs.message = Pref.enum("Message", 1, "Top-level message decoded on the configured ports", message_choices, false)
+proto.prefs.udp_port = Pref.uint("UDP port", 0, "UDP port to decode (0 to disable)")
+proto.prefs.tcp_port = Pref.uint("TCP port", 0, "TCP port to decode (0 to disable)")
+function values.register_port(table_name, port, message)
+    values.ports[port] = message
+    DissectorTable.get(table_name):add(port, proto)
+end
+local current_ports = {}
+function proto.prefs_changed()
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):remove(port, proto)
+    end
+    current_ports = {}
+    if proto.prefs.udp_port ~= 0 then
+        current_ports["udp.port"] = proto.prefs.udp_port
+    end
+    if proto.prefs.tcp_port ~= 0 then
+        current_ports["tcp.port"] = proto.prefs.tcp_port
+    end
+    for table_name, port in pairs(current_ports) do
+        DissectorTable.get(table_name):add(port, proto)
+    end
+end
+function proto.dissector(tvb, pinfo, tree)
+    local message = values.ports[pinfo.match_uint] or values.messages[proto.prefs.message]
+    pinfo.cols.protocol = "values"
+    pinfo.cols.info = message
+    local size = values[message].dissect(tvb, 0, tree, message)
+    return size
+end
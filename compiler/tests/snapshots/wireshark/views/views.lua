@@ -0,0 +1,79 @@
+-- Wireshark dissector for the views protocol.
+views = views or {}
+local proto = Proto("views", "views protocol")
+views.proto = proto
+local fields = {}
+local function read_bits(range, le, shift, mask)
+    local value
+    if le then
+        value = range:le_uint64()
+    else
+        value = range:uint64()
+    end
+    if shift >= 0 then
+        value = value:rshift(shift)
+    else
+        value = value:lshift(-shift)
+    end
+    return value:band(mask):tonumber()
+end
+views.Level = {
+    [0] = "Low",
+    [1] = "Mid",
+    [2] = "High",
+
+}
+views.Floats = { size = 3 }
+fields["views.floats.a"] = ProtoField.uint24("views.floats.a", "a", base.DEC, nil, 0x1ffff)
+fields["views.floats.a.value"] = ProtoField.double("views.floats.a.value", "a (value)")
+fields["views.floats.b"] = ProtoField.uint8("views.floats.b", "b", base.DEC, nil, 0xfe)
+fields["views.floats.b.value"] = ProtoField.double("views.floats.b.value", "b (value)")
+
+function views.Floats.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 3), label .. ": Floats")
+    local field_values = {}
+    field_values["a"] = read_bits(tvb(offset + 0, 3), true, 0, 0x1ffff)
+    subtree:add_le(fields["views.floats.a"], tvb(offset + 0, 3))
+    subtree:add(fields["views.floats.a.value"], tvb(offset + 0, 3), field_values["a"] * 7.629452739355007e-5 + 0.0)
+    field_values["b"] = read_bits(tvb(offset + 2, 1), true, 1, 0x7f)
+    subtree:add_le(fields["views.floats.b"], tvb(offset + 2, 1))
+    subtree:add(fields["views.floats.b.value"], tvb(offset + 2, 1), field_values["b"] * 0.1 + 0.0)
+
+    return 3, field_values
+end
+views.Access = { size = 4 }
+fields["views.access.owner"] = ProtoField.uint8("views.access.owner", "owner", base.HEX, nil, 0x7)
+fields["views.access.features"] = ProtoField.uint16("views.access.features", "features", base.HEX, nil, 0xfff8)
+fields["views.access.group"] = ProtoField.uint16("views.access.group", "group", base.HEX, nil)
+
+function views.Access.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 4), label .. ": Access")
+    local field_values = {}
+    field_values["owner"] = read_bits(tvb(offset + 0, 1), true, 0, 0x7)
+    subtree:add_le(fields["views.access.owner"], tvb(offset + 0, 1))
+    field_values["features"] = read_bits(tvb(offset + 0, 2), true, 3, 0x1fff)
+    subtree:add_le(fields["views.access.features"], tvb(offset + 0, 2))
+    field_values["group"] = read_bits(tvb(offset + 2, 2), true, 0, 0xffff)
+    subtree:add_le(fields["views.access.group"], tvb(offset + 2, 2))
+
+    return 4, field_values
+end
+views.Readings = { size = 14 }
+fields["views.readings.levels"] = ProtoField.bytes("views.readings.levels", "levels")
+fields["views.readings.values"] = ProtoField.bytes("views.readings.values", "values")
+fields["views.readings.deltas"] = ProtoField.bytes("views.readings.deltas", "deltas")
+fields["views.readings.mask"] = ProtoField.bytes("views.readings.mask", "mask")
+fields["views.readings.gains"] = ProtoField.bytes("views.readings.gains", "gains")
+
+function views.Readings.dissect(tvb, offset, tree, label)
+    local subtree = tree:add(proto, tvb(offset, 14), label .. ": Readings")
+    local field_values = {}
+    subtree:add(fields["views.readings.levels"], tvb(offset + 0, 2))
+    subtree:add(fields["views.readings.values"], tvb(offset + 1, 7))
+    subtree:add(fields["views.readings.deltas"], tvb(offset + 7, 3))
+    subtree:add(fields["views.readings.mask"], tvb(offset + 9, 1))
+    subtree:add(fields["views.readings.gains"], tvb(offset + 10, 4))
+
+    return 14, field_values
+end
+proto.fields = fields
@@ -0,0 +1,112 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::{Path, PathBuf};
+use bp3d_protoc::{Error, Loader};
+use bp3d_protoc::compiler::Error as CompilerError;
+use bp3d_protoc::util::SimpleImportSolver;
+
+fn testprog(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../testprog/src").join(format!("{}.json5", name))
+}
+
+/// Compiles the given messages against the unions testprog protocol.
+fn compile(name: &str, messages: &str) -> Result<(), Error> {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("{}.json5", name));
+    std::fs::write(&path, format!(r#"{{
+        name: "{}",
+        imports: [
+            {{ protocol: "enums", type: "Header" }},
+            {{ protocol: "unions", type: "PartialValue" }},
+            {{ protocol: "unions", type: "PartialItem" }}
+        ],
+        messages: [{}]
+    }}"#, name, messages)).unwrap();
+    let mut loader = Loader::new();
+    for import in ["enums", "values", "unions"] {
+        loader.import(testprog(import), import).unwrap();
+    }
+    loader.load(path).unwrap();
+    loader.compile(SimpleImportSolver::new(".")).map(|_| ())
+}
+
+fn assert_embedded(res: Result<(), Error>) {
+    match res {
+        Err(Error::Compiler(CompilerError::EmbeddedUnknownUnion(name))) => assert_eq!(name, "PartialItem"),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("embedded unknown union accepted")
+    }
+}
+
+#[test]
+fn outermost() {
+    compile("unknown_outermost", r#"{
+        name: "Msg",
+        fields: [
+            { name: "header", info: { type: "item", item_type: "Header" } },
+            { name: "value", info: { type: "union", on: "header", item_type: "PartialValue" } }
+        ]
+    }"#).unwrap();
+}
+
+#[test]
+fn not_last() {
+    let res = compile("unknown_not_last", r#"{
+        name: "Msg",
+        fields: [
+            { name: "header", info: { type: "item", item_type: "Header" } },
+            { name: "value", info: { type: "union", on: "header", item_type: "PartialValue" } },
+            { name: "id", info: { type: "item", item_type: "uint8" } }
+        ]
+    }"#);
+    match res {
+        Err(Error::Compiler(CompilerError::UnknownUnionNotLast(name))) => assert_eq!(name, "value"),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("unknown union followed by a field accepted")
+    }
+}
+
+#[test]
+fn list_item() {
+    assert_embedded(compile("unknown_list_item", r#"{
+        name: "Msg",
+        fields: [
+            { name: "items", info: { type: "list", max_len: 255, item_type: "PartialItem" } }
+        ]
+    }"#));
+}
+
+#[test]
+fn nested() {
+    assert_embedded(compile("unknown_nested", r#"{
+        name: "Msg",
+        fields: [
+            { name: "item", info: { type: "item", item_type: "PartialItem" } }
+        ]
+    }"#));
+}
@@ -26,9 +26,10 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 use bytesutil::{ReadBytes, WriteBytes};
-use crate::codec::ByteCodec;
+use crate::codec::{BitAccess, ByteCodec};
+use crate::util::ToUsize;
 
 pub struct ArrayCodec<B, Item, C, const ITEM_BIT_SIZE: usize> {
     buffer: B,
@@ -86,3 +87,202 @@ impl<B: AsRef<[u8]>, C> AsRef<[u8]> for ArrayCodec<B, u8, C, 8> {
         self.buffer.as_ref()
     }
 }
+
+#[cfg(feature = "serde")]
+impl<B: AsRef<[u8]>, Item: ReadBytes + serde::Serialize, C: ByteCodec, const ITEM_BIT_SIZE: usize> serde::Serialize for ArrayCodec<B, Item, C, ITEM_BIT_SIZE> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_raw())
+    }
+}
+
+/// An array of `LEN` items of `ITEM_BIT_SIZE` bits packed without padding, the first item
+/// starting `BIT_OFFSET` bits into the buffer.
+pub struct BitArrayCodec<B, Item, C, const BIT_OFFSET: usize, const ITEM_BIT_SIZE: usize, const LEN: usize> {
+    buffer: B,
+    useless: PhantomData<Item>,
+    useless1: PhantomData<C>
+}
+
+impl<B, Item, C, const BIT_OFFSET: usize, const ITEM_BIT_SIZE: usize, const LEN: usize> BitArrayCodec<B, Item, C, BIT_OFFSET, ITEM_BIT_SIZE, LEN> {
+    pub fn new(buffer: B) -> Self {
+        Self {
+            buffer,
+            useless: PhantomData,
+            useless1: PhantomData
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        LEN
+    }
+
+    pub fn is_empty(&self) -> bool {
+        LEN == 0
+    }
+
+    fn bit_offset(index: usize) -> usize {
+        assert!(index < LEN, "index {} out of bounds for length {}", index, LEN);
+        BIT_OFFSET + index * ITEM_BIT_SIZE
+    }
+}
+
+impl<B: AsRef<[u8]>, Item: ToUsize, C: BitAccess, const BIT_OFFSET: usize, const ITEM_BIT_SIZE: usize, const LEN: usize> BitArrayCodec<B, Item, C, BIT_OFFSET, ITEM_BIT_SIZE, LEN> {
+    pub fn get_raw(&self, index: usize) -> Item {
+        let value = C::read_bits(self.buffer.as_ref(), Self::bit_offset(index), ITEM_BIT_SIZE);
+        Item::from_u64(value)
+    }
+
+    pub fn iter_raw(&self) -> impl Iterator<Item = Item> + '_ {
+        (0..LEN).map(|i| self.get_raw(i))
+    }
+}
+
+impl<B: AsMut<[u8]>, Item: ToUsize, C: BitAccess, const BIT_OFFSET: usize, const ITEM_BIT_SIZE: usize, const LEN: usize> BitArrayCodec<B, Item, C, BIT_OFFSET, ITEM_BIT_SIZE, LEN> {
+    pub fn set_raw(&mut self, index: usize, value: Item) -> &mut Self {
+        C::write_bits(self.buffer.as_mut(), Self::bit_offset(index), ITEM_BIT_SIZE, value.to_u64());
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: AsRef<[u8]>, Item: ToUsize + serde::Serialize, C: BitAccess, const BIT_OFFSET: usize, const ITEM_BIT_SIZE: usize, const LEN: usize> serde::Serialize for BitArrayCodec<B, Item, C, BIT_OFFSET, ITEM_BIT_SIZE, LEN> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_raw())
+    }
+}
+
+/// Raw access to the items of an array codec.
+pub trait RawArray {
+    type Raw;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get_raw(&self, index: usize) -> Self::Raw;
+}
+
+/// Raw write access to the items of an array codec.
+pub trait RawArrayMut: RawArray {
+    fn set_raw(&mut self, index: usize, value: Self::Raw);
+}
+
+impl<B: AsRef<[u8]>, Item: ReadBytes, C: ByteCodec, const ITEM_BIT_SIZE: usize> RawArray for ArrayCodec<B, Item, C, ITEM_BIT_SIZE> {
+    type Raw = Item;
+
+    fn len(&self) -> usize {
+        ArrayCodec::len(self)
+    }
+
+    fn get_raw(&self, index: usize) -> Item {
+        ArrayCodec::get_raw(self, index)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>, Item: ReadBytes + WriteBytes, C: ByteCodec, const ITEM_BIT_SIZE: usize> RawArrayMut for ArrayCodec<B, Item, C, ITEM_BIT_SIZE> {
+    fn set_raw(&mut self, index: usize, value: Item) {
+        ArrayCodec::set_raw(self, index, value);
+    }
+}
+
+impl<B: AsRef<[u8]>, Item: ToUsize, C: BitAccess, const BIT_OFFSET: usize, const ITEM_BIT_SIZE: usize, const LEN: usize> RawArray for BitArrayCodec<B, Item, C, BIT_OFFSET, ITEM_BIT_SIZE, LEN> {
+    type Raw = Item;
+
+    fn len(&self) -> usize {
+        LEN
+    }
+
+    fn get_raw(&self, index: usize) -> Item {
+        BitArrayCodec::get_raw(self, index)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>, Item: ToUsize, C: BitAccess, const BIT_OFFSET: usize, const ITEM_BIT_SIZE: usize, const LEN: usize> RawArrayMut for BitArrayCodec<B, Item, C, BIT_OFFSET, ITEM_BIT_SIZE, LEN> {
+    fn set_raw(&mut self, index: usize, value: Item) {
+        BitArrayCodec::set_raw(self, index, value);
+    }
+}
+
+/// An array codec whose items are converted to `Out` when read and from `In` when written.
+pub struct ViewArray<A: RawArray, Out, In = Out> {
+    array: A,
+    from_raw: fn(A::Raw) -> Out,
+    to_raw: fn(In) -> A::Raw
+}
+
+impl<A: RawArray, Out, In> ViewArray<A, Out, In> {
+    pub fn new(array: A, from_raw: fn(A::Raw) -> Out, to_raw: fn(In) -> A::Raw) -> Self {
+        Self {
+            array,
+            from_raw,
+            to_raw
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.array.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Out {
+        (self.from_raw)(self.array.get_raw(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Out> + '_ {
+        (0..self.len()).map(|i| self.get(i))
+    }
+
+    pub fn into_raw(self) -> A {
+        self.array
+    }
+}
+
+impl<A: RawArrayMut, Out, In> ViewArray<A, Out, In> {
+    pub fn set(&mut self, index: usize, value: In) -> &mut Self {
+        self.array.set_raw(index, (self.to_raw)(value));
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A: RawArray, Out: serde::Serialize, In> serde::Serialize for ViewArray<A, Out, In> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codec::{BitArrayCodec, BitCodecBE, BitCodecLE, ViewArray};
+
+    #[test]
+    fn bit_packed() {
+        let mut buffer = [0u8; 7];
+        let mut array = BitArrayCodec::<_, u8, BitCodecLE, 4, 5, 10>::new(&mut buffer[..]);
+        for i in 0..10 {
+            array.set_raw(i, i as u8 * 3);
+        }
+        assert_eq!(array.iter_raw().collect::<Vec<_>>(), [0, 3, 6, 9, 12, 15, 18, 21, 24, 27]);
+        assert_eq!(buffer[0] & 0x0F, 0);
+        let mut buffer = [0u8; 2];
+        let mut array = BitArrayCodec::<_, u8, BitCodecBE, 4, 4, 3>::new(&mut buffer[..]);
+        array.set_raw(0, 0xA).set_raw(1, 0xB).set_raw(2, 0xC);
+        assert_eq!(array.get_raw(1), 0xB);
+        assert_eq!(buffer, [0x0A, 0xBC]);
+    }
+
+    #[test]
+    fn view() {
+        let mut buffer = [0u8; 2];
+        let mut array = ViewArray::new(BitArrayCodec::<_, u8, BitCodecLE, 0, 4, 4>::new(&mut buffer[..]),
+            |raw| if raw > 7 { raw as i8 - 16 } else { raw as i8 }, |value| value as u8);
+        array.set(0, -8).set(1, 7).set(3, -1);
+        assert_eq!(array.iter().collect::<Vec<_>>(), [-8, 7, 0, -1]);
+        assert_eq!(array.into_raw().get_raw(3), 0xF);
+    }
+}
@@ -26,7 +26,7 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::ops::{BitAnd, BitOr, Shl, Shr};
+use core::ops::{BitAnd, BitOr, Shl, Shr};
 use bytesutil::{ReadBytes, WriteBytes};
 use crate::util::ToUsize;
 
@@ -66,6 +66,34 @@ pub trait BitCodec {
     }
 }
 
+mod private {
+    pub trait Sealed {}
+}
+
+/// Bit level access at offsets only known at runtime, as used by
+/// [BitArrayCodec](crate::codec::BitArrayCodec).
+///
+/// This trait is sealed and only implemented by [BitCodecLE] and [BitCodecBE].
+pub trait BitAccess: BitCodec + private::Sealed {
+    /// Reads a value of `bit_size` bits (at most 64) starting `bit_offset` bits into the buffer.
+    fn read_bits(buffer: &[u8], bit_offset: usize, bit_size: usize) -> u64;
+
+    /// Writes a value of `bit_size` bits (at most 64) starting `bit_offset` bits into the buffer,
+    /// leaving the surrounding bits untouched.
+    fn write_bits(buffer: &mut [u8], bit_offset: usize, bit_size: usize, value: u64);
+}
+
+/// Returns the (shift from the least significant bit of the byte, length) of the next chunk of
+/// bits which fits in the byte containing bit `pos`.
+fn next_chunk(pos: usize, remaining: usize) -> (usize, usize) {
+    let shift = pos % 8;
+    (shift, usize::min(8 - shift, remaining))
+}
+
+fn chunk_mask(len: usize) -> u8 {
+    ((1u16 << len) - 1) as u8
+}
+
 pub struct BitCodecLE;
 pub struct BitCodecBE;
 
@@ -86,6 +114,36 @@ impl BitCodec for BitCodecLE {
     }
 }
 
+impl private::Sealed for BitCodecLE {}
+
+impl BitAccess for BitCodecLE {
+    fn read_bits(buffer: &[u8], bit_offset: usize, bit_size: usize) -> u64 {
+        let mut value = 0;
+        let mut done = 0;
+        while done < bit_size {
+            let pos = bit_offset + done;
+            let (shift, len) = next_chunk(pos, bit_size - done);
+            let bits = (buffer[pos / 8] >> shift) & chunk_mask(len);
+            value |= (bits as u64) << done;
+            done += len;
+        }
+        value
+    }
+
+    fn write_bits(buffer: &mut [u8], bit_offset: usize, bit_size: usize, value: u64) {
+        let mut done = 0;
+        while done < bit_size {
+            let pos = bit_offset + done;
+            let (shift, len) = next_chunk(pos, bit_size - done);
+            let mask = chunk_mask(len);
+            let bits = (value >> done) as u8 & mask;
+            let byte = &mut buffer[pos / 8];
+            *byte = (*byte & !(mask << shift)) | (bits << shift);
+            done += len;
+        }
+    }
+}
+
 impl BitCodec for BitCodecBE {
     unsafe fn read_aligned<T: ToUsize + ReadBytes + Shr<Output=T> + BitAnd<Output=T>, const BIT_OFFSET: usize, const BIT_SIZE: usize>(buffer: &[u8]) -> T {
         let mask: usize = (1 << BIT_SIZE) - 1;
@@ -103,9 +161,39 @@ impl BitCodec for BitCodecBE {
     }
 }
 
+impl private::Sealed for BitCodecBE {}
+
+impl BitAccess for BitCodecBE {
+    fn read_bits(buffer: &[u8], bit_offset: usize, bit_size: usize) -> u64 {
+        let mut value = 0;
+        let mut done = 0;
+        while done < bit_size {
+            let pos = bit_offset + done;
+            let (shift, len) = next_chunk(pos, bit_size - done);
+            let bits = (buffer[pos / 8] >> (8 - shift - len)) & chunk_mask(len);
+            value = (value << len) | bits as u64;
+            done += len;
+        }
+        value
+    }
+
+    fn write_bits(buffer: &mut [u8], bit_offset: usize, bit_size: usize, value: u64) {
+        let mut done = 0;
+        while done < bit_size {
+            let pos = bit_offset + done;
+            let (shift, len) = next_chunk(pos, bit_size - done);
+            let mask = chunk_mask(len);
+            let bits = (value >> (bit_size - done - len)) as u8 & mask;
+            let byte = &mut buffer[pos / 8];
+            *byte = (*byte & !(mask << (8 - shift - len))) | (bits << (8 - shift - len));
+            done += len;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::codec::{BitCodec, BitCodecBE, BitCodecLE};
+    use crate::codec::{BitAccess, BitCodec, BitCodecBE, BitCodecLE};
 
     #[test]
     fn little_endian() {
@@ -124,4 +212,25 @@ mod tests {
         BitCodecBE::write::<u16, 0, 12>(&mut buffer[0..2], 0xABF);
         assert_eq!(BitCodecBE::read::<u16, 0, 12>(&buffer[0..2]), 0xABF);
     }
+
+    #[test]
+    fn runtime_offsets() {
+        let buffer = [0xAB, 0xF0];
+        assert_eq!(BitCodecBE::read_bits(&buffer, 0, 12), 0xABF);
+        assert_eq!(BitCodecBE::read_bits(&buffer, 4, 8), 0xBF);
+        assert_eq!(BitCodecLE::read_bits(&buffer, 4, 8), 0x0A);
+        let mut buffer = [0xFF; 9];
+        BitCodecLE::write_bits(&mut buffer, 3, 64, 0x0123456789ABCDEF);
+        assert_eq!(BitCodecLE::read_bits(&buffer, 3, 64), 0x0123456789ABCDEF);
+        assert_eq!(BitCodecLE::read_bits(&buffer, 0, 3), 0x7);
+        assert_eq!(BitCodecLE::read_bits(&buffer, 67, 5), 0x1F);
+        BitCodecBE::write_bits(&mut buffer, 5, 7, 0x2A);
+        assert_eq!(BitCodecBE::read_bits(&buffer, 5, 7), 0x2A);
+        let mut buffer = [0x0, 0x0];
+        BitCodecBE::write_bits(&mut buffer, 0, 12, 0xABF);
+        assert_eq!(BitCodecBE::read::<u16, 0, 12>(&buffer), 0xABF);
+        let mut buffer = [0x0];
+        BitCodecLE::write_bits(&mut buffer, 4, 4, 0xF);
+        assert_eq!(BitCodecLE::read::<u8, 4, 4>(&buffer), 0xF);
+    }
 }
@@ -30,6 +30,6 @@ mod bytes;
 mod bits;
 mod arrays;
 
-pub use arrays::ArrayCodec;
+pub use arrays::{ArrayCodec, BitArrayCodec, RawArray, RawArrayMut, ViewArray};
 pub use bits::*;
 pub use bytes::*;
@@ -0,0 +1,91 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+use crate::framed::{encode_frame, frame_too_large, DEFAULT_MAX_FRAME_SIZE, LENGTH_SIZE};
+use crate::message::{Error, Result, WriteTo};
+
+/// A [Decoder]/[Encoder] pair for length prefixed messages, to be used with
+/// [Framed](tokio_util::codec::Framed) and friends.
+///
+/// Decoded items are the raw bytes of each message, as decoded messages borrow their buffer;
+/// use [FromSlice](crate::message::FromSlice) to read them.
+pub struct MessageCodec {
+    max_frame_size: usize
+}
+
+impl MessageCodec {
+    pub fn new() -> Self {
+        Self {
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE
+        }
+    }
+
+    /// Sets the maximum size in bytes of a frame, larger frames are rejected before any
+    /// allocation.
+    pub fn set_max_frame_size(&mut self, size: usize) -> &mut Self {
+        self.max_frame_size = size;
+        self
+    }
+}
+
+impl Default for MessageCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for MessageCodec {
+    type Item = BytesMut;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        if src.len() < LENGTH_SIZE {
+            return Ok(None);
+        }
+        let size = u32::from_le_bytes([src[0], src[1], src[2], src[3]]) as usize;
+        if size > self.max_frame_size {
+            return Err(frame_too_large(size, self.max_frame_size));
+        }
+        if src.len() < LENGTH_SIZE + size {
+            src.reserve(LENGTH_SIZE + size - src.len());
+            return Ok(None);
+        }
+        src.advance(LENGTH_SIZE);
+        Ok(Some(src.split_to(size)))
+    }
+}
+
+impl<'a, T: WriteTo<Input = T>> Encoder<&'a T> for MessageCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: &'a T, dst: &mut BytesMut) -> Result<()> {
+        encode_frame(item, dst)
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Length prefixed framing of messages over tokio streams.
+//!
+//! Every frame starts with the size of the encoded message as a little endian u32, followed by
+//! the message bytes.
+
+mod reader;
+mod writer;
+mod codec;
+
+pub use reader::FramedReader;
+pub use writer::FramedWriter;
+pub use codec::MessageCodec;
+
+use bytes::BytesMut;
+use crate::message::{Error, Result, Write, WriteTo};
+
+/// The size in bytes of the length prefix of each frame.
+pub const LENGTH_SIZE: usize = 4;
+
+/// The default maximum size in bytes of a frame accepted by readers and decoders (16 MiB).
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+fn frame_too_large(size: usize, max: usize) -> Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("frame size {} exceeds the maximum of {} bytes", size, max)
+    ).into()
+}
+
+/// A growable buffer which frames are encoded into.
+trait FrameBuffer: AsMut<[u8]> {
+    fn len(&self) -> usize;
+    fn truncate(&mut self, len: usize);
+    fn extend_from_slice(&mut self, data: &[u8]);
+}
+
+impl FrameBuffer for Vec<u8> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        Vec::truncate(self, len)
+    }
+
+    fn extend_from_slice(&mut self, data: &[u8]) {
+        Vec::extend_from_slice(self, data)
+    }
+}
+
+impl FrameBuffer for BytesMut {
+    fn len(&self) -> usize {
+        BytesMut::len(self)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        BytesMut::truncate(self, len)
+    }
+
+    fn extend_from_slice(&mut self, data: &[u8]) {
+        BytesMut::extend_from_slice(self, data)
+    }
+}
+
+struct Append<'a, B>(&'a mut B);
+
+impl<B: FrameBuffer> Write for Append<'_, B> {
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.0.extend_from_slice(buf);
+        Ok(())
+    }
+}
+
+/// Appends the given message to out, prefixed with its length.
+fn encode_frame<T: WriteTo<Input = T>, B: FrameBuffer>(msg: &T, out: &mut B) -> Result<()> {
+    let start = out.len();
+    out.extend_from_slice(&[0; LENGTH_SIZE]);
+    if let Err(e) = T::write_to(msg, Append(&mut *out)) {
+        out.truncate(start);
+        return Err(e);
+    }
+    let size = out.len() - start - LENGTH_SIZE;
+    let Ok(size) = u32::try_from(size) else {
+        out.truncate(start);
+        return Err(frame_too_large(size, u32::MAX as usize));
+    };
+    out.as_mut()[start..start + LENGTH_SIZE].copy_from_slice(&size.to_le_bytes());
+    Ok(())
+}
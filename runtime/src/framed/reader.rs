@@ -0,0 +1,127 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+use crate::framed::{frame_too_large, DEFAULT_MAX_FRAME_SIZE, LENGTH_SIZE};
+use crate::message::{Error, FromSlice, Result};
+
+/// Reads length prefixed messages from an [AsyncRead].
+pub struct FramedReader<R> {
+    inner: R,
+    buffer: Vec<u8>,
+    max_frame_size: usize,
+    // Progress of the frame being read, kept across calls so that read_frame is cancel safe.
+    header: [u8; LENGTH_SIZE],
+    header_len: usize,
+    filled: Option<usize>
+}
+
+impl<R> FramedReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            header: [0; LENGTH_SIZE],
+            header_len: 0,
+            filled: None
+        }
+    }
+
+    /// Sets the maximum size in bytes of a frame, larger frames are rejected before any
+    /// allocation.
+    pub fn set_max_frame_size(&mut self, size: usize) -> &mut Self {
+        self.max_frame_size = size;
+        self
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + Unpin> FramedReader<R> {
+    /// Reads the next frame and returns the message bytes it contains.
+    ///
+    /// Returns None if the stream ended cleanly before the start of a frame.
+    ///
+    /// This method is cancel safe: the bytes of a partially read frame are kept in the reader and
+    /// the next call resumes reading the same frame.
+    pub async fn read_frame(&mut self) -> Result<Option<&[u8]>> {
+        while self.header_len < LENGTH_SIZE {
+            let read = self.inner.read(&mut self.header[self.header_len..]).await?;
+            if read == 0 {
+                return match self.header_len {
+                    0 => Ok(None),
+                    _ => Err(Error::Truncated)
+                };
+            }
+            self.header_len += read;
+        }
+        let mut filled = match self.filled {
+            Some(filled) => filled,
+            None => {
+                let size = u32::from_le_bytes(self.header) as usize;
+                if size > self.max_frame_size {
+                    return Err(frame_too_large(size, self.max_frame_size));
+                }
+                self.buffer.resize(size, 0);
+                0
+            }
+        };
+        while filled < self.buffer.len() {
+            self.filled = Some(filled);
+            let read = self.inner.read(&mut self.buffer[filled..]).await?;
+            if read == 0 {
+                return Err(Error::Truncated);
+            }
+            filled += read;
+        }
+        self.header_len = 0;
+        self.filled = None;
+        Ok(Some(&self.buffer))
+    }
+
+    /// Reads the next frame and decodes it as a T message.
+    ///
+    /// Returns None if the stream ended cleanly before the start of a frame.
+    pub async fn read<'a, T: FromSlice<'a>>(&'a mut self) -> Result<Option<T::Output>> {
+        match self.read_frame().await? {
+            Some(frame) => Ok(Some(T::from_slice(frame)?.into_inner())),
+            None => Ok(None)
+        }
+    }
+}
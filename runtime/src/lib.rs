@@ -26,6 +26,17 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-exported for use by generated code which may itself be no_std.
+pub extern crate alloc;
+
 pub mod message;
 pub mod util;
 pub mod codec;
+
+#[cfg(feature = "tokio")]
+pub mod framed;
+
+#[cfg(feature = "serde")]
+pub use serde;
@@ -26,18 +26,50 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use bp3d_util::simple_error;
-
-simple_error! {
-    pub Error {
-        InvalidUtf8 => "invalid UTF-8 string",
-        Truncated => "truncated input",
-        InvalidUnionDiscriminant(usize) => "invalid union discriminant ({})",
-        (impl From) Io(std::io::Error) => "io error: {}"
+use core::fmt::{Display, Formatter};
+use crate::message::{Limit, Limiter, Progress};
+
+// simple_error! is not usable here as it relies on std.
+#[derive(Debug)]
+pub enum Error {
+    InvalidUtf8,
+    Truncated,
+    InvalidUnionDiscriminant(usize),
+    InvalidEnumValue(usize),
+    BufferTooSmall,
+    LengthExceeded,
+    LengthMismatch,
+    LimitExceeded(Limit),
+    /// An error reported by a [Write] sink; with std this wraps a [std::io::Error].
+    Io(alloc::boxed::Box<dyn core::error::Error + Send + Sync>)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::InvalidUtf8 => f.write_str("invalid UTF-8 string"),
+            Error::Truncated => f.write_str("truncated input"),
+            Error::InvalidUnionDiscriminant(e) => write!(f, "invalid union discriminant ({})", e),
+            Error::InvalidEnumValue(e) => write!(f, "invalid enum value ({})", e),
+            Error::BufferTooSmall => f.write_str("buffer too small"),
+            Error::LengthExceeded => f.write_str("length exceeded"),
+            Error::LengthMismatch => f.write_str("length mismatch"),
+            Error::LimitExceeded(e) => write!(f, "{} limit exceeded", e),
+            Error::Io(e) => write!(f, "io error: {}", e)
+        }
     }
 }
 
-pub type Result<T> = std::result::Result<T, Error>;
+impl core::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::Io(alloc::boxed::Box::new(value))
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Default, Copy, Clone, Debug)]
 pub struct FieldOffset {
@@ -81,17 +113,109 @@ pub trait FromSlice<'a> {
     type Output: Sized;
 
     fn from_slice(slice: &'a [u8]) -> Result<Message<Self::Output>>;
+
+    /// Decodes the slice while consuming the budget of the given [Limiter].
+    ///
+    /// The default implementation is only suitable for types whose decoding cost is bounded by
+    /// their encoded size.
+    fn from_slice_with_limits(slice: &'a [u8], _: &mut Limiter) -> Result<Message<Self::Output>> {
+        Self::from_slice(slice)
+    }
     //fn copy_to_slice(&self, out_slice: &mut [u8]);
 }
 
+/// The result of measuring a message at the start of a possibly truncated input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Measurement {
+    /// The input contains the whole message, which spans the given number of bytes.
+    Complete(usize),
+
+    /// The input is truncated, at least the given number of additional bytes are needed to make
+    /// progress.
+    Incomplete(usize)
+}
+
+/// Computes the size of an encoded message without decoding it.
+///
+/// Only self-delimited messages can be measured: messages ending with a payload extend to the end
+/// of their input and do not implement this trait.
+pub trait Measure<'a> {
+    fn measure(slice: &'a [u8]) -> Result<Measurement>;
+
+    /// Measures the slice, resuming from the state saved in the given [Progress] by a previous
+    /// incomplete measurement of a prefix of the same input.
+    ///
+    /// The default implementation is only suitable for types which are measured in constant time.
+    fn measure_with_progress(slice: &'a [u8], _: &mut Progress) -> Result<Measurement> {
+        Self::measure(slice)
+    }
+}
+
 pub trait FromSliceWithOffsets<'a>: FromSlice<'a> {
     type Offsets: Sized;
 
     fn from_slice_with_offsets(slice: &'a [u8]) -> Result<Message<(Self::Output, Self::Offsets)>>;
 }
 
+/// A byte sink which messages are written to.
+///
+/// With the std feature it is implemented for every [std::io::Write], otherwise for `Vec<u8>`
+/// and `&mut Vec<u8>`. Mutable references to other sinks are not sinks themselves, so generic
+/// code must use [reborrow](Write::reborrow) to write into a sink it keeps using afterwards.
+pub trait Write {
+    fn write_all(&mut self, buf: &[u8]) -> Result<()>;
+
+    /// Returns a sink writing into this one.
+    fn reborrow(&mut self) -> Reborrow<'_, Self> {
+        Reborrow(self)
+    }
+}
+
+/// A sink writing into another sink, see [reborrow](Write::reborrow).
+pub struct Reborrow<'a, W: ?Sized>(&'a mut W);
+
+impl<W: Write + ?Sized> Write for Reborrow<'_, W> {
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.0.write_all(buf)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write + ?Sized> Write for W {
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        Ok(std::io::Write::write_all(self, buf)?)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Write for alloc::vec::Vec<u8> {
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Write for &mut alloc::vec::Vec<u8> {
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+}
+
 pub trait WriteTo {
     type Input: ?Sized;
 
-    fn write_to<W: std::io::Write>(input: &Self::Input, out: W) -> Result<()>;
+    fn write_to<W: Write>(input: &Self::Input, out: W) -> Result<()>;
+}
+
+/// Encodes directly into a caller provided buffer, without going through [Write].
+pub trait WriteToSlice: WriteTo {
+    /// Encodes the input at the start of the given buffer and returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BufferTooSmall](Error::BufferTooSmall) if the encoded input does not fit in the
+    /// buffer, in which case the content of the buffer is unspecified.
+    fn write_to_slice(input: &Self::Input, out: &mut [u8]) -> Result<usize>;
 }
@@ -0,0 +1,161 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use core::fmt::{Display, Formatter};
+use crate::message::{Error, FromSlice, Message, Result};
+
+/// The limit which was exceeded while decoding a message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Limit {
+    /// Too many list and array items across the whole message.
+    Items,
+
+    /// Messages are nested too deeply.
+    Depth,
+
+    /// A message spans too many bytes.
+    Size
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Limit::Items => f.write_str("items"),
+            Limit::Depth => f.write_str("depth"),
+            Limit::Size => f.write_str("size")
+        }
+    }
+}
+
+/// Resource limits to enforce when decoding untrusted input.
+///
+/// All limits default to unlimited.
+#[derive(Copy, Clone, Debug)]
+pub struct DecodeLimits {
+    max_items: usize,
+    max_depth: usize,
+    max_size: usize
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DecodeLimits {
+    pub fn new() -> Self {
+        Self {
+            max_items: usize::MAX,
+            max_depth: usize::MAX,
+            max_size: usize::MAX
+        }
+    }
+
+    /// Sets the maximum total number of list and array items, counted across all nested
+    /// messages. Item counts are checked before any item is decoded.
+    pub fn set_max_items(&mut self, max_items: usize) -> &mut Self {
+        self.max_items = max_items;
+        self
+    }
+
+    /// Sets the maximum nesting depth of messages, the top level message being at depth 1.
+    pub fn set_max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum size in bytes of a message. Decoding stops at the first field or item
+    /// which ends past the limit.
+    pub fn set_max_size(&mut self, max_size: usize) -> &mut Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Decodes a T message from the given slice while enforcing these limits.
+    pub fn from_slice<'a, T: FromSlice<'a>>(&self, slice: &'a [u8]) -> Result<Message<T::Output>> {
+        T::from_slice_with_limits(slice, &mut Limiter::new(self))
+    }
+}
+
+/// The remaining budget while decoding a single message under [DecodeLimits].
+#[derive(Clone, Debug)]
+pub struct Limiter {
+    items: usize,
+    depth: usize,
+    max_size: usize,
+    start: Option<usize>
+}
+
+impl Limiter {
+    pub fn new(limits: &DecodeLimits) -> Self {
+        Self {
+            items: limits.max_items,
+            depth: limits.max_depth,
+            max_size: limits.max_size,
+            start: None
+        }
+    }
+
+    /// Consumes the given number of items from the budget.
+    pub fn add_items(&mut self, count: usize) -> Result<()> {
+        self.items = self.items.checked_sub(count).ok_or(Error::LimitExceeded(Limit::Items))?;
+        Ok(())
+    }
+
+    /// Checks that the first size bytes of the given slice end within the maximum size counted
+    /// from the start of the outermost message.
+    ///
+    /// The slice must be part of the input of the outermost message, which starts at the first
+    /// slice given to this limiter. This is checked while walking fields and items so that decoding
+    /// stops as soon as the limit is crossed instead of once the whole message is decoded.
+    pub fn check_size(&mut self, slice: &[u8], size: usize) -> Result<()> {
+        // Only addresses are compared, slices of the same input share a single allocation.
+        let address = slice.as_ptr() as usize;
+        let start = *self.start.get_or_insert(address);
+        match address.saturating_sub(start).saturating_add(size) > self.max_size {
+            true => Err(Error::LimitExceeded(Limit::Size)),
+            false => Ok(())
+        }
+    }
+
+    /// Enters a nested message starting at the given slice.
+    pub fn enter(&mut self, slice: &[u8]) -> Result<()> {
+        self.depth = self.depth.checked_sub(1).ok_or(Error::LimitExceeded(Limit::Depth))?;
+        self.check_size(slice, 0)
+    }
+
+    /// Leaves a nested message which spans the given number of bytes.
+    pub fn leave(&mut self, size: usize) -> Result<()> {
+        self.depth += 1;
+        match size > self.max_size {
+            true => Err(Error::LimitExceeded(Limit::Size)),
+            false => Ok(())
+        }
+    }
+}
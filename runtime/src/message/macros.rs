@@ -49,7 +49,7 @@ macro_rules! generate_array_wrapper {
             }
         }
 
-        impl<'a, B: std::io::Write> $name<'a, B> {
+        impl<'a, B: $crate::message::Write> $name<'a, B> {
             pub fn write_item(&mut self, item: &$inner<&[u8]>) -> $crate::message::Result<()> {
                 self.0.write_item(item)
             }
@@ -29,5 +29,9 @@
 mod interface;
 pub mod util;
 mod macros;
+mod stream;
+mod limits;
 
 pub use interface::*;
+pub use stream::{Progress, StreamDecoder};
+pub use limits::{DecodeLimits, Limit, Limiter};
@@ -0,0 +1,146 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use alloc::vec::Vec;
+use crate::message::{Error, FromSlice, Measure, Measurement, Result};
+
+/// The state of an incomplete measurement, used to resume it once more input is available instead
+/// of measuring the message again from the start.
+///
+/// Each list or message which stopped on a truncated input saves its own state, innermost first,
+/// and takes it back when resumed, outermost first.
+#[derive(Clone, Debug, Default)]
+pub struct Progress {
+    stack: Vec<usize>
+}
+
+impl Progress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if no state is saved, in which case the next measurement starts from the
+    /// beginning of the input.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Saves the state of a measurement which stopped on a truncated input.
+    pub fn save<const N: usize>(&mut self, state: [usize; N]) {
+        self.stack.extend(state.iter().rev());
+    }
+
+    /// Takes back the state saved by [save](Self::save), returning None if the measurement has
+    /// not been started yet.
+    pub fn resume<const N: usize>(&mut self) -> Option<[usize; N]> {
+        let start = self.stack.len().checked_sub(N)?;
+        let mut state = [0; N];
+        for (v, saved) in state.iter_mut().zip(self.stack.drain(start..).rev()) {
+            *v = saved;
+        }
+        Some(state)
+    }
+
+    /// Discards any saved state.
+    pub fn clear(&mut self) {
+        self.stack.clear();
+    }
+}
+
+/// A push decoder which accepts input in arbitrary chunks and yields complete messages.
+///
+/// Messages are only measured once enough input has been pushed to make progress on the
+/// previous measurement, which is then resumed where it stopped, and only decoded once, when
+/// complete. Consumed input is discarded lazily,
+/// once it makes up at least half of the buffer.
+#[derive(Default)]
+pub struct StreamDecoder {
+    buffer: Vec<u8>,
+    start: usize,
+    consumed: usize,
+    needed: usize,
+    progress: Progress
+}
+
+impl StreamDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn discard_consumed(&mut self) {
+        self.start += self.consumed;
+        self.consumed = 0;
+        if self.start > 0 && self.start * 2 >= self.buffer.len() {
+            self.buffer.drain(..self.start);
+            self.start = 0;
+        }
+    }
+
+    /// Appends a chunk of input to the decoder.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.discard_consumed();
+        self.buffer.reserve(self.remaining().max(chunk.len()));
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Returns the number of buffered bytes which are not yet part of a decoded message.
+    pub fn buffered(&self) -> usize {
+        self.buffer.len() - self.start - self.consumed
+    }
+
+    /// Returns a lower bound of the number of bytes which must be pushed before the next message
+    /// can be decoded.
+    pub fn remaining(&self) -> usize {
+        self.needed.saturating_sub(self.buffered())
+    }
+
+    /// Decodes the next message, returning None if more input is needed.
+    ///
+    /// The decoder must always be used with the same message type; once an error is returned the
+    /// stream cannot be resynchronized.
+    pub fn next<'a, T: Measure<'a> + FromSlice<'a>>(&'a mut self) -> Result<Option<T::Output>> {
+        self.discard_consumed();
+        if self.buffer.len() - self.start < self.needed {
+            return Ok(None);
+        }
+        let slice = &self.buffer[self.start..];
+        match T::measure_with_progress(slice, &mut self.progress)? {
+            Measurement::Complete(size) => {
+                self.progress.clear();
+                let msg = T::from_slice(slice.get(..size).ok_or(Error::Truncated)?)?;
+                self.consumed = size;
+                self.needed = 0;
+                Ok(Some(msg.into_inner()))
+            },
+            Measurement::Incomplete(n) => {
+                self.needed = slice.len() + n;
+                Ok(None)
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+/// An append-only storage for the encoded bytes of lists and arrays, used to borrow owned
+/// messages.
+///
+/// Allocated buffers are only freed when the arena is dropped, which allows handing out slices
+/// which live as long as the arena itself.
+#[derive(Default)]
+pub struct Arena {
+    buffers: RefCell<Vec<Vec<u8>>>
+}
+
+impl Arena {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the given buffer into the arena and returns a slice to its content.
+    pub fn alloc(&self, data: Vec<u8>) -> &[u8] {
+        let ptr = data.as_ptr();
+        let len = data.len();
+        self.buffers.borrow_mut().push(data);
+        // SAFETY: moving a Vec does not move its heap allocation and buffers are never removed
+        // nor modified until the arena is dropped, so the slice stays valid for the lifetime of
+        // the borrow of the arena.
+        unsafe { core::slice::from_raw_parts(ptr, len) }
+    }
+}
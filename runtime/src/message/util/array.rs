@@ -27,9 +27,10 @@
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 
-use std::marker::PhantomData;
-use std::slice::{Chunks, ChunksMut};
-use crate::message::{Error, FromSlice, Message};
+use core::marker::PhantomData;
+use core::slice::{Chunks, ChunksMut};
+use crate::message::{Error, FromSlice, Limiter, Measure, Measurement, Message};
+use crate::message::util::common::{measure_fixed, measure_len};
 use crate::message::util::list_base::impl_list_base;
 use crate::util::{FixedSize, ToUsize};
 use crate::message::WriteTo;
@@ -116,17 +117,39 @@ impl<'a, T: FromSlice<'a, Output: ToUsize>, Item: FixedSize + FromSlice<'a, Outp
         let msg = T::from_slice(slice)?;
         let control_size = msg.size();
         let len = msg.into_inner().to_usize();
-        let total_size = control_size + (len * Item::SIZE);
-        if slice.len() < total_size {
-            Err(Error::Truncated)
-        } else {
-            let data = &slice[control_size..total_size];
-            Ok(Message::new(total_size, Array {
-                data,
-                len,
-                useless: PhantomData::default(),
-                useless1: PhantomData::default()
-            }))
+        let total_size = len.checked_mul(Item::SIZE).and_then(|v| v.checked_add(control_size))
+            .ok_or(Error::Truncated)?;
+        let data = slice.get(control_size..total_size).ok_or(Error::Truncated)?;
+        Ok(Message::new(total_size, Array {
+            data,
+            len,
+            useless: PhantomData::default(),
+            useless1: PhantomData::default()
+        }))
+    }
+
+    fn from_slice_with_limits(slice: &'a [u8], limiter: &mut Limiter) -> Result<Message<Self::Output>, Error> {
+        let msg = Self::from_slice(slice)?;
+        let size = msg.size();
+        let array = msg.into_inner();
+        limiter.add_items(array.len())?;
+        limiter.check_size(slice, size)?;
+        Ok(Message::new(size, array))
+    }
+}
+
+impl<'a, T: Measure<'a> + FromSlice<'a, Output: ToUsize>, Item: FixedSize> Measure<'a> for Array<&'a [u8], T, Item> {
+    fn measure(slice: &'a [u8]) -> Result<Measurement, Error> {
+        match measure_len::<T>(slice)? {
+            Ok((size, len)) => Ok(measure_fixed(slice, len.saturating_mul(Item::SIZE).saturating_add(size))),
+            Err(v) => Ok(v)
         }
     }
 }
+
+#[cfg(feature = "serde")]
+impl<'a, T, Item: FixedSize + From<&'a [u8]> + serde::Serialize> serde::Serialize for Array<&'a [u8], T, Item> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.data.chunks(Item::SIZE).map(Item::from))
+    }
+}
@@ -26,17 +26,45 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::io::Write;
-use std::marker::PhantomData;
-use bytesutil::ReadBytes;
-use crate::message::{Error, FromSlice, FromSliceWithOffsets, Message, WriteTo};
+use core::marker::PhantomData;
+use bytesutil::{ReadBytes, WriteBytes};
+use crate::message::{Error, FromSlice, FromSliceWithOffsets, Limiter, Measure, Measurement, Message, Progress, Write, WriteTo, WriteToSlice};
+use crate::util::ToUsize;
+
+/// Copies the given bytes at the start of the buffer and returns the number of bytes written.
+#[inline]
+pub(crate) fn write_bytes(bytes: &[u8], out: &mut [u8]) -> Result<usize, Error> {
+    if out.len() < bytes.len() {
+        return Err(Error::BufferTooSmall);
+    }
+    out[..bytes.len()].copy_from_slice(bytes);
+    Ok(bytes.len())
+}
+
+/// Measures and decodes the length prefix at the start of the buffer, returning its size and
+/// value, or the measurement of the truncated input.
+pub(crate) fn measure_len<'a, T: Measure<'a> + FromSlice<'a, Output: ToUsize>>(slice: &'a [u8]) -> Result<Result<(usize, usize), Measurement>, Error> {
+    match T::measure(slice)? {
+        Measurement::Complete(size) => Ok(Ok((size, T::from_slice(slice)?.into_inner().to_usize()))),
+        Measurement::Incomplete(n) => Ok(Err(Measurement::Incomplete(n)))
+    }
+}
+
+/// Measures a message made of a fixed number of bytes.
+pub(crate) fn measure_fixed(slice: &[u8], size: usize) -> Measurement {
+    if slice.len() < size {
+        Measurement::Incomplete(size - slice.len())
+    } else {
+        Measurement::Complete(size)
+    }
+}
 
 pub struct Optional<T>(PhantomData<T>);
 
-impl<'a, T: FromSlice<'a, Output = T>> FromSlice<'a> for Optional<T> {
-    type Output = Option<T>;
+impl<'a, T: FromSlice<'a>> FromSlice<'a> for Optional<T> {
+    type Output = Option<T::Output>;
 
-    fn from_slice(slice: &'a [u8]) -> Result<Message<Option<T>>, Error> {
+    fn from_slice(slice: &'a [u8]) -> Result<Message<Self::Output>, Error> {
         if slice.len() < 1 {
             Err(Error::Truncated)
         } else {
@@ -49,9 +77,20 @@ impl<'a, T: FromSlice<'a, Output = T>> FromSlice<'a> for Optional<T> {
             }
         }
     }
+
+    fn from_slice_with_limits(slice: &'a [u8], limiter: &mut Limiter) -> Result<Message<Self::Output>, Error> {
+        match slice.first() {
+            None => Err(Error::Truncated),
+            Some(0) => Ok(Message::new(1, None)),
+            Some(_) => {
+                let msg = T::from_slice_with_limits(&slice[1..], limiter)?;
+                Ok(Message::new(msg.size() + 1, Some(msg.into_inner())))
+            }
+        }
+    }
 }
 
-impl<'a, T: FromSlice<'a, Output = T> + FromSliceWithOffsets<'a>> FromSliceWithOffsets<'a> for Optional<T> {
+impl<'a, T: FromSliceWithOffsets<'a>> FromSliceWithOffsets<'a> for Optional<T> {
     type Offsets = Option<T::Offsets>;
 
     fn from_slice_with_offsets(slice: &'a [u8]) -> crate::message::Result<Message<(Self::Output, Self::Offsets)>> {
@@ -71,21 +110,50 @@ impl<'a, T: FromSlice<'a, Output = T> + FromSliceWithOffsets<'a>> FromSliceWithO
     }
 }
 
-impl<T: WriteTo<Input = T>> WriteTo for Optional<T> {
-    type Input = Option<T>;
+impl<T: WriteTo<Input: Sized>> WriteTo for Optional<T> {
+    type Input = Option<T::Input>;
 
     fn write_to<W: Write>(input: &Self::Input, mut out: W) -> Result<(), Error> {
         match input {
             None => out.write_all(&[0x0])?,
             Some(v) => {
                 out.write_all(&[0x1])?;
-                T::write_to(v, out)?;
+                T::write_to(v, out.reborrow())?;
             }
         }
         Ok(())
     }
 }
 
+impl<'a, T: Measure<'a>> Measure<'a> for Optional<T> {
+    fn measure(slice: &'a [u8]) -> Result<Measurement, Error> {
+        Self::measure_with_progress(slice, &mut Progress::new())
+    }
+
+    fn measure_with_progress(slice: &'a [u8], progress: &mut Progress) -> Result<Measurement, Error> {
+        match slice.first() {
+            None => Ok(Measurement::Incomplete(1)),
+            Some(0) => Ok(Measurement::Complete(1)),
+            Some(_) => match T::measure_with_progress(&slice[1..], progress)? {
+                Measurement::Complete(size) => Ok(Measurement::Complete(size + 1)),
+                v => Ok(v)
+            }
+        }
+    }
+}
+
+impl<T: WriteToSlice<Input: Sized>> WriteToSlice for Optional<T> {
+    fn write_to_slice(input: &Self::Input, out: &mut [u8]) -> Result<usize, Error> {
+        match input {
+            None => write_bytes(&[0x0], out),
+            Some(v) => {
+                write_bytes(&[0x1], out)?;
+                Ok(T::write_to_slice(v, &mut out[1..])? + 1)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct ValueLE<T>(PhantomData<T>);
 #[derive(Debug, Copy, Clone)]
@@ -105,12 +173,34 @@ impl<'a, T: ReadBytes> FromSlice<'a> for ValueLE<T> {
     }
 }
 
-impl<T: bytesutil::WriteTo> WriteTo for ValueLE<T> {
+// Scalars are at most 16 bytes long (u128), so they can be encoded on the stack.
+fn write_scalar<T, W: Write>(mut out: W, f: impl FnOnce(&mut [u8])) -> Result<(), Error> {
+    let mut buffer = [0; 16];
+    let bytes = &mut buffer[..size_of::<T>()];
+    f(bytes);
+    out.write_all(bytes)?;
+    Ok(())
+}
+
+impl<T: WriteBytes> WriteTo for ValueLE<T> {
     type Input = T;
 
     fn write_to<W: Write>(input: &Self::Input, out: W) -> Result<(), Error> {
-        input.write_to_le(out)?;
-        Ok(())
+        write_scalar::<T, W>(out, |bytes| input.write_bytes_le(bytes))
+    }
+}
+
+impl<'a, T> Measure<'a> for ValueLE<T> {
+    fn measure(slice: &'a [u8]) -> Result<Measurement, Error> {
+        Ok(measure_fixed(slice, size_of::<T>()))
+    }
+}
+
+impl<T: WriteBytes> WriteToSlice for ValueLE<T> {
+    fn write_to_slice(input: &Self::Input, out: &mut [u8]) -> Result<usize, Error> {
+        let bytes = out.get_mut(..size_of::<T>()).ok_or(Error::BufferTooSmall)?;
+        input.write_bytes_le(bytes);
+        Ok(bytes.len())
     }
 }
 
@@ -128,12 +218,144 @@ impl<'a, T: ReadBytes> FromSlice<'a> for ValueBE<T> {
     }
 }
 
-impl<T: bytesutil::WriteTo> WriteTo for ValueBE<T> {
+impl<T: WriteBytes> WriteTo for ValueBE<T> {
     type Input = T;
 
     fn write_to<W: Write>(input: &Self::Input, out: W) -> Result<(), Error> {
-        input.write_to_be(out)?;
-        Ok(())
+        write_scalar::<T, W>(out, |bytes| input.write_bytes_be(bytes))
+    }
+}
+
+impl<'a, T> Measure<'a> for ValueBE<T> {
+    fn measure(slice: &'a [u8]) -> Result<Measurement, Error> {
+        Ok(measure_fixed(slice, size_of::<T>()))
+    }
+}
+
+impl<T: WriteBytes> WriteToSlice for ValueBE<T> {
+    fn write_to_slice(input: &Self::Input, out: &mut [u8]) -> Result<usize, Error> {
+        let bytes = out.get_mut(..size_of::<T>()).ok_or(Error::BufferTooSmall)?;
+        input.write_bytes_be(bytes);
+        Ok(bytes.len())
+    }
+}
+
+/// Enum codec which encodes variants with the scalar codec `T` and rejects unknown values on
+/// decode.
+#[derive(Debug, Copy, Clone)]
+pub struct EnumValue<T, E>(PhantomData<(T, E)>);
+
+impl<'a, T: FromSlice<'a>, E: TryFrom<T::Output, Error: ToUsize>> FromSlice<'a> for EnumValue<T, E> {
+    type Output = E;
+
+    fn from_slice(slice: &'a [u8]) -> Result<Message<Self::Output>, Error> {
+        let msg = T::from_slice(slice)?;
+        let size = msg.size();
+        let value = E::try_from(msg.into_inner()).map_err(|e| Error::InvalidEnumValue(e.to_usize()))?;
+        Ok(Message::new(size, value))
+    }
+}
+
+impl<'a, T: Measure<'a>, E> Measure<'a> for EnumValue<T, E> {
+    fn measure(slice: &'a [u8]) -> Result<Measurement, Error> {
+        T::measure(slice)
+    }
+}
+
+impl<T: WriteTo<Input: Sized>, E: Copy + Into<T::Input>> WriteTo for EnumValue<T, E> {
+    type Input = E;
+
+    fn write_to<W: Write>(input: &Self::Input, out: W) -> Result<(), Error> {
+        T::write_to(&(*input).into(), out)
+    }
+}
+
+impl<T: WriteToSlice<Input: Sized>, E: Copy + Into<T::Input>> WriteToSlice for EnumValue<T, E> {
+    fn write_to_slice(input: &Self::Input, out: &mut [u8]) -> Result<usize, Error> {
+        T::write_to_slice(&(*input).into(), out)
+    }
+}
+
+/// Length prefix codec which rejects lengths greater than `MAX` on both decode and encode.
+///
+/// The prefix itself is encoded with `T`, which may be wider than needed to represent `MAX`.
+#[derive(Debug, Copy, Clone)]
+pub struct MaxLen<T, const MAX: usize>(PhantomData<T>);
+
+fn check_max_len<T: ToUsize + Copy>(value: T, max: usize) -> Result<T, Error> {
+    match value.to_usize() > max {
+        true => Err(Error::LengthExceeded),
+        false => Ok(value)
+    }
+}
+
+impl<'a, T: FromSlice<'a, Output: ToUsize + Copy>, const MAX: usize> FromSlice<'a> for MaxLen<T, MAX> {
+    type Output = T::Output;
+
+    fn from_slice(slice: &'a [u8]) -> Result<Message<Self::Output>, Error> {
+        let msg = T::from_slice(slice)?;
+        let size = msg.size();
+        Ok(Message::new(size, check_max_len(msg.into_inner(), MAX)?))
+    }
+}
+
+impl<'a, T: Measure<'a>, const MAX: usize> Measure<'a> for MaxLen<T, MAX> {
+    fn measure(slice: &'a [u8]) -> Result<Measurement, Error> {
+        T::measure(slice)
+    }
+}
+
+impl<T: WriteTo<Input: ToUsize + Copy + Sized>, const MAX: usize> WriteTo for MaxLen<T, MAX> {
+    type Input = T::Input;
+
+    fn write_to<W: Write>(input: &Self::Input, out: W) -> Result<(), Error> {
+        T::write_to(&check_max_len(*input, MAX)?, out)
+    }
+}
+
+impl<T: WriteToSlice<Input: ToUsize + Copy + Sized>, const MAX: usize> WriteToSlice for MaxLen<T, MAX> {
+    fn write_to_slice(input: &Self::Input, out: &mut [u8]) -> Result<usize, Error> {
+        T::write_to_slice(&check_max_len(*input, MAX)?, out)
+    }
+}
+
+/// Item count codec for lists which always hold exactly `LEN` items.
+///
+/// Nothing is encoded; decoding always yields `LEN` and encoding any other count fails.
+#[derive(Debug, Copy, Clone)]
+pub struct FixedLen<const LEN: usize>;
+
+impl<'a, const LEN: usize> FromSlice<'a> for FixedLen<LEN> {
+    type Output = usize;
+
+    fn from_slice(_: &'a [u8]) -> Result<Message<Self::Output>, Error> {
+        Ok(Message::new(0, LEN))
+    }
+}
+
+impl<'a, const LEN: usize> Measure<'a> for FixedLen<LEN> {
+    fn measure(_: &'a [u8]) -> Result<Measurement, Error> {
+        Ok(Measurement::Complete(0))
+    }
+}
+
+impl<const LEN: usize> WriteTo for FixedLen<LEN> {
+    type Input = usize;
+
+    fn write_to<W: Write>(input: &Self::Input, _: W) -> Result<(), Error> {
+        match *input == LEN {
+            true => Ok(()),
+            false => Err(Error::LengthMismatch)
+        }
+    }
+}
+
+impl<const LEN: usize> WriteToSlice for FixedLen<LEN> {
+    fn write_to_slice(input: &Self::Input, _: &mut [u8]) -> Result<usize, Error> {
+        match *input == LEN {
+            true => Ok(0),
+            false => Err(Error::LengthMismatch)
+        }
     }
 }
 
@@ -155,3 +377,10 @@ impl WriteTo for Buffer {
         Ok(())
     }
 }
+
+impl WriteToSlice for Buffer {
+    #[inline]
+    fn write_to_slice(input: &Self::Input, out: &mut [u8]) -> Result<usize, Error> {
+        write_bytes(input, out)
+    }
+}
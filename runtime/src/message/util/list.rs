@@ -26,8 +26,9 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::marker::PhantomData;
-use crate::message::{Error, FromSlice, FromSliceWithOffsets, Message, WriteTo};
+use core::marker::PhantomData;
+use crate::message::{Error, FromSlice, FromSliceWithOffsets, Limiter, Measure, Measurement, Message, Progress, WriteTo};
+use crate::message::util::common::measure_len;
 use crate::message::util::list_base::impl_list_base;
 use crate::util::ToUsize;
 
@@ -41,22 +42,70 @@ pub struct List<B, T, Item> {
 
 impl_list_base!(List);
 
-impl<'a, T: FromSlice<'a, Output: ToUsize>, Item: FromSlice<'a, Output = Item>> FromSlice<'a> for List<&'a [u8], T, Item> {
+impl<'a, T: FromSlice<'a, Output: ToUsize>, Item: FromSlice<'a>> FromSlice<'a> for List<&'a [u8], T, Item> {
     type Output = List<&'a [u8], T, Item>;
 
     fn from_slice(slice: &'a [u8]) -> Result<Message<Self::Output>, Error> {
         let msg = T::from_slice(slice)?;
         let control_size = msg.size();
         let len = msg.into_inner().to_usize();
-        let data = &slice[control_size..];
+        let data = slice.get(control_size..).ok_or(Error::Truncated)?;
         let mut total_size: usize = 0;
         for _ in 0..len {
-            let msg = Item::from_slice(&data[total_size..])?;
+            let msg = Item::from_slice(data.get(total_size..).ok_or(Error::Truncated)?)?;
             total_size += msg.size();
         }
-        let data = &slice[control_size..control_size + total_size];
+        let data = data.get(..total_size).ok_or(Error::Truncated)?;
         Ok(Message::new(total_size + control_size, unsafe { List::from_raw_parts(data, len) }))
     }
+
+    fn from_slice_with_limits(slice: &'a [u8], limiter: &mut Limiter) -> Result<Message<Self::Output>, Error> {
+        let msg = T::from_slice(slice)?;
+        let control_size = msg.size();
+        let len = msg.into_inner().to_usize();
+        limiter.add_items(len)?;
+        let data = slice.get(control_size..).ok_or(Error::Truncated)?;
+        let mut total_size: usize = 0;
+        for _ in 0..len {
+            limiter.check_size(data, total_size)?;
+            let msg = Item::from_slice_with_limits(data.get(total_size..).ok_or(Error::Truncated)?, limiter)?;
+            total_size += msg.size();
+        }
+        let data = data.get(..total_size).ok_or(Error::Truncated)?;
+        Ok(Message::new(total_size + control_size, unsafe { List::from_raw_parts(data, len) }))
+    }
+}
+
+/// Measures a length prefix followed by as many variable sized items, resuming at the item which
+/// was truncated in the previous measurement.
+fn measure_items<'a, T: Measure<'a> + FromSlice<'a, Output: ToUsize>, Item: Measure<'a>>(slice: &'a [u8], progress: &mut Progress) -> crate::message::Result<Measurement> {
+    let [start, mut total_size, len] = match progress.resume() {
+        Some(v) => v,
+        None => match measure_len::<T>(slice)? {
+            Ok((size, len)) => [0, size, len],
+            Err(v) => return Ok(v)
+        }
+    };
+    for i in start..len {
+        match Item::measure_with_progress(slice.get(total_size..).ok_or(Error::Truncated)?, progress)? {
+            Measurement::Complete(size) => total_size += size,
+            v => {
+                progress.save([i, total_size, len]);
+                return Ok(v);
+            }
+        }
+    }
+    Ok(Measurement::Complete(total_size))
+}
+
+impl<'a, T: Measure<'a> + FromSlice<'a, Output: ToUsize>, Item: Measure<'a>> Measure<'a> for List<&'a [u8], T, Item> {
+    fn measure(slice: &'a [u8]) -> crate::message::Result<Measurement> {
+        measure_items::<T, Item>(slice, &mut Progress::new())
+    }
+
+    fn measure_with_progress(slice: &'a [u8], progress: &mut Progress) -> crate::message::Result<Measurement> {
+        measure_items::<T, Item>(slice, progress)
+    }
 }
 
 pub struct Iter<'a, Item> {
@@ -65,18 +114,22 @@ pub struct Iter<'a, Item> {
     useless: PhantomData<Item>
 }
 
-impl<'a, Item: FromSlice<'a, Output = Item>> Iterator for Iter<'a, Item> {
-    type Item = crate::message::Result<Item>;
+impl<'a, Item: FromSlice<'a>> Iterator for Iter<'a, Item> {
+    type Item = crate::message::Result<Item::Output>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.len == 0 {
             return None;
         }
+        // Stop at the first error as the position of the next item is unknown.
         let msg = match Item::from_slice(self.data) {
-            Err(e) => return Some(Err(e)),
+            Err(e) => {
+                self.len = 0;
+                return Some(Err(e));
+            },
             Ok(v) => v
         };
-        self.data = &self.data[msg.size()..];
+        self.data = self.data.get(msg.size()..).unwrap_or_default();
         self.len -= 1;
         Some(Ok(msg.into_inner()))
     }
@@ -95,11 +148,15 @@ impl<'a, Item: FromSlice<'a, Output = Item> + FromSliceWithOffsets<'a>> Iterator
         if self.len == 0 {
             return None;
         }
-        let msg = match Item::from_slice_with_offsets(&self.data[..]) {
-            Err(e) => return Some(Err(e)),
+        // Stop at the first error as the position of the next item is unknown.
+        let msg = match Item::from_slice_with_offsets(self.data) {
+            Err(e) => {
+                self.len = 0;
+                return Some(Err(e));
+            },
             Ok(v) => v
         };
-        self.data = &self.data[msg.size()..];
+        self.data = self.data.get(msg.size()..).unwrap_or_default();
         self.len -= 1;
         Some(Ok(msg.into_inner()))
     }
@@ -135,7 +192,43 @@ impl<'a, T: FromSlice<'a, Output: ToUsize>, Item> FromSlice<'a> for Unsized<T, I
         let msg = T::from_slice(slice)?;
         let control_size = msg.size();
         let len = msg.into_inner().to_usize();
-        let data = &slice[control_size..];
+        let data = slice.get(control_size..).ok_or(Error::Truncated)?;
         Ok(Message::new(slice.len(), unsafe { List::from_raw_parts(data, len) }))
     }
+
+    fn from_slice_with_limits(slice: &'a [u8], limiter: &mut Limiter) -> crate::message::Result<Message<Self::Output>> {
+        limiter.check_size(slice, slice.len())?;
+        let msg = Self::from_slice(slice)?;
+        let size = msg.size();
+        let list = msg.into_inner();
+        limiter.add_items(list.len())?;
+        Ok(Message::new(size, list))
+    }
+}
+
+impl<'a, T: Measure<'a> + FromSlice<'a, Output: ToUsize>, Item: Measure<'a>> Measure<'a> for Unsized<T, Item> {
+    fn measure(slice: &'a [u8]) -> crate::message::Result<Measurement> {
+        measure_items::<T, Item>(slice, &mut Progress::new())
+    }
+
+    fn measure_with_progress(slice: &'a [u8], progress: &mut Progress) -> crate::message::Result<Measurement> {
+        measure_items::<T, Item>(slice, progress)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T, Item: FromSlice<'a, Output: serde::Serialize>> serde::Serialize for List<&'a [u8], T, Item> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error, SerializeSeq};
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        let iter = Iter::<'a, Item> {
+            data: self.data,
+            len: self.len,
+            useless: PhantomData
+        };
+        for item in iter {
+            seq.serialize_element(&item.map_err(S::Error::custom)?)?;
+        }
+        seq.end()
+    }
 }
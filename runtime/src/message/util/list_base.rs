@@ -50,16 +50,23 @@ macro_rules! impl_list_base {
         impl<B: AsRef<[u8]>, T: WriteTo<Input: ToUsize + Sized>, Item> WriteTo for $t<B, T, Item> {
             type Input = $t<B, T, Item>;
 
-            fn write_to<W: std::io::Write>(input: &Self::Input, mut out: W) -> Result<(), Error> {
-                T::write_to(&T::Input::from_usize(input.len), &mut out)?;
+            fn write_to<W: crate::message::Write>(input: &Self::Input, mut out: W) -> Result<(), Error> {
+                T::write_to(&T::Input::try_from_usize(input.len).ok_or(Error::LengthExceeded)?, out.reborrow())?;
                 out.write_all(input.data.as_ref())?;
                 Ok(())
             }
         }
 
-        impl<B: std::io::Write, T, I> $t<B, T, I> {
+        impl<B: AsRef<[u8]>, T: crate::message::WriteToSlice<Input: ToUsize + Sized>, Item> crate::message::WriteToSlice for $t<B, T, Item> {
+            fn write_to_slice(input: &Self::Input, out: &mut [u8]) -> Result<usize, Error> {
+                let size = T::write_to_slice(&T::Input::try_from_usize(input.len).ok_or(Error::LengthExceeded)?, out)?;
+                Ok(size + crate::message::util::common::write_bytes(input.data.as_ref(), &mut out[size..])?)
+            }
+        }
+
+        impl<B: crate::message::Write, T, I> $t<B, T, I> {
             pub fn write_item<Item: WriteTo<Input = Item>>(&mut self, item: &Item) -> Result<(), Error> {
-                Item::write_to(item, &mut self.data)?;
+                Item::write_to(item, self.data.reborrow())?;
                 self.len += 1;
                 Ok(())
             }
@@ -72,6 +79,26 @@ macro_rules! impl_list_base {
             }
         }
 
+        impl<B: crate::message::Write, T, Item: WriteTo> $t<B, T, Item> {
+            /// Encodes a value with the item codec of this list.
+            pub fn write_value(&mut self, value: &Item::Input) -> Result<(), Error> {
+                Item::write_to(value, self.data.reborrow())?;
+                self.len += 1;
+                Ok(())
+            }
+        }
+
+        impl<'a, T, Item: WriteTo> $t<&'a [u8], T, Item> {
+            /// Encodes the given items into the arena and returns a list borrowing them.
+            pub fn from_items<V: core::borrow::Borrow<Item::Input>>(arena: &'a crate::message::util::Arena, items: impl IntoIterator<Item = Result<V, Error>>) -> Result<Self, Error> {
+                let mut list = $t::<alloc::vec::Vec<u8>, T, Item>::new(alloc::vec::Vec::new());
+                for item in items {
+                    list.write_value(item?.borrow())?;
+                }
+                Ok(unsafe { $t::from_raw_parts(arena.alloc(list.data), list.len) })
+            }
+        }
+
         impl<B: AsRef<[u8]>, T, Item> $t<B, T, Item> {
             pub fn to_ref<Item1>(&self) -> $t<&[u8], T, Item1> {
                 unsafe { $t::from_raw_parts(self.data.as_ref(), self.len) }
@@ -32,9 +32,11 @@ pub mod list;
 mod size;
 pub mod array;
 mod list_base;
+mod arena;
 
 pub use common::*;
 pub use string::*;
 pub use list::List;
 pub use array::Array;
 pub use size::size_of;
+pub use arena::Arena;
@@ -26,19 +26,14 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::io::Write;
-use std::marker::PhantomData;
-use crate::message::WriteTo;
+use core::marker::PhantomData;
+use crate::message::{Write, WriteTo};
 
 struct Counter(usize);
 
 impl Write for Counter {
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+    fn write_all(&mut self, buf: &[u8]) -> crate::message::Result<()> {
         self.0 += buf.len();
-        Ok(buf.len())
-    }
-
-    fn flush(&mut self) -> std::io::Result<()> {
         Ok(())
     }
 }
@@ -48,7 +43,7 @@ pub struct SizeOf<Msg>(PhantomData<Msg>);
 impl<Msg: WriteTo<Input = Msg>> SizeOf<Msg> {
     pub fn get(msg: &Msg) -> crate::message::Result<usize> {
         let mut counter = Counter(0);
-        Msg::write_to(msg, &mut counter)?;
+        Msg::write_to(msg, counter.reborrow())?;
         Ok(counter.0)
     }
 }
@@ -26,11 +26,12 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::io::Write;
-use std::marker::PhantomData;
-use crate::message::{Error, FromSlice, Message, WriteTo};
+use core::marker::PhantomData;
+use crate::message::{Error, FromSlice, Measure, Measurement, Message, Progress, Write, WriteTo, WriteToSlice};
+use crate::message::util::common::{measure_fixed, measure_len, write_bytes};
 use crate::util::ToUsize;
 
+#[derive(Debug, Copy, Clone)]
 pub struct NullTerminatedString;
 
 impl<'a> FromSlice<'a> for NullTerminatedString {
@@ -40,12 +41,30 @@ impl<'a> FromSlice<'a> for NullTerminatedString {
         let string = slice.iter().enumerate().find_map(|(id, v)| match *v == 0x0 {
             true => Some(id),
             false => None
-        }).map(|pos| std::str::from_utf8(&slice[0..pos]))
+        }).map(|pos| core::str::from_utf8(&slice[0..pos]))
             .ok_or(Error::Truncated)?.map_err(|_| Error::InvalidUtf8)?;
         Ok(Message::new(string.len() + 1, string))
     }
 }
 
+impl<'a> Measure<'a> for NullTerminatedString {
+    fn measure(slice: &'a [u8]) -> Result<Measurement, Error> {
+        Self::measure_with_progress(slice, &mut Progress::new())
+    }
+
+    fn measure_with_progress(slice: &'a [u8], progress: &mut Progress) -> Result<Measurement, Error> {
+        // Bytes scanned by the previous measurement are known not to be the terminator.
+        let [scanned] = progress.resume().unwrap_or_default();
+        match slice.get(scanned..).and_then(|v| v.iter().position(|v| *v == 0x0)) {
+            Some(pos) => Ok(Measurement::Complete(scanned + pos + 1)),
+            None => {
+                progress.save([slice.len()]);
+                Ok(Measurement::Incomplete(1))
+            }
+        }
+    }
+}
+
 impl WriteTo for NullTerminatedString {
     type Input = str;
 
@@ -56,6 +75,15 @@ impl WriteTo for NullTerminatedString {
     }
 }
 
+impl WriteToSlice for NullTerminatedString {
+    #[inline]
+    fn write_to_slice(input: &Self::Input, out: &mut [u8]) -> Result<usize, Error> {
+        let size = write_bytes(input.as_bytes(), out)?;
+        Ok(size + write_bytes(&[0x0], &mut out[size..])?)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
 pub struct VarcharString<T>(PhantomData<T>);
 
 impl<'a, T: FromSlice<'a, Output: ToUsize>> FromSlice<'a> for VarcharString<T> {
@@ -64,18 +92,35 @@ impl<'a, T: FromSlice<'a, Output: ToUsize>> FromSlice<'a> for VarcharString<T> {
     fn from_slice(slice: &'a [u8]) -> Result<Message<Self::Output>, Error> {
         let msg = T::from_slice(slice)?;
         let size = msg.size();
-        let subslice = &slice[size..size + msg.into_inner().to_usize()];
-        let string = std::str::from_utf8(subslice).map_err(|_| Error::InvalidUtf8)?;
+        let end = size.checked_add(msg.into_inner().to_usize()).ok_or(Error::Truncated)?;
+        let subslice = slice.get(size..end).ok_or(Error::Truncated)?;
+        let string = core::str::from_utf8(subslice).map_err(|_| Error::InvalidUtf8)?;
         Ok(Message::new(size + string.len(), string))
     }
 }
 
+impl<'a, T: Measure<'a> + FromSlice<'a, Output: ToUsize>> Measure<'a> for VarcharString<T> {
+    fn measure(slice: &'a [u8]) -> Result<Measurement, Error> {
+        match measure_len::<T>(slice)? {
+            Ok((size, len)) => Ok(measure_fixed(slice, size.saturating_add(len))),
+            Err(v) => Ok(v)
+        }
+    }
+}
+
 impl<T: WriteTo<Input: ToUsize + Sized>> WriteTo for VarcharString<T> {
     type Input = str;
 
     fn write_to<W: Write>(input: &Self::Input, mut out: W) -> Result<(), Error> {
-        T::write_to(&T::Input::from_usize(input.len()), &mut out)?;
+        T::write_to(&T::Input::try_from_usize(input.len()).ok_or(Error::LengthExceeded)?, out.reborrow())?;
         out.write_all(input.as_bytes())?;
         Ok(())
     }
 }
+
+impl<T: WriteToSlice<Input: ToUsize + Sized>> WriteToSlice for VarcharString<T> {
+    fn write_to_slice(input: &Self::Input, out: &mut [u8]) -> Result<usize, Error> {
+        let size = T::write_to_slice(&T::Input::try_from_usize(input.len()).ok_or(Error::LengthExceeded)?, out)?;
+        Ok(size + write_bytes(input.as_bytes(), &mut out[size..])?)
+    }
+}
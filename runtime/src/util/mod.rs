@@ -28,6 +28,8 @@
 
 mod scalar;
 
+use core::fmt::{Display, Formatter};
+
 pub use scalar::*;
 
 pub trait Size {
@@ -44,6 +46,19 @@ impl<T: FixedSize> Size for T {
     }
 }
 
+/// The error returned when parsing an enum from a string which matches none of its variant names.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnknownVariant;
+
+impl Display for UnknownVariant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unknown enum variant")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownVariant {}
+
 pub trait MultiOptionExt {
     type Output;
 
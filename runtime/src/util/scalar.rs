@@ -29,6 +29,17 @@
 pub trait ToUsize {
     fn to_usize(self) -> usize;
     fn from_usize(value: usize) -> Self;
+    fn try_from_usize(value: usize) -> Option<Self> where Self: Sized;
+
+    /// Converts this value to a u64 without going through usize, which may be narrower.
+    fn to_u64(self) -> u64 where Self: Sized {
+        self.to_usize() as u64
+    }
+
+    /// Converts a u64 to this type without going through usize, which may be narrower.
+    fn from_u64(value: u64) -> Self where Self: Sized {
+        Self::from_usize(value as usize)
+    }
 }
 
 macro_rules! impl_to_usize {
@@ -41,9 +52,18 @@ macro_rules! impl_to_usize {
                 fn from_usize(value: usize) -> Self {
                     value as _
                 }
+                fn try_from_usize(value: usize) -> Option<Self> {
+                    <$t>::try_from(value).ok()
+                }
+                fn to_u64(self) -> u64 {
+                    self as _
+                }
+                fn from_u64(value: u64) -> Self {
+                    value as _
+                }
             }
         )*
     };
 }
 
-impl_to_usize!(u8 u16 u32 u64);
+impl_to_usize!(u8 u16 u32 u64 usize);
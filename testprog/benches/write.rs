@@ -0,0 +1,82 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::hint::black_box;
+use bp3d_proto::message::{WriteTo, WriteToSlice};
+use criterion::{criterion_group, criterion_main, Criterion};
+use testprog::enums::Header;
+use testprog::lists::{SpanRun, SpanRunVars, Times};
+use testprog::test::{Test, Test1};
+use testprog::unions::{Item, Value};
+use testprog::values::{ValueInt16, ValueString, SIZE_VALUEUINT64};
+
+fn bench<T: WriteTo<Input = T> + WriteToSlice>(c: &mut Criterion, name: &str, msg: &T) {
+    let mut group = c.benchmark_group(name);
+    let mut vec = Vec::with_capacity(256);
+    group.bench_function("io_write", |b| b.iter(|| {
+        vec.clear();
+        T::write_to(black_box(msg), &mut vec).unwrap();
+        black_box(&vec);
+    }));
+    let mut buffer = [0; 256];
+    group.bench_function("write_to_slice", |b| b.iter(|| {
+        let size = T::write_to_slice(black_box(msg), &mut buffer).unwrap();
+        black_box(&buffer[..size]);
+    }));
+    group.finish();
+}
+
+fn strings(c: &mut Criterion) {
+    let msg = Test {
+        p1: Some(Test1 {
+            s1: "this is a test",
+            p1: 42
+        }),
+        s1: "a test",
+        s2: "hello world",
+    };
+    bench(c, "strings", &msg);
+}
+
+fn lists(c: &mut Criterion) {
+    let mut value: [u8; SIZE_VALUEUINT64] = [0; SIZE_VALUEUINT64];
+    let mut header = Header::new_on_stack();
+    let mut times = Times::new_on_stack();
+    times.set_start(42).set_end(4242);
+    let mut list = SpanRunVars::new(Vec::new());
+    list.write_item(&Item::new(&mut header, "test", Value::String(ValueString { data: "this is a test" }))).unwrap();
+    list.write_item(&Item::new(&mut header, "test1", Value::Int16(ValueInt16::from(&mut value).set_data(-4242).to_ref()))).unwrap();
+    let msg = SpanRun {
+        times: times.to_ref(),
+        vars: list.to_ref(),
+    };
+    bench(c, "lists", &msg);
+}
+
+criterion_group!(benches, strings, lists);
+criterion_main!(benches);
@@ -41,18 +41,20 @@ fn main() {
         loader.load("./src/unions2.json5")?;
         loader.load("./src/arrays.json5")?;
         Ok(())
-    }, |protoc| protoc.set_reads_messages(true).set_writes_messages(true));
+    }, |protoc| protoc.set_reads_messages(true).set_writes_messages(true).set_owned_messages(true).set_serde(true));
     generate_rust(|loader| {
         loader.import("./src/enums.json5", "crate::enums")?;
         loader.import("./src/values.json5", "crate::values")?;
         loader.load("./src/unions.json5")?;
         Ok(())
-    }, |protoc| protoc.set_reads_messages(true).set_writes_messages(true));
+    }, |protoc| protoc.set_reads_messages(true).set_writes_messages(true).set_owned_messages(true).set_serde(true));
     generate_rust(|loader| {
         loader.import("./src/enums.json5", "crate::enums")?;
         loader.import("./src/values.json5", "crate::values")?;
         loader.import("./src/unions.json5", "crate::unions")?;
         loader.load("./src/lists.json5")?;
+        loader.load("./src/scalars.json5")?;
+        loader.load("./src/nested.json5")?;
         Ok(())
-    }, |protoc| protoc.set_reads_messages(true).set_writes_messages(true));
+    }, |protoc| protoc.set_reads_messages(true).set_writes_messages(true).set_owned_messages(true).set_serde(true));
 }
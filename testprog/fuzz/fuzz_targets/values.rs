@@ -0,0 +1,47 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use testprog_fuzz::{check, check_message};
+use testprog::values::{ValueDouble, ValueFloat, ValueInt16, ValueInt32, ValueInt64, ValueInt8, ValueString, ValueUInt16, ValueUInt32, ValueUInt64, ValueUInt8};
+
+fuzz_target!(|data: &[u8]| {
+    check_message::<ValueString>(data);
+    check::<ValueInt8<&[u8]>>(data);
+    check::<ValueInt16<&[u8]>>(data);
+    check::<ValueInt32<&[u8]>>(data);
+    check::<ValueInt64<&[u8]>>(data);
+    check::<ValueUInt8<&[u8]>>(data);
+    check::<ValueUInt16<&[u8]>>(data);
+    check::<ValueUInt32<&[u8]>>(data);
+    check::<ValueUInt64<&[u8]>>(data);
+    check::<ValueFloat<&[u8]>>(data);
+    check::<ValueDouble<&[u8]>>(data);
+});
@@ -0,0 +1,66 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Shared checks of the fuzz targets, one per testprog protocol.
+//!
+//! Run a target with `cargo +nightly fuzz run <protocol>` from this directory.
+
+use bp3d_proto::message::{DecodeLimits, Error, FromSlice, FromSliceWithOffsets, Measure, Measurement};
+use bp3d_proto::serde::Serialize;
+
+/// Decodes the input as a T and walks every decoded field.
+///
+/// Also checks that measuring agrees with decoding: a complete measurement must fit in the input
+/// and decoding the measured bytes must never report a truncated input. Decoding without limits
+/// must agree with decoding with unlimited [DecodeLimits].
+pub fn check<'a, T: FromSlice<'a, Output: Serialize> + Measure<'a>>(data: &'a [u8]) {
+    if let Ok(msg) = T::from_slice(data) {
+        assert!(msg.size() <= data.len());
+        let size = msg.size();
+        let _ = serde_json::to_writer(std::io::sink(), &msg.into_inner());
+        assert_eq!(DecodeLimits::new().from_slice::<T>(data).map(|v| v.size()).ok(), Some(size));
+    } else {
+        assert!(DecodeLimits::new().from_slice::<T>(data).is_err());
+    }
+    match T::measure(data) {
+        Ok(Measurement::Complete(size)) => {
+            assert!(size <= data.len());
+            assert!(!matches!(T::from_slice(&data[..size]), Err(Error::Truncated)));
+        },
+        Ok(Measurement::Incomplete(n)) => assert!(n > 0),
+        Err(_) => ()
+    }
+}
+
+/// Same as [check] but also decodes field offsets, which only messages provide.
+pub fn check_message<'a, T: FromSliceWithOffsets<'a, Output: Serialize> + Measure<'a>>(data: &'a [u8]) {
+    check::<T>(data);
+    if let Ok(msg) = T::from_slice_with_offsets(data) {
+        assert!(msg.size() <= data.len());
+    }
+}
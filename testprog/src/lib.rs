@@ -26,6 +26,8 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 include!(env!("BP3D_PROTOC_TEST"));
 include!(env!("BP3D_PROTOC_STRUCTS"));
 include!(env!("BP3D_PROTOC_BITS"));
@@ -38,3 +40,5 @@ include!(env!("BP3D_PROTOC_UNIONS2"));
 include!(env!("BP3D_PROTOC_ARRAYS"));
 include!(env!("BP3D_PROTOC_LISTS"));
 include!(env!("BP3D_PROTOC_BITS2"));
+include!(env!("BP3D_PROTOC_SCALARS"));
+include!(env!("BP3D_PROTOC_NESTED"));
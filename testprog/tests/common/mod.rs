@@ -0,0 +1,48 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use testprog::enums::Header;
+use testprog::lists::{SpanRun, SpanRunVars, Times};
+use testprog::unions::{Item, Value};
+use testprog::values::{ValueInt16, ValueString, SIZE_VALUEUINT64};
+
+/// Builds a SpanRun from 42 to 4242 holding a "test" string item and a "test1" int16 item, and
+/// passes it to f.
+pub fn write_span_run<F: FnOnce(SpanRun) -> bp3d_proto::message::Result<()>>(f: F) {
+    let mut value: [u8; SIZE_VALUEUINT64] = [0; SIZE_VALUEUINT64];
+    let mut header = Header::new_on_stack();
+    let mut times = Times::new_on_stack();
+    times.set_start(42).set_end(4242);
+    let mut list = SpanRunVars::new(Vec::new());
+    list.write_item(&Item::new(&mut header, "test", Value::String(ValueString { data: "this is a test" }))).unwrap();
+    list.write_item(&Item::new(&mut header, "test1", Value::Int16(ValueInt16::from(&mut value).set_data(-4242).to_ref()))).unwrap();
+    f(SpanRun {
+        times: times.to_ref(),
+        vars: list.to_ref(),
+    }).unwrap()
+}
@@ -26,8 +26,8 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use bp3d_proto::message::{FromSlice, WriteTo};
-use testprog::arrays::{Msg, Msg1, MsgItems, Msg1Items};
+use bp3d_proto::message::{Error, FromSlice, WriteTo};
+use testprog::arrays::{Block, BlockItems, BlockSamples, Item, Msg, Msg1, MsgItems, Msg1Items, Pair, Pairs, PairsPairs};
 
 #[test]
 fn msg() {
@@ -119,3 +119,72 @@ fn msg1_2() {
         assert!(msg.items.is_none());
     }
 }
+
+#[test]
+fn block() {
+    let mut msg_buffer = Vec::new();
+    {
+        let mut buffer: [u8; 3 * 4] = [0; 3 * 4];
+        let mut arr = BlockItems::from_parts(&mut buffer, 4).unwrap();
+        for (i, mut v) in arr.iter_mut().enumerate() {
+            v.set_id(i as u8).set_count(i as u16 * 100).set_slot(7);
+        }
+        let mut samples = BlockSamples::new(Vec::new());
+        for i in 0..16 {
+            samples.write_value(&(i - 8)).unwrap();
+        }
+        let msg = Block {
+            items: arr.to_ref(),
+            samples: samples.to_ref()
+        };
+        Block::write_to(&msg, &mut msg_buffer).unwrap();
+    }
+    assert_eq!(msg_buffer.len(), 3 * 4 + 2 * 16);
+    {
+        let msg = Block::from_slice(&msg_buffer).unwrap();
+        assert_eq!(msg_buffer.len(), msg.size());
+        let msg = msg.into_inner();
+        let items = BlockItems::from_array(msg.items);
+        assert_eq!(items.len(), 4);
+        assert_eq!(items.get(3).get_id(), 3);
+        assert_eq!(items.get(3).get_count(), 300);
+        assert_eq!(items.get(0).get_slot(), 7);
+        let samples: Vec<i16> = msg.samples.iter().collect::<Result<_, _>>().unwrap();
+        assert_eq!(samples, (-8..8).collect::<Vec<i16>>());
+    }
+}
+
+#[test]
+fn block_length_mismatch() {
+    let mut buffer: [u8; 3 * 2] = [0; 3 * 2];
+    let arr = BlockItems::from_parts(&mut buffer, 2).unwrap();
+    let samples = BlockSamples::new(Vec::new());
+    let msg = Block {
+        items: arr.to_ref(),
+        samples: samples.to_ref()
+    };
+    let mut msg_buffer = Vec::new();
+    assert!(matches!(Block::write_to(&msg, &mut msg_buffer), Err(Error::LengthMismatch)));
+    assert!(matches!(Block::from_slice(&[0; 3 * 4 + 2 * 15]), Err(Error::Truncated)));
+}
+
+#[test]
+fn pairs() {
+    let mut pairs = PairsPairs::new(Vec::new());
+    for id in [1, 2] {
+        let mut item = Item::new_on_stack();
+        item.set_id(id).set_count(10).set_slot(1);
+        pairs.write_item(&Pair { id, item: item.to_ref() }).unwrap();
+    }
+    let msg = Pairs { pairs: pairs.to_ref() };
+    let mut msg_buffer = Vec::new();
+    Pairs::write_to(&msg, &mut msg_buffer).unwrap();
+    assert_eq!(msg_buffer.len(), 2 * (1 + 3));
+    let msg = Pairs::from_slice(&msg_buffer).unwrap();
+    assert_eq!(msg_buffer.len(), msg.size());
+    let msg = msg.into_inner();
+    let pairs: Vec<Pair> = msg.pairs.iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(pairs.len(), 2);
+    assert_eq!(pairs[1].id, 2);
+    assert_eq!(pairs[1].item.get_id(), 2);
+}
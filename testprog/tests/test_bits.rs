@@ -27,7 +27,8 @@
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use bp3d_proto::util::Size;
-use testprog::bits::Numbers;
+use bp3d_proto::message::WriteTo;
+use testprog::bits::{Numbers, Packed};
 use testprog::bits2::Numbers as Numbers2;
 
 #[test]
@@ -135,3 +136,34 @@ fn numbers_raw2() {
         cur_a += 1;
     }
 }
+
+#[test]
+fn packed_le() {
+    let mut packed = Packed::new_on_stack();
+    assert_eq!(packed.size(), 13);
+    assert_eq!(packed.get_nibbles().len(), 12);
+    packed.set_tag(0xA).set_tail(3);
+    packed.get_nibbles_mut().set_raw(0, 0xB).set_raw(11, 0x7);
+    for i in 0..10 {
+        packed.get_fives_mut().set_raw(i, i as u8 * 3);
+    }
+    assert_eq!(packed.get_tag(), 0xA);
+    assert_eq!(packed.get_tail(), 3);
+    assert_eq!(packed.get_nibbles().get_raw(0), 0xB);
+    assert_eq!(packed.get_nibbles().get_raw(1), 0);
+    assert_eq!(packed.get_nibbles().get_raw(11), 0x7);
+    let fives: Vec<u8> = packed.get_fives().iter_raw().collect();
+    assert_eq!(fives, [0, 3, 6, 9, 12, 15, 18, 21, 24, 27]);
+    let mut bytes = Vec::new();
+    Packed::write_to(&packed.to_ref(), &mut bytes).unwrap();
+    assert_eq!(bytes[0], 0xBA);
+    assert_eq!(bytes[6] & 0x0F, 0x7);
+    assert_eq!(bytes[12] >> 6, 3);
+}
+
+#[test]
+#[should_panic]
+fn packed_out_of_bounds() {
+    let packed = Packed::new_on_stack();
+    packed.get_fives().get_raw(10);
+}
@@ -26,7 +26,7 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use testprog::enums::{Header, Type};
+use testprog::enums::{Header, Record, Severity, Type};
 
 #[test]
 fn basic() {
@@ -39,3 +39,52 @@ fn basic() {
     header.set_type(Type::Double);
     assert_eq!(header.get_type().unwrap(), Type::Double);
 }
+
+#[test]
+fn sparse() {
+    let mut record = Record::new_on_stack();
+    record.set_severity(Severity::Error).set_count(3);
+    assert_eq!(record.get_raw_severity(), 5);
+    assert_eq!(record.get_severity(), Some(Severity::Error));
+    for raw in [2, 3, 4, 6, 7] {
+        record.set_raw_severity(raw);
+        assert_eq!(record.get_severity(), None);
+        assert_eq!(record.get_raw_severity(), raw);
+    }
+    assert_eq!(record.get_count(), 3);
+}
+
+#[test]
+fn try_from() {
+    assert_eq!(Severity::try_from(1u8), Ok(Severity::Info));
+    assert_eq!(Severity::try_from(5u64), Ok(Severity::Error));
+    assert_eq!(Severity::try_from(3u16), Err(3));
+    assert_eq!(Severity::try_from(0x105u32), Err(0x105));
+    assert_eq!(Type::try_from(11u8), Ok(Type::Double));
+    assert_eq!(Type::try_from(12u8), Err(12));
+}
+
+#[test]
+fn names() {
+    assert_eq!(Severity::Info.to_string(), "Info");
+    assert_eq!(Type::UInt16.name(), "UInt16");
+    assert_eq!("Error".parse::<Severity>(), Ok(Severity::Error));
+    assert_eq!("error".parse::<Severity>(), Err(bp3d_proto::util::UnknownVariant));
+    for v in Type::ALL {
+        assert_eq!(v.name().parse::<Type>(), Ok(v));
+    }
+}
+
+#[test]
+fn raw() {
+    assert_eq!(size_of::<Severity>(), 1);
+    assert_eq!(Severity::ALL, [Severity::Debug, Severity::Info, Severity::Error]);
+    assert_eq!(u8::from(Severity::Error), 5);
+    assert_eq!(u64::from(Type::Double), 11);
+    for v in Severity::ALL {
+        assert_eq!(Severity::try_from(u8::from(v)), Ok(v));
+    }
+    assert!(Severity::Debug < Severity::Error);
+    let set: std::collections::HashSet<Type> = Type::ALL.into_iter().collect();
+    assert_eq!(set.len(), Type::ALL.len());
+}
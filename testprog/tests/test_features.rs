@@ -0,0 +1,100 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use bp3d_proto::message::{Error, FromSlice, Write, WriteTo};
+use testprog::enums::{Header, Type};
+use testprog::unions::{Item, Value};
+use testprog::values::ValueString;
+
+// The tests are always built with std enabled through the dev-dependencies, so they check that
+// code written against the core-only API keeps building when another crate enables std.
+
+// A sink as written by a no_std user, which only implements the core-only trait.
+struct Sink {
+    data: Vec<u8>,
+    capacity: usize
+}
+
+#[derive(Debug)]
+struct SinkFull;
+
+impl core::fmt::Display for SinkFull {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("sink full")
+    }
+}
+
+impl core::error::Error for SinkFull {}
+
+impl Write for Sink {
+    fn write_all(&mut self, buf: &[u8]) -> bp3d_proto::message::Result<()> {
+        if self.data.len() + buf.len() > self.capacity {
+            return Err(Error::Io(Box::new(SinkFull)));
+        }
+        self.data.extend_from_slice(buf);
+        Ok(())
+    }
+}
+
+// Must list every variant so that a variant added or removed by a feature fails to build.
+fn describe(e: &Error) -> &'static str {
+    match e {
+        Error::InvalidUtf8 => "invalid utf8",
+        Error::Truncated => "truncated",
+        Error::InvalidUnionDiscriminant(_) => "invalid union discriminant",
+        Error::InvalidEnumValue(_) => "invalid enum value",
+        Error::BufferTooSmall => "buffer too small",
+        Error::LengthExceeded => "length exceeded",
+        Error::LengthMismatch => "length mismatch",
+        Error::LimitExceeded(_) => "limit exceeded",
+        Error::Io(_) => "io"
+    }
+}
+
+#[test]
+fn core_only_sink() {
+    let mut header = Header::new_on_stack();
+    let item = Item::new(&mut header, "test", Value::String(ValueString { data: "this is a test" }));
+    let mut sink = Sink { data: Vec::new(), capacity: 64 };
+    Item::write_to(&item, sink.reborrow()).unwrap();
+    let mut buffer = Vec::new();
+    Item::write_to(&item, &mut buffer).unwrap();
+    assert_eq!(sink.data, buffer);
+    let msg = Item::from_slice(&sink.data).unwrap().into_inner();
+    assert_eq!(msg.header().get_type(), Some(Type::String));
+}
+
+#[test]
+fn core_only_sink_error() {
+    let mut header = Header::new_on_stack();
+    let item = Item::new(&mut header, "test", Value::String(ValueString { data: "this is a test" }));
+    let sink = Sink { data: Vec::new(), capacity: 4 };
+    let err = Item::write_to(&item, sink).unwrap_err();
+    assert_eq!(describe(&err), "io");
+    assert_eq!(err.to_string(), "io error: sink full");
+}
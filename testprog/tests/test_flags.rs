@@ -0,0 +1,82 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use testprog::views::{Access, Features, Permissions};
+
+#[test]
+fn contains_insert_remove() {
+    let mut flags = Permissions::READ | Permissions::WRITE;
+    assert!(flags.contains(Permissions::READ));
+    assert!(!flags.contains(Permissions::READ | Permissions::EXECUTE));
+    assert!(flags.intersects(Permissions::READ | Permissions::EXECUTE));
+    flags.insert(Permissions::EXECUTE);
+    assert_eq!(flags, Permissions::all());
+    flags.remove(Permissions::READ | Permissions::WRITE);
+    assert_eq!(flags, Permissions::EXECUTE);
+    flags.set(Permissions::EXECUTE, false);
+    assert!(flags.is_empty());
+    assert_eq!(!Permissions::READ, Permissions::WRITE | Permissions::EXECUTE);
+}
+
+#[test]
+fn from_bits() {
+    assert_eq!(Permissions::all().bits(), 0b111);
+    assert_eq!(Features::all().bits(), 0x203);
+    assert_eq!(Permissions::from_bits(0b101), Some(Permissions::READ | Permissions::EXECUTE));
+    assert_eq!(Permissions::from_bits(0b1000), None);
+    assert_eq!(Permissions::from_bits_truncate(0b1001), Permissions::READ);
+    assert_eq!(Permissions::from_bits_retain(0b1001).bits(), 0b1001);
+}
+
+#[test]
+fn getters_setters() {
+    let mut access = Access::new_on_stack();
+    access.set_owner(Permissions::READ | Permissions::WRITE)
+        .set_features(Features::SIGNED | Features::COMPRESSED)
+        .set_group(Permissions::READ);
+    assert_eq!(access.get_raw_owner(), 0b011);
+    assert_eq!(access.get_raw_features(), 0x201);
+    assert_eq!(access.get_raw_group(), 0b001);
+    assert_eq!(access.get_owner(), Permissions::READ | Permissions::WRITE);
+    assert_eq!(access.get_features(), Features::SIGNED | Features::COMPRESSED);
+    assert_eq!(access.get_group(), Permissions::READ);
+    let mut group = access.get_group();
+    group.insert(Permissions::EXECUTE);
+    access.set_group(group);
+    assert_eq!(access.get_raw_group(), 0b101);
+}
+
+#[test]
+fn unknown_bits() {
+    let mut access = Access::new_on_stack();
+    access.set_raw_owner(0b111);
+    access.set_raw_features(0x1003);
+    assert_eq!(access.get_owner(), Permissions::all());
+    assert!(access.get_features().contains(Features::COMPRESSED | Features::ENCRYPTED));
+    assert_eq!(access.get_features().bits(), 0x1003);
+    assert_eq!(Features::from_bits(access.get_raw_features()), None);
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use bp3d_proto::framed::{FramedReader, FramedWriter, MessageCodec};
+use bp3d_proto::message::{Error, FromSlice};
+use futures::{FutureExt, SinkExt, StreamExt};
+use testprog::test::{Test, Test1};
+use tokio::io::AsyncWriteExt;
+use tokio_util::codec::{FramedRead, FramedWrite};
+
+fn messages() -> [Test<'static>; 2] {
+    [
+        Test {
+            p1: Some(Test1 {
+                s1: "this is a test",
+                p1: 42
+            }),
+            s1: "a test",
+            s2: "hello world",
+        },
+        Test {
+            p1: None,
+            s1: "",
+            s2: "second",
+        }
+    ]
+}
+
+fn assert_eq_test(a: &Test, b: &Test) {
+    assert_eq!(a.s1, b.s1);
+    assert_eq!(a.s2, b.s2);
+    assert_eq!(a.p1.as_ref().map(|v| (v.s1, v.p1)), b.p1.as_ref().map(|v| (v.s1, v.p1)));
+}
+
+#[tokio::test]
+async fn reader_writer() {
+    let (client, server) = tokio::io::duplex(64);
+    let writer = tokio::spawn(async move {
+        let mut writer = FramedWriter::new(client);
+        for msg in &messages() {
+            writer.write(msg).await.unwrap();
+        }
+        writer.flush().await.unwrap();
+    });
+    let mut reader = FramedReader::new(server);
+    for expected in &messages() {
+        let msg = reader.read::<Test>().await.unwrap().unwrap();
+        assert_eq_test(&msg, expected);
+    }
+    writer.await.unwrap();
+    assert!(reader.read::<Test>().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn reader_errors() {
+    let (mut client, server) = tokio::io::duplex(64);
+    client.write_all(&[0xFF, 0xFF, 0xFF, 0x00]).await.unwrap();
+    let mut reader = FramedReader::new(server);
+    reader.set_max_frame_size(1024);
+    assert!(matches!(reader.read_frame().await, Err(Error::Io(_))));
+
+    let (mut client, server) = tokio::io::duplex(64);
+    client.write_all(&[0x08, 0x00, 0x00, 0x00, 0x01, 0x02]).await.unwrap();
+    drop(client);
+    let mut reader = FramedReader::new(server);
+    assert!(matches!(reader.read_frame().await, Err(Error::Truncated)));
+}
+
+#[tokio::test]
+async fn reader_cancel() {
+    let (mut client, server) = tokio::io::duplex(64);
+    let mut reader = FramedReader::new(server);
+    client.write_all(&[0x03, 0x00]).await.unwrap();
+    assert!(reader.read_frame().now_or_never().is_none());
+    client.write_all(&[0x00, 0x00, 0x01]).await.unwrap();
+    assert!(reader.read_frame().now_or_never().is_none());
+    client.write_all(&[0x02, 0x03]).await.unwrap();
+    assert_eq!(reader.read_frame().await.unwrap(), Some(&[0x01, 0x02, 0x03][..]));
+    drop(client);
+    assert_eq!(reader.read_frame().await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn codec() {
+    let (client, server) = tokio::io::duplex(64);
+    let writer = tokio::spawn(async move {
+        let mut sink = FramedWrite::new(client, MessageCodec::new());
+        for msg in &messages() {
+            sink.send(msg).await.unwrap();
+        }
+    });
+    let mut stream = FramedRead::new(server, MessageCodec::new());
+    for expected in &messages() {
+        let frame = stream.next().await.unwrap().unwrap();
+        let msg = Test::from_slice(&frame).unwrap().into_inner();
+        assert_eq_test(&msg, expected);
+    }
+    writer.await.unwrap();
+    assert!(stream.next().await.is_none());
+}
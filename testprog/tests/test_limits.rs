@@ -0,0 +1,105 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod common;
+
+use bp3d_proto::message::{DecodeLimits, Error, FromSlice, Limit, WriteTo};
+use testprog::lists::{Dataset, DatasetRuns, SpanRun, SIZE_TIMES};
+use common::write_span_run;
+
+fn assert_limit<T>(res: bp3d_proto::message::Result<T>, limit: Limit) {
+    match res {
+        Err(Error::LimitExceeded(v)) => assert_eq!(v, limit),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("limit {} not enforced", limit)
+    }
+}
+
+#[test]
+fn unlimited() {
+    let mut data = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut data));
+    let msg = DecodeLimits::new().from_slice::<SpanRun>(&data).unwrap();
+    assert_eq!(msg.size(), data.len());
+    let msg = msg.into_inner();
+    assert_eq!(msg.times.get_end(), 4242);
+    assert_eq!(msg.vars.iter().map(|v| v.unwrap().name).collect::<Vec<_>>(), ["test", "test1"]);
+}
+
+#[test]
+fn max_items() {
+    let mut data = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut data));
+    assert!(DecodeLimits::new().set_max_items(2).from_slice::<SpanRun>(&data).is_ok());
+    assert_limit(DecodeLimits::new().set_max_items(1).from_slice::<SpanRun>(&data), Limit::Items);
+    // The item count is rejected before walking the items.
+    data[SIZE_TIMES] = 0xFF;
+    assert!(matches!(SpanRun::from_slice(&data), Err(Error::Truncated)));
+    assert_limit(DecodeLimits::new().set_max_items(16).from_slice::<SpanRun>(&data), Limit::Items);
+}
+
+#[test]
+fn max_items_unsized() {
+    let mut data = Vec::new();
+    let mut list = DatasetRuns::new(Vec::new());
+    write_span_run(|msg| list.write_item(&msg));
+    write_span_run(|msg| list.write_item(&msg));
+    Dataset::write_to(&Dataset { runs: list.to_ref() }, &mut data).unwrap();
+    // Runs of a top level message are only counted as they are decoded lazily.
+    assert!(DecodeLimits::new().set_max_items(2).from_slice::<Dataset>(&data).is_ok());
+    assert_limit(DecodeLimits::new().set_max_items(1).from_slice::<Dataset>(&data), Limit::Items);
+}
+
+#[test]
+fn max_depth() {
+    let mut data = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut data));
+    // SpanRun > Item > ValueString
+    assert!(DecodeLimits::new().set_max_depth(3).from_slice::<SpanRun>(&data).is_ok());
+    assert_limit(DecodeLimits::new().set_max_depth(2).from_slice::<SpanRun>(&data), Limit::Depth);
+}
+
+#[test]
+fn max_size() {
+    let mut data = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut data));
+    assert!(DecodeLimits::new().set_max_size(data.len()).from_slice::<SpanRun>(&data).is_ok());
+    assert_limit(DecodeLimits::new().set_max_size(data.len() - 1).from_slice::<SpanRun>(&data), Limit::Size);
+}
+
+#[test]
+fn max_size_stops_early() {
+    let mut data = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut data));
+    // Make the name of the first item invalid.
+    let name = data.windows(4).position(|v| v == b"test").unwrap();
+    data[name] = 0xFF;
+    assert!(matches!(SpanRun::from_slice(&data), Err(Error::InvalidUtf8)));
+    // The times fit but the items are not decoded as the list starts past the limit.
+    assert_limit(DecodeLimits::new().set_max_size(SIZE_TIMES).from_slice::<SpanRun>(&data), Limit::Size);
+}
@@ -26,47 +26,24 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use bp3d_proto::message::{FromSlice, WriteTo};
-use testprog::enums::{Header, Type};
-use testprog::lists::{Times, SIZE_TIMES, SpanRunVars, SpanRun, DatasetRuns, Dataset};
-use testprog::unions::{Item, Value};
-use testprog::values::{ValueInt16, ValueString, SIZE_VALUEUINT64};
+mod common;
 
-fn write_span_run<F: FnOnce(SpanRun) -> bp3d_proto::message::Result<()>>(f: F) {
-    let data: Vec<u8> = Vec::new();
-    let mut value: [u8; SIZE_VALUEUINT64] = [0; SIZE_VALUEUINT64];
-    let mut header = Header::new_on_stack();
-    let mut times = Times::new_on_stack();
-    times.set_start(42424242).set_end(42424242);
-    let mut list = SpanRunVars::new(data);
-    list.write_item(&Item {
-        header: header.set_type(Type::String).to_ref(),
-        name: "test",
-        value: Value::String(ValueString { data: "this is a test" })
-    }).unwrap();
-    list.write_item(&Item {
-        header: header.set_type(Type::Int16).to_ref(),
-        name: "test1",
-        value: Value::Int16(ValueInt16::from(&mut value).set_data(-4242).to_ref())
-    }).unwrap();
-    let msg = SpanRun {
-        times: times.to_ref(),
-        vars: list.to_ref(),
-    };
-    f(msg).unwrap()
-}
+use bp3d_proto::message::{FromSlice, WriteTo};
+use testprog::enums::Type;
+use testprog::lists::{Times, SIZE_TIMES, SpanRun, DatasetRuns, Dataset};
+use common::write_span_run;
 
 fn assert_span_run(msg: SpanRun) {
     assert_eq!(msg.vars.len(), 2);
-    assert_eq!(msg.times.get_start(), 42424242);
-    assert_eq!(msg.times.get_end(), 42424242);
+    assert_eq!(msg.times.get_start(), 42);
+    assert_eq!(msg.times.get_end(), 4242);
     let mut vars = msg.vars.iter();
     let var = vars.next().unwrap().unwrap();
     assert_eq!(var.name, "test");
-    assert_eq!(var.value.as_string().unwrap().data, "this is a test");
+    assert_eq!(var.value().as_string().unwrap().data, "this is a test");
     let var = vars.next().unwrap().unwrap();
     assert_eq!(var.name, "test1");
-    assert_eq!(var.value.as_int16().unwrap().get_data(), -4242);
+    assert_eq!(var.value().as_int16().unwrap().get_data(), -4242);
 }
 
 #[test]
@@ -83,6 +60,16 @@ fn run() {
     }
 }
 
+#[test]
+fn run_item_headers() {
+    // The same header is reused by every item as Item::new writes the type of each value into it.
+    let mut msg_buffer: Vec<u8> = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut msg_buffer));
+    let msg = SpanRun::from_slice(&msg_buffer).unwrap().into_inner();
+    let types: Vec<Type> = msg.vars.iter().map(|v| v.unwrap().header().get_type().unwrap()).collect();
+    assert_eq!(types, [Type::String, Type::Int16]);
+}
+
 #[test]
 fn dataset() {
     let mut msg_buffer: Vec<u8> = Vec::new();
@@ -0,0 +1,91 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use bp3d_proto::message::{Error, FromSlice, WriteTo, WriteToSlice};
+use testprog::enums::{Header, Type};
+use testprog::lists::{Dataset, DatasetRuns, SpanRun, SpanRunVars, Times};
+use testprog::test::Test;
+use testprog::unions::{Item, Value};
+use testprog::values::ValueString;
+
+#[test]
+fn varchar_encode() {
+    let mut buffer = [0; 64];
+    let s2 = "a".repeat(32);
+    let msg = Test { s1: "", s2: &s2, p1: None };
+    let mut out = Vec::new();
+    Test::write_to(&msg, &mut out).unwrap();
+    assert_eq!(Test::write_to_slice(&msg, &mut buffer).unwrap(), out.len());
+    let s2 = "a".repeat(33);
+    let msg = Test { s1: "", s2: &s2, p1: None };
+    assert!(matches!(Test::write_to(&msg, &mut Vec::new()), Err(Error::LengthExceeded)));
+    assert!(matches!(Test::write_to_slice(&msg, &mut buffer), Err(Error::LengthExceeded)));
+}
+
+#[test]
+fn varchar_decode() {
+    let encode = |len: u8| {
+        let mut data = vec![0x0, len];
+        data.resize(len as usize + 2, b'a');
+        data.push(0x0);
+        data
+    };
+    assert_eq!(Test::from_slice(&encode(32)).unwrap().into_inner().s2.len(), 32);
+    assert!(matches!(Test::from_slice(&encode(33)), Err(Error::LengthExceeded)));
+}
+
+#[test]
+fn list_prefix_overflow() {
+    // SpanRun.vars has a 1 byte prefix: 256 items do not fit.
+    let mut header = Header::new_on_stack();
+    header.set_type(Type::String);
+    let mut list = SpanRunVars::new(Vec::new());
+    for _ in 0..256 {
+        list.write_item(&Item::new(&mut header, "test", Value::String(ValueString { data: "" }))).unwrap();
+    }
+    let times = Times::new_on_stack();
+    let msg = SpanRun {
+        times: times.to_ref(),
+        vars: list.to_ref()
+    };
+    assert!(matches!(SpanRun::write_to(&msg, &mut Vec::new()), Err(Error::LengthExceeded)));
+    assert!(matches!(SpanRun::write_to_slice(&msg, &mut [0; 4096]), Err(Error::LengthExceeded)));
+}
+
+#[test]
+fn list_wider_prefix() {
+    // Dataset.runs has a 4 bytes prefix but at most 999999 items.
+    let runs = unsafe { DatasetRuns::from_raw_parts(&[] as &[u8], 1000000) };
+    let msg = Dataset { runs };
+    assert!(matches!(Dataset::write_to(&msg, &mut Vec::new()), Err(Error::LengthExceeded)));
+    assert!(matches!(Dataset::write_to_slice(&msg, &mut [0; 16]), Err(Error::LengthExceeded)));
+    let data = 1000000u32.to_le_bytes();
+    assert!(matches!(Dataset::from_slice(&data), Err(Error::LengthExceeded)));
+    let data = 999999u32.to_le_bytes();
+    assert_eq!(Dataset::from_slice(&data).unwrap().into_inner().runs.len(), 999999);
+}
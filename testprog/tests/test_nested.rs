@@ -0,0 +1,115 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use bp3d_proto::message::util::{Arena, Array, List};
+use bp3d_proto::message::{FromSlice, WriteTo};
+use testprog::nested::{Point, Samples, SamplesChannels, SamplesOwned, SamplesShapes, Tags, TagsLabels, TagsNames, TagsOwned};
+
+#[test]
+fn strings() {
+    let mut names = TagsNames::new(Vec::new());
+    names.write_value("alpha").unwrap();
+    names.write_value("beta").unwrap();
+    let mut labels = TagsLabels::new(Vec::new());
+    labels.write_value("x").unwrap();
+    let msg = Tags {
+        names: names.to_ref(),
+        labels: Some(labels.to_ref())
+    };
+    let mut buffer: Vec<u8> = Vec::new();
+    Tags::write_to(&msg, &mut buffer).unwrap();
+    assert_eq!(buffer, b"\x02\x05alpha\x04beta\x01\x01x\x00");
+    let msg = Tags::from_slice(&buffer).unwrap();
+    assert_eq!(msg.size(), buffer.len());
+    let msg = msg.into_inner();
+    let names: Vec<&str> = msg.names.iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(names, ["alpha", "beta"]);
+    let labels = msg.labels.unwrap();
+    let labels: Vec<&str> = labels.iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(labels, ["x"]);
+}
+
+#[test]
+fn nested_lists() {
+    let arena = Arena::new();
+    let mut channels = SamplesChannels::new(Vec::new());
+    channels.write_value(&List::from_items(&arena, [Ok(1), Ok(-2)]).unwrap()).unwrap();
+    channels.write_value(&List::from_items(&arena, Vec::<Result<i32, _>>::new()).unwrap()).unwrap();
+    let mut shapes = SamplesShapes::new(Vec::new());
+    let mut point = Point::new_on_stack();
+    point.set_x(3).set_y(-4);
+    shapes.write_value(&Array::from_items(&arena, [Ok(point.to_ref())]).unwrap()).unwrap();
+    let msg = Samples {
+        channels: channels.to_ref(),
+        shapes: shapes.to_ref(),
+        groups: None
+    };
+    let mut buffer: Vec<u8> = Vec::new();
+    Samples::write_to(&msg, &mut buffer).unwrap();
+    let msg = Samples::from_slice(&buffer).unwrap();
+    assert_eq!(msg.size(), buffer.len());
+    let msg = msg.into_inner();
+    let channels: Vec<Vec<i32>> = msg.channels.iter()
+        .map(|v| v.and_then(|v| v.iter().collect()))
+        .collect::<Result<_, _>>().unwrap();
+    assert_eq!(channels, [vec![1, -2], vec![]]);
+    let shapes: Vec<_> = msg.shapes.iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(shapes.len(), 1);
+    let points: Vec<Point<&[u8]>> = shapes[0].iter().collect();
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].get_x(), 3);
+    assert_eq!(points[0].get_y(), -4);
+    assert!(msg.groups.is_none());
+}
+
+#[test]
+fn owned() {
+    let tags = TagsOwned {
+        names: vec!["a".into(), "bc".into()],
+        labels: None
+    };
+    let owned = SamplesOwned {
+        channels: vec![vec![7], vec![8, 9]],
+        shapes: vec![vec![], vec![Point::new_on_stack()]],
+        groups: Some(vec![vec![tags.clone()], vec![]])
+    };
+    let arena = Arena::new();
+    let msg = owned.as_borrowed(&arena).unwrap();
+    let mut buffer: Vec<u8> = Vec::new();
+    Samples::write_to(&msg, &mut buffer).unwrap();
+    let owned = Samples::from_slice(&buffer).unwrap().into_inner().to_owned().unwrap();
+    assert_eq!(owned.channels, [vec![7], vec![8, 9]]);
+    assert_eq!(owned.shapes.len(), 2);
+    assert!(owned.shapes[0].is_empty());
+    assert_eq!(owned.shapes[1].len(), 1);
+    let groups = owned.groups.unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0][0].names, tags.names);
+    assert_eq!(groups[0][0].labels, None);
+    assert!(groups[1].is_empty());
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod common;
+
+use bp3d_proto::message::util::Arena;
+use bp3d_proto::message::{FromSlice, WriteTo};
+use testprog::lists::{SpanRun, SpanRunOwned};
+use common::write_span_run;
+
+fn decode_owned(buffer: &[u8]) -> SpanRunOwned {
+    let msg = SpanRun::from_slice(buffer).unwrap();
+    msg.into_inner().to_owned().unwrap()
+}
+
+#[test]
+fn owned() {
+    let mut buffer: Vec<u8> = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut buffer));
+    // The owned value must outlive the input buffer.
+    let owned = decode_owned(&buffer);
+    drop(buffer);
+    assert_eq!(owned.times.get_start(), 42);
+    assert_eq!(owned.times.get_end(), 4242);
+    assert_eq!(owned.vars.len(), 2);
+    assert_eq!(owned.vars[0].name, "test");
+    assert_eq!(owned.vars[1].name, "test1");
+}
+
+#[test]
+fn owned_round_trip() {
+    let mut buffer: Vec<u8> = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut buffer));
+    let owned = decode_owned(&buffer);
+    let arena = Arena::new();
+    let msg = owned.as_borrowed(&arena).unwrap();
+    let mut buffer2: Vec<u8> = Vec::new();
+    SpanRun::write_to(&msg, &mut buffer2).unwrap();
+    assert_eq!(buffer, buffer2);
+    let clone = owned.clone();
+    assert_eq!(clone.vars.len(), 2);
+}
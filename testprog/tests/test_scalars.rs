@@ -0,0 +1,100 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use bp3d_proto::message::util::Arena;
+use bp3d_proto::message::{Error, FromSlice, WriteTo};
+use testprog::enums::Severity;
+use testprog::scalars::{Log, LogLevels, Sample, SampleOwned, SampleValues};
+
+#[test]
+fn sample() {
+    let mut values = SampleValues::new(Vec::new());
+    values.write_value(&-1).unwrap();
+    values.write_value(&4242).unwrap();
+    let msg = Sample {
+        id: 42,
+        severity: Severity::Error,
+        scale: Some(0.5),
+        values: values.to_ref()
+    };
+    let mut buffer: Vec<u8> = Vec::new();
+    Sample::write_to(&msg, &mut buffer).unwrap();
+    assert_eq!(buffer.len(), 4 + 1 + 9 + 1 + 4);
+    let msg = Sample::from_slice(&buffer).unwrap();
+    assert_eq!(msg.size(), buffer.len());
+    let msg = msg.into_inner();
+    assert_eq!(msg.id, 42);
+    assert_eq!(msg.severity, Severity::Error);
+    assert_eq!(msg.scale, Some(0.5));
+    let values: Vec<i16> = msg.values.iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(values, [-1, 4242]);
+}
+
+#[test]
+fn enum_list() {
+    let mut levels = LogLevels::new(Vec::new());
+    levels.write_value(&Severity::Debug).unwrap();
+    levels.write_value(&Severity::Error).unwrap();
+    levels.write_value(&Severity::Info).unwrap();
+    let msg = Log {
+        levels: levels.to_ref(),
+        flags: None
+    };
+    let mut buffer: Vec<u8> = Vec::new();
+    Log::write_to(&msg, &mut buffer).unwrap();
+    assert_eq!(buffer, [3, 0, 0, 5, 1, 0]);
+    let msg = Log::from_slice(&buffer).unwrap().into_inner();
+    let levels: Vec<Severity> = msg.levels.iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(levels, [Severity::Debug, Severity::Error, Severity::Info]);
+    assert!(msg.flags.is_none());
+}
+
+#[test]
+fn invalid_enum() {
+    let buffer = [2, 0, 1, 3, 0];
+    assert!(matches!(Log::from_slice(&buffer), Err(Error::InvalidEnumValue(3))));
+}
+
+#[test]
+fn owned() {
+    let owned = SampleOwned {
+        id: 1,
+        severity: Severity::Info,
+        scale: None,
+        values: vec![1, 2, 3]
+    };
+    let arena = Arena::new();
+    let msg = owned.as_borrowed(&arena).unwrap();
+    assert_eq!(msg.values.len(), 3);
+    let mut buffer: Vec<u8> = Vec::new();
+    Sample::write_to(&msg, &mut buffer).unwrap();
+    let owned = Sample::from_slice(&buffer).unwrap().into_inner().to_owned().unwrap();
+    assert_eq!(owned.id, 1);
+    assert_eq!(owned.severity, Severity::Info);
+    assert_eq!(owned.scale, None);
+    assert_eq!(owned.values, [1, 2, 3]);
+}
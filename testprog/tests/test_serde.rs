@@ -0,0 +1,228 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use bp3d_proto::message::util::Arena;
+use bp3d_proto::message::{FromSlice, WriteTo};
+use testprog::enums::{Header, Severity, Type};
+use testprog::lists::{SpanRun, SpanRunOwned, SpanRunVars, Times};
+use testprog::nested::{Samples, SamplesOwned, TagsOwned};
+use testprog::scalars::{Log, LogFlags, LogLevels, LogOwned};
+use testprog::struct_arrays::{Basic, Triangle};
+use testprog::structs::Master;
+use testprog::unions::{Item, ItemOwned, PartialItem, PartialItemOwned, Value};
+use testprog::values::{ValueInt16, ValueString, SIZE_VALUEUINT64};
+use testprog::views::{Access, Features, Level, Permissions, Readings};
+
+#[test]
+fn structs() {
+    let mut master = Master::new_on_stack();
+    master.get_floats_mut().set_a(4242.0).set_b(4242.4242);
+    master.get_nums_mut().set_u_a(0x123456AB).set_a(-424242).set_c(-42);
+    master.get_flags_mut().set_a(true).set_d(true);
+    let json = serde_json::to_string(&master).unwrap();
+    let master1: Master<[u8; 41]> = serde_json::from_str(&json).unwrap();
+    assert_eq!(master1.get_floats().get_b(), 4242.4242);
+    assert_eq!(master1.get_nums().get_a(), -424242);
+    assert_eq!(master1.get_nums().get_c(), -42);
+    assert!(master1.get_flags().get_d());
+    assert!(!master1.get_flags().get_b());
+    assert_eq!(serde_json::to_string(&master1).unwrap(), json);
+}
+
+#[test]
+fn arrays() {
+    let mut basic = Basic::new_on_stack();
+    basic.set_p1(424242);
+    basic.get_p3_mut().set_raw(0, 1.0).set_raw(1, 2.0).set_raw(2, 3.0).set_raw(3, 4.0);
+    basic.get_p4_mut().set_raw(0, 0xABCDEF).set_raw(1, 0x123456);
+    let json = serde_json::to_value(&basic).unwrap();
+    assert_eq!(json["p3"], serde_json::json!([1.0, 2.0, 3.0, 4.0]));
+    let basic1: Basic<[u8; 58]> = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(basic1.get_p1(), 424242);
+    assert_eq!(basic1.get_p4().get_raw(1), 0x123456);
+    assert_eq!(serde_json::to_value(&basic1).unwrap(), json);
+    let mut invalid = json;
+    invalid["p3"] = serde_json::json!([1.0, 2.0]);
+    assert!(serde_json::from_value::<Basic<[u8; 58]>>(invalid).is_err());
+}
+
+#[test]
+fn struct_arrays() {
+    let mut triangle = Triangle::new_on_stack();
+    triangle.set_id(1);
+    triangle.get_vertices_mut(1).set_x(-3).set_visible(true);
+    let json = serde_json::to_value(&triangle).unwrap();
+    assert_eq!(json["vertices"][1]["x"], -3);
+    assert_eq!(json["vertices"].as_array().unwrap().len(), 3);
+    let triangle1: Triangle<[u8; 16]> = serde_json::from_value(json.clone()).unwrap();
+    assert!(triangle1.get_vertices(1).get_visible());
+    assert_eq!(serde_json::to_value(&triangle1).unwrap(), json);
+    let mut invalid = json;
+    invalid["vertices"].as_array_mut().unwrap().pop();
+    assert!(serde_json::from_value::<Triangle<[u8; 16]>>(invalid).is_err());
+}
+
+#[test]
+fn array_views() {
+    let mut readings = Readings::new_on_stack();
+    readings.get_levels_mut().set(1, Level::High);
+    readings.get_deltas_mut().set(2, -3);
+    readings.get_mask_mut().set(0, true);
+    readings.get_gains_mut().set(1, 2.5);
+    let json = serde_json::to_value(&readings).unwrap();
+    assert_eq!(json["levels"][1], "High");
+    assert_eq!(json["deltas"][2], -3);
+    assert_eq!(json["mask"][0], true);
+    let readings1: Readings<[u8; 14]> = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(readings1.get_levels().get(1), Some(Level::High));
+    assert_eq!(readings1.get_deltas().get(2), -3);
+    assert_eq!(serde_json::to_value(&readings1).unwrap(), json);
+    let mut invalid = json;
+    invalid["deltas"].as_array_mut().unwrap().pop();
+    assert!(serde_json::from_value::<Readings<[u8; 14]>>(invalid).is_err());
+}
+
+#[test]
+fn enums() {
+    let mut header = Header::new_on_stack();
+    header.set_type(Type::Int16);
+    assert_eq!(serde_json::to_string(&header).unwrap(), r#"{"type":"Int16"}"#);
+    let header: Header<[u8; 1]> = serde_json::from_str(r#"{"type":"Float"}"#).unwrap();
+    assert_eq!(header.get_type(), Some(Type::Float));
+    assert!(serde_json::from_str::<Type>(r#""Unknown""#).is_err());
+}
+
+#[test]
+fn flags() {
+    let mut access = Access::new_on_stack();
+    access.set_owner(Permissions::READ | Permissions::EXECUTE).set_features(Features::SIGNED);
+    let json = serde_json::to_string(&access).unwrap();
+    assert_eq!(json, r#"{"owner":5,"features":512,"group":0}"#);
+    let access1: Access<[u8; 4]> = serde_json::from_str(&json).unwrap();
+    assert_eq!(access1.get_owner(), Permissions::READ | Permissions::EXECUTE);
+    assert_eq!(access1.get_features(), Features::SIGNED);
+}
+
+fn write_span_run(out: &mut Vec<u8>) {
+    let mut value: [u8; SIZE_VALUEUINT64] = [0; SIZE_VALUEUINT64];
+    let mut header = Header::new_on_stack();
+    let mut times = Times::new_on_stack();
+    times.set_start(42).set_end(4242);
+    let mut list = SpanRunVars::new(Vec::new());
+    list.write_item(&Item::new(&mut header, "test", Value::String(ValueString { data: "this is a test" }))).unwrap();
+    list.write_item(&Item::new(&mut header, "test1", Value::Int16(ValueInt16::from(&mut value).set_data(-4242).to_ref()))).unwrap();
+    let msg = SpanRun {
+        times: times.to_ref(),
+        vars: list.to_ref(),
+    };
+    SpanRun::write_to(&msg, out).unwrap();
+}
+
+#[test]
+fn messages() {
+    let mut buffer: Vec<u8> = Vec::new();
+    write_span_run(&mut buffer);
+    let msg = SpanRun::from_slice(&buffer).unwrap().into_inner();
+    let json = serde_json::to_value(msg).unwrap();
+    assert_eq!(json["times"]["end"], 4242);
+    assert_eq!(json["vars"][0]["name"], "test");
+    assert_eq!(json["vars"][0]["value"]["String"]["data"], "this is a test");
+    assert_eq!(json["vars"][1]["header"]["type"], "Int16");
+    assert_eq!(json["vars"][1]["value"]["Int16"]["data"], -4242);
+    let owned: SpanRunOwned = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&owned).unwrap(), json);
+    let arena = Arena::new();
+    let mut buffer1: Vec<u8> = Vec::new();
+    SpanRun::write_to(&owned.as_borrowed(&arena).unwrap(), &mut buffer1).unwrap();
+    assert_eq!(buffer, buffer1);
+}
+
+#[test]
+fn unknown_union_case() {
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut value: [u8; SIZE_VALUEUINT64] = [0; SIZE_VALUEUINT64];
+    let mut header = Header::new_on_stack();
+    Item::write_to(&Item::new(&mut header, "test", Value::Int16(ValueInt16::from(&mut value).set_data(-4242).to_ref())), &mut buffer).unwrap();
+    let msg = PartialItem::from_slice(&buffer).unwrap().into_inner();
+    let json = serde_json::to_value(msg).unwrap();
+    assert_eq!(json["value"]["Unknown"], serde_json::json!([Type::Int16 as u8, [0x6E, 0xEF]]));
+    let owned: PartialItemOwned = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&owned).unwrap(), json);
+    let arena = Arena::new();
+    let mut buffer1: Vec<u8> = Vec::new();
+    PartialItem::write_to(&owned.as_borrowed(&arena).unwrap(), &mut buffer1).unwrap();
+    assert_eq!(buffer, buffer1);
+}
+
+#[test]
+fn union_discriminant_mismatch() {
+    let mut header = Header::new_on_stack();
+    let item = Item::new(&mut header, "test", Value::String(ValueString { data: "this is a test" }));
+    let mut json = serde_json::to_value(item).unwrap();
+    json["header"]["type"] = serde_json::json!("Float");
+    let owned: ItemOwned = serde_json::from_value(json).unwrap();
+    assert_eq!(owned.header().get_type(), Some(Type::String));
+}
+
+#[test]
+fn scalars() {
+    let mut levels = LogLevels::new(Vec::new());
+    levels.write_value(&Severity::Info).unwrap();
+    levels.write_value(&Severity::Error).unwrap();
+    let mut flags = LogFlags::new(Vec::new());
+    flags.write_value(&true).unwrap();
+    let msg = Log {
+        levels: levels.to_ref(),
+        flags: Some(flags.to_ref())
+    };
+    let json = serde_json::to_value(msg).unwrap();
+    assert_eq!(json, serde_json::json!({ "levels": ["Info", "Error"], "flags": [true] }));
+    let owned: LogOwned = serde_json::from_value(json).unwrap();
+    assert_eq!(owned.levels, [Severity::Info, Severity::Error]);
+    assert_eq!(owned.flags, Some(vec![true]));
+}
+
+#[test]
+fn nested() {
+    let owned = SamplesOwned {
+        channels: vec![vec![1, 2], vec![]],
+        shapes: vec![],
+        groups: Some(vec![vec![TagsOwned { names: vec!["a".into()], labels: Some(vec!["b".into()]) }]])
+    };
+    let arena = Arena::new();
+    let msg: Samples = owned.as_borrowed(&arena).unwrap();
+    let json = serde_json::to_value(msg).unwrap();
+    assert_eq!(json, serde_json::json!({
+        "channels": [[1, 2], []],
+        "shapes": [],
+        "groups": [[{ "names": ["a"], "labels": ["b"] }]]
+    }));
+    let owned: SamplesOwned = serde_json::from_value(json).unwrap();
+    assert_eq!(owned.channels, [vec![1, 2], vec![]]);
+    assert_eq!(owned.groups.unwrap()[0][0].labels, Some(vec!["b".into()]));
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod common;
+
+use bp3d_proto::message::{Measure, Measurement, Progress, StreamDecoder, WriteTo};
+use testprog::lists::{SpanRun, SIZE_TIMES};
+use testprog::test::{Test, Test1};
+use testprog::unions::Item;
+use common::write_span_run;
+
+fn write_tests(out: &mut Vec<u8>) {
+    Test::write_to(&Test {
+        p1: Some(Test1 {
+            s1: "this is a test",
+            p1: 42
+        }),
+        s1: "a test",
+        s2: "hello world",
+    }, &mut *out).unwrap();
+    Test::write_to(&Test {
+        p1: None,
+        s1: "",
+        s2: "",
+    }, out).unwrap();
+}
+
+#[test]
+fn measure() {
+    let mut buffer = Vec::new();
+    write_tests(&mut buffer);
+    let size = buffer.len();
+    assert_eq!(Test::measure(&buffer).unwrap(), Measurement::Complete(size - 3));
+    assert_eq!(Test::measure(&[]).unwrap(), Measurement::Incomplete(1));
+    // s1 is complete and the length of s2 is known.
+    assert_eq!(Test::measure(&buffer[..8]).unwrap(), Measurement::Incomplete(11));
+    let mut buffer = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut buffer));
+    for len in 0..buffer.len() {
+        match SpanRun::measure(&buffer[..len]).unwrap() {
+            Measurement::Incomplete(n) => assert!(n > 0 && len + n <= buffer.len()),
+            Measurement::Complete(_) => panic!("truncated input measured as complete")
+        }
+    }
+    assert_eq!(SpanRun::measure(&buffer).unwrap(), Measurement::Complete(buffer.len()));
+}
+
+#[test]
+fn measure_resumes() {
+    let mut buffer = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut buffer));
+    let mut progress = Progress::new();
+    for len in 0..buffer.len() {
+        assert!(matches!(SpanRun::measure_with_progress(&buffer[..len], &mut progress).unwrap(), Measurement::Incomplete(_)));
+    }
+    assert!(!progress.is_empty());
+    // The times and the list length were measured by previous calls and are not read again.
+    let mut altered = buffer.clone();
+    altered[..SIZE_TIMES + 1].fill(0);
+    assert_eq!(SpanRun::measure(&altered).unwrap(), Measurement::Complete(SIZE_TIMES + 1));
+    assert_eq!(SpanRun::measure_with_progress(&altered, &mut progress).unwrap(), Measurement::Complete(buffer.len()));
+    assert!(progress.is_empty());
+}
+
+#[test]
+fn stream_tests() {
+    let mut buffer = Vec::new();
+    write_tests(&mut buffer);
+    write_tests(&mut buffer);
+    for chunk_size in [1, 3, 7, buffer.len()] {
+        let mut decoder = StreamDecoder::new();
+        let mut messages = Vec::new();
+        for chunk in buffer.chunks(chunk_size) {
+            decoder.push(chunk);
+            while let Some(msg) = decoder.next::<Test>().unwrap() {
+                messages.push((msg.s1.to_string(), msg.s2.to_string(), msg.p1.map(|v| (v.s1.to_string(), v.p1))));
+            }
+        }
+        assert_eq!(decoder.buffered(), 0);
+        assert_eq!(messages.len(), 4);
+        for i in [0, 2] {
+            assert_eq!(messages[i], ("a test".into(), "hello world".into(), Some(("this is a test".into(), 42))));
+            assert_eq!(messages[i + 1], ("".into(), "".into(), None));
+        }
+    }
+}
+
+#[test]
+fn stream_span_runs() {
+    let mut buffer = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut buffer));
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut buffer));
+    let mut decoder = StreamDecoder::new();
+    let mut count = 0;
+    for chunk in buffer.chunks(5) {
+        decoder.push(chunk);
+        while let Some(msg) = decoder.next::<SpanRun>().unwrap() {
+            assert_eq!(msg.times.get_start(), 42);
+            assert_eq!(msg.times.get_end(), 4242);
+            let items: Vec<Item> = msg.vars.iter().map(|v| v.unwrap()).collect();
+            assert_eq!(items.len(), 2);
+            assert_eq!(items[0].value().as_string().unwrap().data, "this is a test");
+            assert_eq!(items[1].value().as_int16().unwrap().get_data(), -4242);
+            count += 1;
+        }
+    }
+    assert_eq!(count, 2);
+    assert_eq!(decoder.buffered(), 0);
+}
+
+#[test]
+fn stream_remaining() {
+    let mut buffer = Vec::new();
+    write_tests(&mut buffer);
+    let mut decoder = StreamDecoder::new();
+    // The first string is complete and the length prefix of the second is known.
+    decoder.push(&buffer[..8]);
+    assert!(decoder.next::<Test>().unwrap().is_none());
+    assert_eq!(decoder.remaining(), 11);
+    decoder.push(&buffer[8..10]);
+    // Not enough input to make progress: the message is not measured again.
+    assert!(decoder.next::<Test>().unwrap().is_none());
+    assert_eq!(decoder.remaining(), 9);
+    decoder.push(&buffer[10..]);
+    assert!(decoder.next::<Test>().unwrap().is_some());
+    assert!(decoder.next::<Test>().unwrap().is_some());
+    assert!(decoder.next::<Test>().unwrap().is_none());
+    assert_eq!(decoder.remaining(), 1);
+}
@@ -27,7 +27,7 @@
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use bp3d_proto::util::Size;
-use testprog::struct_arrays::Basic;
+use testprog::struct_arrays::{Basic, Triangle, Vertex};
 
 #[test]
 fn basic() {
@@ -47,3 +47,38 @@ fn basic() {
     assert_eq!(basic.get_p4().get_raw(0), 0xABCDEF);
     assert_eq!(basic.get_p4().get_raw(1), 0xABCDEF);
 }
+
+#[test]
+fn nested() {
+    let mut triangle = Triangle::new_on_stack();
+    assert_eq!(triangle.size(), 1 + 3 * 5);
+    triangle.set_id(7);
+    for (i, mut v) in triangle.iter_vertices_mut().enumerate() {
+        v.set_x(i as i16 * 10).set_y(-(i as i16)).set_layer(i as u8);
+    }
+    triangle.get_vertices_mut(2).set_visible(true);
+    assert_eq!(triangle.get_id(), 7);
+    assert_eq!(triangle.get_vertices(1).get_x(), 10);
+    assert_eq!(triangle.get_vertices(1).get_y(), -1);
+    assert_eq!(triangle.get_vertices(2).get_layer(), 2);
+    assert!(triangle.get_vertices(2).get_visible());
+    assert!(!triangle.get_vertices(0).get_visible());
+    let xs: Vec<i16> = triangle.iter_vertices().map(|v| v.get_x()).collect();
+    assert_eq!(xs, [0, 10, 20]);
+    let vertex: Vertex<[u8; 5]> = triangle.get_vertices(1).to_owned();
+    assert_eq!(vertex.get_x(), 10);
+}
+
+#[test]
+#[should_panic(expected = "index 3 out of bounds for length 3")]
+fn nested_out_of_bounds() {
+    let triangle = Triangle::new_on_stack();
+    triangle.get_vertices(3);
+}
+
+#[test]
+#[should_panic(expected = "index 3 out of bounds for length 3")]
+fn nested_out_of_bounds_mut() {
+    let mut triangle = Triangle::new_on_stack();
+    triangle.get_vertices_mut(3);
+}
@@ -0,0 +1,82 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod common;
+
+use bp3d_proto::message::{Error, FromSlice, WriteTo};
+use testprog::arrays::Msg;
+use testprog::lists::{Dataset, DatasetRuns, SpanRun, SIZE_TIMES};
+use testprog::test::Test;
+use common::write_span_run;
+
+#[test]
+fn varchar_length_out_of_bounds() {
+    // s1 = "", s2 announces 20 bytes but only 2 follow.
+    let data = [0x0, 20, b'a', b'b'];
+    assert!(matches!(Test::from_slice(&data), Err(Error::Truncated)));
+}
+
+#[test]
+fn list_count_out_of_bounds() {
+    let mut data = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut data));
+    // Announce more items than encoded.
+    data[SIZE_TIMES] = 0xFF;
+    assert!(matches!(SpanRun::from_slice(&data), Err(Error::Truncated)));
+}
+
+#[test]
+fn array_count_overflow() {
+    // The largest count does not overflow when computing the size of the array.
+    let data = [0xFF, 0x0, 0x0, 0x0];
+    assert!(matches!(Msg::from_slice(&data), Err(Error::Truncated)));
+}
+
+#[test]
+fn truncated_messages() {
+    let mut data = Vec::new();
+    write_span_run(|msg| SpanRun::write_to(&msg, &mut data));
+    for len in 0..data.len() {
+        assert!(SpanRun::from_slice(&data[..len]).is_err());
+    }
+    let run = SpanRun::from_slice(&data).unwrap().into_inner();
+    let mut list = DatasetRuns::new(Vec::new());
+    list.write_items(&[run, run]).unwrap();
+    let mut data = Vec::new();
+    Dataset::write_to(&Dataset { runs: list.to_ref() }, &mut data).unwrap();
+    for len in 0..data.len() {
+        // Lists extending to the end of a message are decoded lazily: errors are reported by the
+        // iterator, which stops after the first one.
+        if let Ok(msg) = Dataset::from_slice(&data[..len]) {
+            let msg = msg.into_inner();
+            let runs: Vec<_> = msg.runs.iter().collect();
+            assert!(runs.len() <= 2);
+            assert!(runs.last().unwrap().is_err());
+        }
+    }
+}
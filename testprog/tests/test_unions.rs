@@ -27,19 +27,15 @@
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::io::Write;
-use bp3d_proto::message::{FromSlice, WriteTo};
+use bp3d_proto::message::{FromSlice, WriteTo, WriteToSlice};
+use bp3d_proto::message::util::Arena;
 use testprog::enums::{Header, Type};
-use testprog::unions::{Item, Value};
-use testprog::values::{ValueDouble, ValueFloat, ValueInt16, ValueInt32, ValueInt64, ValueInt8, ValueString, ValueUInt16, ValueUInt32, ValueUInt64, ValueUInt8};
+use testprog::unions::{Item, ItemOwned, PartialItem, PartialValue, Value, ValueOwned};
+use testprog::values::{ValueDouble, ValueFloat, ValueInt16, ValueInt32, ValueInt64, ValueInt8, ValueString, ValueUInt16, ValueUInt32, ValueUInt64, ValueUInt8, ValueStringOwned};
 
 fn write_message(value: Value, out: &mut impl Write) {
     let mut header = Header::new_on_stack();
-    value.set_discriminant(&mut header);
-    let item = Item {
-        header: header.to_ref(),
-        name: "test",
-        value
-    };
+    let item = Item::new(&mut header, "test", value);
     Item::write_to(&item, out).unwrap();
 }
 
@@ -47,9 +43,9 @@ fn read_message(slice: &[u8], ty: Type) -> Value {
     let msg = Item::from_slice(slice).unwrap();
     assert_eq!(slice.len(), msg.size());
     let item = msg.into_inner();
-    assert_eq!(item.header.get_type().unwrap(), ty);
+    assert_eq!(item.header().get_type().unwrap(), ty);
     assert_eq!(item.name, "test");
-    item.value
+    *item.value()
 }
 
 #[test]
@@ -117,3 +113,82 @@ fn item_string() {
     write_message(Value::String(ValueString { data: "this is a test" }), &mut buf);
     assert_eq!(read_message(&buf, Type::String).as_string().unwrap().data, "this is a test");
 }
+
+#[test]
+fn item_new_discriminant() {
+    let mut header = Header::new_on_stack();
+    header.set_type(Type::Int8);
+    let item = Item::new(&mut header, "test", Value::String(ValueString { data: "this is a test" }));
+    assert_eq!(item.header().get_type().unwrap(), Type::String);
+    let mut buf = Vec::new();
+    Item::write_to(&item, &mut buf).unwrap();
+    assert_eq!(read_message(&buf, Type::String).as_string().unwrap().data, "this is a test");
+}
+
+#[test]
+fn item_set_value_discriminant() {
+    let mut header = Header::new_on_stack();
+    let mut item = Item::new(&mut header, "test", Value::String(ValueString { data: "this is a test" }));
+    let mut header1 = Header::new_on_stack();
+    header1.set_type(Type::String);
+    item.set_value(&mut header1, Value::Null);
+    assert_eq!(item.header().get_type().unwrap(), Type::Null);
+    let mut buf = Vec::new();
+    Item::write_to(&item, &mut buf).unwrap();
+    assert!(matches!(read_message(&buf, Type::Null), Value::Null));
+}
+
+#[test]
+fn item_owned_discriminant() {
+    let mut header = Header::new_on_stack();
+    header.set_type(Type::Int8);
+    let value = ValueOwned::String(ValueStringOwned { data: "this is a test".into() });
+    let mut owned = ItemOwned::new(header, "test".into(), value);
+    assert_eq!(owned.header().get_type().unwrap(), Type::String);
+    owned.set_value(ValueOwned::Null);
+    assert_eq!(owned.header().get_type().unwrap(), Type::Null);
+    let arena = Arena::new();
+    let item = owned.as_borrowed(&arena).unwrap();
+    assert_eq!(item.header().get_type().unwrap(), Type::Null);
+    let mut buf = Vec::new();
+    Item::write_to(&item, &mut buf).unwrap();
+    assert!(matches!(read_message(&buf, Type::Null), Value::Null));
+}
+
+#[test]
+fn partial_item_known() {
+    let mut buf = Vec::new();
+    write_message(Value::String(ValueString { data: "this is a test" }), &mut buf);
+    let msg = PartialItem::from_slice(&buf).unwrap();
+    assert_eq!(msg.size(), buf.len());
+    let item = msg.into_inner();
+    assert_eq!(item.value().as_string().unwrap().data, "this is a test");
+    assert!(item.value().as_unknown().is_none());
+}
+
+#[test]
+fn partial_item_unknown() {
+    let mut buf = Vec::new();
+    let mut value_buffer: [u8; 8] = [0; 8];
+    write_message(Value::Int16(ValueInt16::from(&mut value_buffer).set_data(-4242).to_ref()), &mut buf);
+    let msg = PartialItem::from_slice(&buf).unwrap();
+    assert_eq!(msg.size(), buf.len());
+    let item = msg.into_inner();
+    assert_eq!(item.name, "test");
+    assert_eq!(item.value().as_unknown(), Some((Type::Int16 as u8, &(-4242i16).to_le_bytes()[..])));
+
+    // A proxy re-encodes the message unchanged.
+    let mut out = Vec::new();
+    PartialItem::write_to(&item, &mut out).unwrap();
+    assert_eq!(out, buf);
+    let mut slice = [0; 64];
+    let size = PartialItem::write_to_slice(&item, &mut slice).unwrap();
+    assert_eq!(&slice[..size], &buf[..]);
+
+    // The discriminant of unknown cases is preserved by constructors.
+    let mut header = Header::new_on_stack();
+    let item = PartialItem::new(&mut header, "test", PartialValue::Unknown(Type::Int16 as u8, &[0x6E, 0xEF]));
+    out.clear();
+    PartialItem::write_to(&item, &mut out).unwrap();
+    assert_eq!(out, buf);
+}
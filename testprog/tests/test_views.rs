@@ -26,7 +26,8 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use testprog::views::Floats;
+use bp3d_proto::util::Size;
+use testprog::views::{Floats, Level, Readings};
 
 macro_rules! assert_feq {
     ($actual: expr, $expected: expr, $delta: expr) => {
@@ -45,3 +46,28 @@ fn floats() {
     floats.set_raw_b(127);
     assert_feq!(floats.get_b(), 12.7, 0.1);
 }
+
+#[test]
+fn arrays() {
+    let mut readings = Readings::new_on_stack();
+    assert_eq!(readings.size(), 14);
+    readings.get_levels_mut().set(0, Level::High).set(4, Level::Mid);
+    readings.get_values_mut().set(1, 0.5).set(3, 1.0);
+    readings.get_deltas_mut().set(0, -16).set(1, 15).set(2, -1);
+    readings.get_mask_mut().set(1, true);
+    readings.get_gains_mut().set(0, 1.5).set(1, 655.0);
+    let levels: Vec<Option<Level>> = readings.get_levels().iter().collect();
+    assert_eq!(levels, [Some(Level::High), Some(Level::Low), Some(Level::Low), Some(Level::Low), Some(Level::Mid)]);
+    assert_feq!(readings.get_values().get(0), 0.0, 0.001);
+    assert_feq!(readings.get_values().get(1), 0.5, 0.001);
+    assert_feq!(readings.get_values().get(3), 1.0, 0.001);
+    let deltas: Vec<i8> = readings.get_deltas().iter().collect();
+    assert_eq!(deltas, [-16, 15, -1]);
+    assert_eq!(readings.get_raw_deltas().get_raw(2), 0x1F);
+    assert!(!readings.get_mask().get(0));
+    assert!(readings.get_mask().get(1));
+    assert_feq!(readings.get_gains().get(0), 1.5, 0.01);
+    assert_feq!(readings.get_gains().get(1), 655.0, 0.01);
+    readings.get_raw_levels_mut().set_raw(2, 7);
+    assert_eq!(readings.get_levels().get(2), None);
+}
@@ -0,0 +1,94 @@
+// Copyright (c) 2024, BlockProject 3D
+//
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//     * Redistributions in binary form must reproduce the above copyright notice,
+//       this list of conditions and the following disclaimer in the documentation
+//       and/or other materials provided with the distribution.
+//     * Neither the name of BlockProject 3D nor the names of its contributors
+//       may be used to endorse or promote products derived from this software
+//       without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+// "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+// LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT OWNER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use bp3d_proto::message::{Error, WriteTo, WriteToSlice};
+use testprog::arrays::{Msg, Msg1, MsgItems};
+use testprog::enums::{Header, Type};
+use testprog::lists::{SpanRun, SpanRunVars, Times};
+use testprog::test::{Test, Test1};
+use testprog::unions::{Item, Value};
+use testprog::values::{ValueInt16, ValueString, SIZE_VALUEUINT64};
+
+fn check<T: WriteTo<Input = T> + WriteToSlice>(msg: &T) {
+    let mut expected = Vec::new();
+    T::write_to(msg, &mut expected).unwrap();
+    let mut buffer = [0; 256];
+    let size = T::write_to_slice(msg, &mut buffer).unwrap();
+    assert_eq!(&buffer[..size], &expected[..]);
+    for len in 0..expected.len() {
+        assert!(matches!(T::write_to_slice(msg, &mut buffer[..len]), Err(Error::BufferTooSmall)));
+    }
+}
+
+#[test]
+fn strings() {
+    check(&Test {
+        p1: Some(Test1 {
+            s1: "this is a test",
+            p1: 42
+        }),
+        s1: "a test",
+        s2: "hello world",
+    });
+    check(&Test {
+        p1: None,
+        s1: "",
+        s2: "",
+    });
+}
+
+#[test]
+fn arrays() {
+    let mut buffer: [u8; 3 * 2] = [0; 3 * 2];
+    let mut arr = MsgItems::from_parts(&mut buffer, 2).unwrap();
+    arr.get_mut(0).set_id(3).set_count(1024).set_slot(10);
+    arr.get_mut(1).set_id(2).set_count(1023).set_slot(9);
+    check(&Msg { items: arr.to_ref() });
+    check(&Msg1 { items: Some(arr.to_ref()) });
+    check(&Msg1 { items: None });
+}
+
+#[test]
+fn lists_and_unions() {
+    let mut value: [u8; SIZE_VALUEUINT64] = [0; SIZE_VALUEUINT64];
+    let mut header = Header::new_on_stack();
+    let mut times = Times::new_on_stack();
+    times.set_start(42).set_end(4242);
+    let mut list = SpanRunVars::new(Vec::new());
+    list.write_item(&Item::new(&mut header, "test", Value::String(ValueString { data: "this is a test" }))).unwrap();
+    list.write_item(&Item::new(&mut header, "test1", Value::Int16(ValueInt16::from(&mut value).set_data(-4242).to_ref()))).unwrap();
+    check(&SpanRun {
+        times: times.to_ref(),
+        vars: list.to_ref(),
+    });
+    // Messages always match their discriminant, only unions can be given a mismatched one.
+    let value = ValueInt16::from(&mut value);
+    header.set_type(Type::String);
+    let mut buffer = [0; 256];
+    assert!(matches!(Value::write_to_slice(&Value::Int16(value.to_ref()), &header.to_ref(), &mut buffer), Err(Error::InvalidUnionDiscriminant(_))));
+}